@@ -268,7 +268,7 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::predicates::{BatchStats, StatsEvaluator};
+    use polars_io::predicates::{BatchStats, ColumnStats, StatsEvaluator};
 
     use super::*;
 
@@ -407,27 +407,41 @@ mod stats {
             let dummy = DataFrame::empty();
             let state = ExecutionState::new();
 
+            // The value filter (e.g. a bloom filter) of a column can only rule out equality
+            // with a literal if the column is compared as is.
+            let may_contain = |column: &dyn PhysicalExpr, st: &ColumnStats, lit_s: &Series| {
+                !matches!(self.op, Eq | EqValidity)
+                    || !matches!(column.as_expression(), Some(Column(_)))
+                    || st.may_contain_any(lit_s)
+            };
+
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    if !may_contain(self.left.as_ref(), l, &lit_s) {
+                        return Ok(false);
+                    }
                     match l.to_min_max() {
                         None => Ok(true),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
                             Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
                         },
                     }
                 },
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    if !may_contain(self.right.as_ref(), r, &lit_s) {
+                        return Ok(false);
+                    }
                     match r.to_min_max() {
                         None => Ok(true),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
                             Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
                         },
                     }
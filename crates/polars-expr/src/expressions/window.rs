@@ -417,12 +417,14 @@ impl PhysicalExpr for WindowExpr {
             .collect::<PolarsResult<Vec<_>>>()?;
 
         // if the keys are sorted
-        let sorted_keys = group_by_columns.iter().all(|s| {
-            matches!(
-                s.is_sorted_flag(),
-                IsSorted::Ascending | IsSorted::Descending
-            )
-        });
+        // the rows of a group are not in their original order if the window is ordered
+        let sorted_keys = self.order_by.is_none()
+            && group_by_columns.iter().all(|s| {
+                matches!(
+                    s.is_sorted_flag(),
+                    IsSorted::Ascending | IsSorted::Descending
+                )
+            });
         let explicit_list_agg = self.is_explicit_list_agg();
 
         // if we flatten this column we need to make sure the groups are sorted.
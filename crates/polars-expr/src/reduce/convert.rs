@@ -1,9 +1,16 @@
 use polars_core::error::feature_gated;
+use polars_core::with_match_physical_numeric_polars_type;
 use polars_plan::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
+use super::count::GroupedCountReduce;
 use super::extrema::*;
-use super::sum::SumReduce;
+use super::first_last::GroupedFirstLastReduce;
+use super::mean::GroupedMeanReduce;
+use super::min_max_by::GroupedMinMaxByReduce;
+use super::n_unique::GroupedNUniqueReduce;
+use super::sum::{GroupedSumReduce, SumReduce};
+use super::var_std::GroupedVarStdReduce;
 use super::*;
 use crate::reduce::mean::MeanReduce;
 
@@ -80,3 +87,156 @@ pub fn into_reduction(
     };
     Ok(Some(out))
 }
+
+/// Whether the physical representation of this type is a primitive numeric
+/// type the grouped numeric reductions are implemented for.
+fn is_primitive_numeric(dtype: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        dtype.to_physical(),
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+    )
+}
+
+/// Whether values of this type can be stored by the value-based grouped
+/// reductions such as `first` and `n_unique`.
+fn is_supported_value_dtype(dtype: &DataType) -> bool {
+    !dtype.is_nested() && !dtype.is_object() && !matches!(dtype, DataType::Null)
+}
+
+/// A grouped reduction together with the nodes of its inputs.
+pub type GroupedReductionWithInputs = (Box<dyn GroupedReduction>, Vec<Node>);
+
+/// Converts an aggregation into a grouped reduction, returning the reduction
+/// and the nodes of its inputs. Returns `None` if the aggregation isn't
+/// supported.
+pub fn into_grouped_reduction(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> PolarsResult<Option<GroupedReductionWithInputs>> {
+    let get_dtype = |node: Node| {
+        expr_arena
+            .get(node)
+            .to_dtype(schema, Context::Default, expr_arena)
+    };
+
+    let agg = match expr_arena.get(node) {
+        AExpr::Len => {
+            let out: Box<dyn GroupedReduction> = Box::new(GroupedCountReduce::new(true));
+            return Ok(Some((out, vec![])));
+        },
+        AExpr::Agg(agg) => agg,
+        _ => return Ok(None),
+    };
+
+    let out: Box<dyn GroupedReduction> = match agg {
+        IRAggExpr::Count(input, include_nulls) => {
+            let out = Box::new(GroupedCountReduce::new(*include_nulls));
+            return Ok(Some((out, vec![*input])));
+        },
+        IRAggExpr::Sum(input) => {
+            let dtype = get_dtype(*input)?;
+            if !(dtype.is_numeric() || dtype.is_bool()) {
+                return Ok(None);
+            }
+            let field = expr_arena
+                .get(node)
+                .to_field(schema, Context::Default, expr_arena)?;
+            if !is_primitive_numeric(&field.dtype) {
+                return Ok(None);
+            }
+            with_match_physical_numeric_polars_type!(field.dtype.to_physical(), |$T| {
+                Box::new(GroupedSumReduce::<$T>::new())
+            })
+        },
+        IRAggExpr::Min {
+            input,
+            propagate_nans,
+        }
+        | IRAggExpr::Max {
+            input,
+            propagate_nans,
+        } => {
+            let dtype = get_dtype(*input)?;
+            if !is_primitive_numeric(&dtype) || dtype.is_categorical() || dtype.is_enum() {
+                return Ok(None);
+            }
+            let is_min = matches!(agg, IRAggExpr::Min { .. });
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                if is_min {
+                    Box::new(GroupedExtremaReduce::<$T>::new_min(*propagate_nans))
+                } else {
+                    Box::new(GroupedExtremaReduce::<$T>::new_max(*propagate_nans))
+                }
+            })
+        },
+        IRAggExpr::Mean(input) => {
+            let dtype = get_dtype(*input)?;
+            if !(dtype.is_numeric() || dtype.is_bool()) {
+                return Ok(None);
+            }
+            Box::<GroupedMeanReduce>::default()
+        },
+        IRAggExpr::Var(input, ddof) | IRAggExpr::Std(input, ddof) => {
+            let dtype = get_dtype(*input)?;
+            if !(dtype.is_numeric() || dtype.is_bool()) {
+                return Ok(None);
+            }
+            let std = matches!(agg, IRAggExpr::Std(..));
+            Box::new(GroupedVarStdReduce::new(*ddof, std))
+        },
+        IRAggExpr::NUnique(input) => {
+            if !is_supported_value_dtype(&get_dtype(*input)?) {
+                return Ok(None);
+            }
+            Box::<GroupedNUniqueReduce>::default()
+        },
+        IRAggExpr::First(input) | IRAggExpr::Last(input) => {
+            let last = matches!(agg, IRAggExpr::Last(_));
+
+            // `first()` and `last()` of a `sort_by()` are the value at the
+            // minimum or maximum of the sort columns.
+            if let AExpr::SortBy {
+                expr,
+                by,
+                sort_options,
+            } = expr_arena.get(*input)
+            {
+                let dtype = get_dtype(*expr)?;
+                if !is_supported_value_dtype(&dtype) {
+                    return Ok(None);
+                }
+                for by in by {
+                    if !is_supported_value_dtype(&get_dtype(*by)?) {
+                        return Ok(None);
+                    }
+                }
+                let broadcast = |flags: &[bool]| {
+                    if flags.len() == by.len() {
+                        flags.to_vec()
+                    } else {
+                        vec![flags.first().copied().unwrap_or(false); by.len()]
+                    }
+                };
+                let out = Box::new(GroupedMinMaxByReduce::new(
+                    dtype,
+                    broadcast(&sort_options.descending),
+                    broadcast(&sort_options.nulls_last),
+                    last,
+                ));
+                let inputs = std::iter::once(*expr).chain(by.iter().copied()).collect();
+                return Ok(Some((out, inputs)));
+            }
+
+            let dtype = get_dtype(*input)?;
+            if !is_supported_value_dtype(&dtype) {
+                return Ok(None);
+            }
+            Box::new(GroupedFirstLastReduce::new(dtype, last))
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some((out, vec![agg.get_input().first()])))
+}
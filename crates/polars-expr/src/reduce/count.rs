@@ -0,0 +1,71 @@
+use super::*;
+
+/// Counts the rows of every group, optionally excluding nulls. Without any
+/// input values every row in the group is counted, which implements `len()`.
+pub(super) struct GroupedCountReduce {
+    counts: Vec<IdxSize>,
+    include_nulls: bool,
+}
+
+impl GroupedCountReduce {
+    pub(super) fn new(include_nulls: bool) -> Self {
+        Self {
+            counts: Vec::new(),
+            include_nulls,
+        }
+    }
+}
+
+impl GroupedReduction for GroupedCountReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.include_nulls))
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.counts.resize(num_groups as usize, 0);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        match values.first() {
+            Some(s) if !self.include_nulls && s.has_nulls() => {
+                let validity = s.is_not_null();
+                for (g, valid) in group_idxs.iter().zip(validity.iter()) {
+                    *self.counts.get_unchecked_mut(*g as usize) +=
+                        valid.unwrap_or(false) as IdxSize;
+                }
+            },
+            _ => {
+                for g in group_idxs {
+                    *self.counts.get_unchecked_mut(*g as usize) += 1;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, c) in group_idxs.iter().zip(&other.counts) {
+            *self.counts.get_unchecked_mut(*g as usize) += *c;
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let counts = std::mem::take(&mut self.counts);
+        Ok(IdxCa::from_vec("", counts).into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -2,7 +2,6 @@
 use polars_core::datatypes::PolarsFloatType;
 #[cfg(feature = "propagate_nans")]
 use polars_ops::prelude::nan_propagating_aggregate;
-#[cfg(feature = "propagate_nans")]
 use polars_utils::min_max::MinMax;
 
 use super::*;
@@ -247,3 +246,106 @@ where
         self
     }
 }
+
+/// Grouped minimum or maximum over the physical values, the extremum is picked
+/// by `reduce` which decides how NaNs are handled.
+pub(super) struct GroupedExtremaReduce<T: PolarsNumericType> {
+    values: Vec<Option<T::Native>>,
+    reduce: fn(T::Native, T::Native) -> T::Native,
+}
+
+impl<T: PolarsNumericType> GroupedExtremaReduce<T>
+where
+    T::Native: MinMax,
+{
+    pub(super) fn new_min(propagate_nans: bool) -> Self {
+        let reduce = if propagate_nans {
+            MinMax::min_propagate_nan
+        } else {
+            MinMax::min_ignore_nan
+        };
+        Self {
+            values: Vec::new(),
+            reduce,
+        }
+    }
+
+    pub(super) fn new_max(propagate_nans: bool) -> Self {
+        let reduce = if propagate_nans {
+            MinMax::max_propagate_nan
+        } else {
+            MinMax::max_ignore_nan
+        };
+        Self {
+            values: Vec::new(),
+            reduce,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn update_group(&mut self, g: IdxSize, v: T::Native) {
+        let slot = self.values.get_unchecked_mut(g as usize);
+        *slot = Some(match *slot {
+            Some(cur) => (self.reduce)(cur, v),
+            None => v,
+        });
+    }
+}
+
+impl<T: PolarsNumericType> GroupedReduction for GroupedExtremaReduce<T>
+where
+    T::Native: MinMax,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            values: Vec::new(),
+            reduce: self.reduce,
+        })
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups as usize, None);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let values = values[0].to_physical_repr();
+        let values = values.cast(&T::get_dtype())?;
+        let ca: &ChunkedArray<T> = values.unpack()?;
+        for (g, v) in group_idxs.iter().zip(ca.iter()) {
+            if let Some(v) = v {
+                self.update_group(*g, v);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, v) in group_idxs.iter().zip(&other.values) {
+            if let Some(v) = v {
+                self.update_group(*g, *v);
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = std::mem::take(&mut self.values);
+        let ca: ChunkedArray<T> = values.into_iter().collect();
+        Ok(ca.into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
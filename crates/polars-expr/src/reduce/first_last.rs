@@ -0,0 +1,103 @@
+use super::*;
+
+/// Keeps the first or last value of every group. Rows are ordered by the
+/// sequence id of their batch and their position within it.
+pub(super) struct GroupedFirstLastReduce {
+    values: Vec<Option<((u64, IdxSize), AnyValue<'static>)>>,
+    dtype: DataType,
+    last: bool,
+}
+
+impl GroupedFirstLastReduce {
+    pub(super) fn new(dtype: DataType, last: bool) -> Self {
+        Self {
+            values: Vec::new(),
+            dtype,
+            last,
+        }
+    }
+}
+
+/// Whether a value ordered at `new` replaces one ordered at `cur`.
+#[inline(always)]
+fn replaces(last: bool, new: &(u64, IdxSize), cur: &(u64, IdxSize)) -> bool {
+    if last {
+        new > cur
+    } else {
+        new < cur
+    }
+}
+
+impl GroupedReduction for GroupedFirstLastReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.dtype.clone(), self.last))
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups as usize, None);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        let values = values[0].rechunk();
+        let last = self.last;
+        let mut update = |row: usize| -> PolarsResult<()> {
+            let g = *group_idxs.get_unchecked(row) as usize;
+            let key = (seq_id, row as IdxSize);
+            let replace = match self.values.get_unchecked(g) {
+                Some((cur, _)) => replaces(last, &key, cur),
+                None => true,
+            };
+            if replace {
+                let av = values.get_unchecked(row).into_static()?;
+                *self.values.get_unchecked_mut(g) = Some((key, av));
+            }
+            Ok(())
+        };
+
+        // Visit the rows in the direction where the first visited row of a
+        // group wins, so we only materialize one value per group.
+        if last {
+            (0..group_idxs.len()).rev().try_for_each(&mut update)
+        } else {
+            (0..group_idxs.len()).try_for_each(&mut update)
+        }
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, v) in group_idxs.iter().zip(&other.values) {
+            let Some((key, av)) = v else {
+                continue;
+            };
+            let replace = match self.values.get_unchecked(*g as usize) {
+                Some((cur, _)) => replaces(self.last, key, cur),
+                None => true,
+            };
+            if replace {
+                *self.values.get_unchecked_mut(*g as usize) = Some((*key, av.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values: Vec<_> = std::mem::take(&mut self.values)
+            .into_iter()
+            .map(|v| v.map(|(_, av)| av).unwrap_or(AnyValue::Null))
+            .collect();
+        Series::from_any_values_and_dtype("", &values, &self.dtype, false)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
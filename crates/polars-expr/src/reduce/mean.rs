@@ -70,3 +70,65 @@ impl Reduction for MeanReduce {
         self
     }
 }
+
+#[derive(Default)]
+pub(super) struct GroupedMeanReduce {
+    sums: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl GroupedReduction for GroupedMeanReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::<Self>::default()
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.sums.resize(num_groups as usize, 0.0);
+        self.counts.resize(num_groups as usize, 0);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let values = values[0].cast(&DataType::Float64)?;
+        let ca = values.f64()?;
+        for (g, v) in group_idxs.iter().zip(ca.iter()) {
+            if let Some(v) = v {
+                *self.sums.get_unchecked_mut(*g as usize) += v;
+                *self.counts.get_unchecked_mut(*g as usize) += 1;
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (i, g) in group_idxs.iter().enumerate() {
+            *self.sums.get_unchecked_mut(*g as usize) += other.sums[i];
+            *self.counts.get_unchecked_mut(*g as usize) += other.counts[i];
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let sums = std::mem::take(&mut self.sums);
+        let counts = std::mem::take(&mut self.counts);
+        let ca: Float64Chunked = sums
+            .into_iter()
+            .zip(counts)
+            .map(|(sum, count)| (count > 0).then(|| sum / count as f64))
+            .collect();
+        Ok(ca.into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,123 @@
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded;
+
+use super::*;
+
+type OrderKey = (Box<[u8]>, u64, IdxSize);
+
+/// Keeps the value of every group at the minimum or maximum of other columns,
+/// i.e. `first()` or `last()` of a `sort_by()`. The first input holds the
+/// values, the remaining inputs the columns to order by. Ties are broken by
+/// row order, like a stable sort would.
+pub(super) struct GroupedMinMaxByReduce {
+    values: Vec<Option<(OrderKey, AnyValue<'static>)>>,
+    dtype: DataType,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    max: bool,
+}
+
+impl GroupedMinMaxByReduce {
+    pub(super) fn new(
+        dtype: DataType,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+        max: bool,
+    ) -> Self {
+        Self {
+            values: Vec::new(),
+            dtype,
+            descending,
+            nulls_last,
+            max,
+        }
+    }
+
+    #[inline(always)]
+    fn replaces(&self, new: &OrderKey, cur: &OrderKey) -> bool {
+        if self.max {
+            new > cur
+        } else {
+            new < cur
+        }
+    }
+}
+
+impl GroupedReduction for GroupedMinMaxByReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(
+            self.dtype.clone(),
+            self.descending.clone(),
+            self.nulls_last.clone(),
+            self.max,
+        ))
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups as usize, None);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        let (values, by) = values.split_first().unwrap();
+        let values = values.rechunk();
+        let by_rows = _get_rows_encoded(by, &self.descending, &self.nulls_last)?.into_array();
+
+        for (row, (g, by_row)) in group_idxs.iter().zip(by_rows.values_iter()).enumerate() {
+            let g = *g as usize;
+            let replace = match self.values.get_unchecked(g) {
+                Some(((cur_by, cur_seq, cur_row), _)) => {
+                    let new = (by_row, seq_id, row as IdxSize);
+                    if self.max {
+                        new > (&**cur_by, *cur_seq, *cur_row)
+                    } else {
+                        new < (&**cur_by, *cur_seq, *cur_row)
+                    }
+                },
+                None => true,
+            };
+            if replace {
+                let key = (by_row.into(), seq_id, row as IdxSize);
+                let av = values.get_unchecked(row).into_static()?;
+                *self.values.get_unchecked_mut(g) = Some((key, av));
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, v) in group_idxs.iter().zip(&other.values) {
+            let Some((key, av)) = v else {
+                continue;
+            };
+            let replace = match self.values.get_unchecked(*g as usize) {
+                Some((cur, _)) => self.replaces(key, cur),
+                None => true,
+            };
+            if replace {
+                *self.values.get_unchecked_mut(*g as usize) = Some((key.clone(), av.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values: Vec<_> = std::mem::take(&mut self.values)
+            .into_iter()
+            .map(|v| v.map(|(_, av)| av).unwrap_or(AnyValue::Null))
+            .collect();
+        Series::from_any_values_and_dtype("", &values, &self.dtype, false)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
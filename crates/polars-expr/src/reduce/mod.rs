@@ -1,11 +1,18 @@
 mod convert;
+mod count;
 mod extrema;
+mod first_last;
 mod mean;
+mod min_max_by;
+mod n_unique;
 mod sum;
+mod var_std;
 
 use std::any::Any;
 
-pub use convert::{can_convert_into_reduction, into_reduction};
+pub use convert::{
+    can_convert_into_reduction, into_grouped_reduction, into_reduction, GroupedReductionWithInputs,
+};
 use polars_core::prelude::*;
 
 #[allow(dead_code)]
@@ -31,3 +38,44 @@ pub trait Reduction: Any + Send {
 
     fn as_any(&self) -> &dyn Any;
 }
+
+/// A reduction which keeps a separate state for every group. Groups are
+/// identified by their index, which the caller assigns.
+pub trait GroupedReduction: Any + Send {
+    /// Creates a fresh reduction with zero groups.
+    fn new_empty(&self) -> Box<dyn GroupedReduction>;
+
+    /// Resizes this reduction to the given number of groups, new groups are in
+    /// the initial state.
+    fn resize(&mut self, num_groups: IdxSize);
+
+    /// Updates the groups with a batch of values. The value at row `i` of the
+    /// inputs belongs to group `group_idxs[i]`. The `seq_id` orders batches for
+    /// order-dependent reductions, rows within a batch are in order.
+    ///
+    /// # Safety
+    /// All group indices must be smaller than the number of groups.
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()>;
+
+    /// Combines another reduction of the same type into this one, group `i` of
+    /// `other` is combined into group `group_idxs[i]` of `self`.
+    ///
+    /// # Safety
+    /// All group indices must be smaller than the number of groups.
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()>;
+
+    /// Returns the result of every group, after which the number of groups is
+    /// reset to zero.
+    fn finalize(&mut self) -> PolarsResult<Series>;
+
+    fn as_any(&self) -> &dyn Any;
+}
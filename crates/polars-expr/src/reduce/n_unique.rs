@@ -0,0 +1,61 @@
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_unordered;
+use polars_utils::aliases::PlHashSet;
+
+use super::*;
+
+/// Counts the distinct values of every group, null counts as a value. Values
+/// are compared by their row encoding.
+#[derive(Default)]
+pub(super) struct GroupedNUniqueReduce {
+    sets: Vec<PlHashSet<Box<[u8]>>>,
+}
+
+impl GroupedReduction for GroupedNUniqueReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::<Self>::default()
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.sets
+            .resize_with(num_groups as usize, PlHashSet::default);
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let rows = _get_rows_encoded_unordered(&values[..1])?.into_array();
+        for (g, row) in group_idxs.iter().zip(rows.values_iter()) {
+            let set = self.sets.get_unchecked_mut(*g as usize);
+            if !set.contains(row) {
+                set.insert(row.into());
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, other_set) in group_idxs.iter().zip(&other.sets) {
+            let set = self.sets.get_unchecked_mut(*g as usize);
+            set.extend(other_set.iter().cloned());
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let sets = std::mem::take(&mut self.sets);
+        let counts: Vec<IdxSize> = sets.iter().map(|s| s.len() as IdxSize).collect();
+        Ok(IdxCa::from_vec("", counts).into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
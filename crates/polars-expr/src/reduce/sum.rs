@@ -1,3 +1,4 @@
+use polars_core::export::num::Zero;
 use polars_core::prelude::{AnyValue, DataType};
 
 use super::*;
@@ -47,3 +48,98 @@ impl Reduction for SumReduce {
         self
     }
 }
+
+/// Addition which wraps around on integer overflow, like the sum kernels.
+pub(super) trait WrappingAdd: Copy {
+    fn wrapping_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_add {
+    ($($T:ty),*) => {
+        $(impl WrappingAdd for $T {
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$T>::wrapping_add(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_wrapping_add!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl WrappingAdd for f32 {
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl WrappingAdd for f64 {
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+pub(super) struct GroupedSumReduce<T: PolarsNumericType> {
+    values: Vec<T::Native>,
+}
+
+impl<T: PolarsNumericType> GroupedSumReduce<T> {
+    pub(super) fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<T: PolarsNumericType> GroupedReduction for GroupedSumReduce<T>
+where
+    ChunkedArray<T>: IntoSeries,
+    T::Native: WrappingAdd,
+{
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new())
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups as usize, T::Native::zero());
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let values = values[0].cast(&T::get_dtype())?;
+        let ca: &ChunkedArray<T> = values.unpack()?;
+        for (g, v) in group_idxs.iter().zip(ca.iter()) {
+            if let Some(v) = v {
+                let acc = self.values.get_unchecked_mut(*g as usize);
+                *acc = acc.wrapping_add(v);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, v) in group_idxs.iter().zip(&other.values) {
+            let acc = self.values.get_unchecked_mut(*g as usize);
+            *acc = acc.wrapping_add(*v);
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = std::mem::take(&mut self.values);
+        Ok(ChunkedArray::<T>::from_vec("", values).into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+#[derive(Clone, Copy, Default)]
+struct VarState {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl VarState {
+    #[inline(always)]
+    fn insert(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    #[inline(always)]
+    fn combine(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+    }
+
+    fn finalize(&self, ddof: u8, std: bool) -> Option<f64> {
+        if self.count <= ddof as u64 {
+            return None;
+        }
+        let var = self.m2 / (self.count - ddof as u64) as f64;
+        Some(if std { var.sqrt() } else { var })
+    }
+}
+
+/// Grouped variance or standard deviation using Welford's online algorithm.
+pub(super) struct GroupedVarStdReduce {
+    states: Vec<VarState>,
+    ddof: u8,
+    std: bool,
+}
+
+impl GroupedVarStdReduce {
+    pub(super) fn new(ddof: u8, std: bool) -> Self {
+        Self {
+            states: Vec::new(),
+            ddof,
+            std,
+        }
+    }
+}
+
+impl GroupedReduction for GroupedVarStdReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.ddof, self.std))
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.states.resize(num_groups as usize, VarState::default());
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &[Series],
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let values = values[0].cast(&DataType::Float64)?;
+        let ca = values.f64()?;
+        for (g, v) in group_idxs.iter().zip(ca.iter()) {
+            if let Some(v) = v {
+                self.states.get_unchecked_mut(*g as usize).insert(v);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        for (g, s) in group_idxs.iter().zip(&other.states) {
+            self.states.get_unchecked_mut(*g as usize).combine(s);
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let states = std::mem::take(&mut self.states);
+        let ca: Float64Chunked = states
+            .iter()
+            .map(|s| s.finalize(self.ddof, self.std))
+            .collect();
+        Ok(ca.into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,15 +1,28 @@
-use std::io::{Read, Seek};
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
 
+use arrow::datatypes::Field;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::avro_schema::read::{block_iterator, BlockStreamingIterator};
+use arrow::io::avro::avro_schema::schema::Field as AvroField;
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use rayon::prelude::*;
 
+use crate::hive::materialize_hive_partitions;
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
+use crate::RowIndex;
 
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
+/// The blocks of the file are decoded in parallel.
+///
 /// [Apache Avro]: https://avro.apache.org
 ///
 /// # Example
@@ -33,6 +46,9 @@ pub struct AvroReader<R> {
     n_rows: Option<usize>,
     columns: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+    hive_partition_columns: Option<Vec<Series>>,
+    include_file_path: Option<(Arc<str>, Arc<str>)>,
 }
 
 impl<R: Read + Seek> AvroReader<R> {
@@ -68,6 +84,93 @@ impl<R: Read + Seek> AvroReader<R> {
         self.columns = columns;
         self
     }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
+        self.hive_partition_columns = columns;
+        self
+    }
+
+    pub fn with_include_file_path(
+        mut self,
+        include_file_path: Option<(Arc<str>, Arc<str>)>,
+    ) -> Self {
+        self.include_file_path = include_file_path;
+        self
+    }
+
+    /// Read the file in batches, every batch holds the rows of the blocks that are decoded in
+    /// parallel at once.
+    pub fn batched(mut self) -> PolarsResult<BatchedAvroReader<R>> {
+        let metadata =
+            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
+        let schema = read::infer_schema(&metadata.record)?;
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &schema)?);
+        }
+        let (mask, projected_schema, names) = self.block_projection(&schema);
+
+        Ok(BatchedAvroReader {
+            blocks: ParallelBlockReader::new(
+                self.reader,
+                metadata,
+                schema.fields.clone(),
+                mask,
+                self.n_rows,
+            ),
+            schema,
+            projected_schema,
+            names,
+            n_rows: self.n_rows,
+            row_index: self.row_index,
+            hive_partition_columns: self.hive_partition_columns,
+            include_file_path: self.include_file_path,
+            rows_read: 0,
+        })
+    }
+}
+
+impl<R> AvroReader<R> {
+    /// Returns the mask of the fields to decode and their schema. The blocks are decoded in the
+    /// order of the fields in the file, so if the projection is in another order the names of the
+    /// output columns are returned as well.
+    fn block_projection(
+        &self,
+        schema: &ArrowSchema,
+    ) -> (Option<Vec<bool>>, ArrowSchema, Option<Vec<String>>) {
+        let Some(projection) = self.projection.as_deref() else {
+            return (None, schema.clone(), None);
+        };
+        let mut sorted_projection = projection.to_vec();
+        sorted_projection.sort_unstable();
+
+        let mut mask = vec![false; schema.fields.len()];
+        for &index in sorted_projection.iter() {
+            mask[index] = true;
+        }
+        let names = (sorted_projection != projection).then(|| {
+            self.row_index
+                .iter()
+                .map(|ri| ri.name.to_string())
+                .chain(
+                    projection
+                        .iter()
+                        .map(|&i| schema.fields[i].name.to_string()),
+                )
+                .collect()
+        });
+        (
+            Some(mask),
+            apply_projection(schema, &sorted_projection),
+            names,
+        )
+    }
 }
 
 impl<R> ArrowReader for read::Reader<R>
@@ -79,6 +182,181 @@ where
     }
 }
 
+/// Count the rows of an Avro file. Only the headers of the blocks are read.
+pub fn count_rows<R: Read + Seek>(reader: &mut R) -> PolarsResult<usize> {
+    let mut reader = std::io::BufReader::new(reader);
+    avro::avro_schema::read::read_metadata(&mut reader)?;
+
+    let mut num_rows = 0;
+    while let Some(block_rows) = read_long(&mut reader)? {
+        let block_size = read_long(&mut reader)?
+            .ok_or_else(|| polars_err!(ComputeError: "unexpected end of Avro file"))?;
+        // Skip the data and the sync marker of the block.
+        reader.seek(SeekFrom::Current(block_size + 16))?;
+        num_rows += block_rows as usize;
+    }
+    Ok(num_rows)
+}
+
+/// Reads a zigzag encoded long. Returns `None` at the end of the file.
+fn read_long<R: Read>(reader: &mut R) -> PolarsResult<Option<i64>> {
+    let mut value = 0u64;
+    let mut byte = [0u8; 1];
+    for i in 0..10 {
+        if reader.read(&mut byte)? == 0 {
+            polars_ensure!(i == 0, ComputeError: "unexpected end of Avro file");
+            return Ok(None);
+        }
+        value |= u64::from(byte[0] & 0x7F) << (i * 7);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some((value >> 1) as i64 ^ -((value & 1) as i64)));
+        }
+    }
+    polars_bail!(ComputeError: "invalid long in Avro file")
+}
+
+/// Decodes the blocks of an Avro file in parallel. Blocks are read and decompressed
+/// sequentially, the rows of a batch of blocks are then deserialized on the thread pool.
+struct ParallelBlockReader<R: Read> {
+    blocks: BlockStreamingIterator<R>,
+    fields: Vec<Field>,
+    avro_fields: Vec<AvroField>,
+    projection: Vec<bool>,
+    /// Stop reading blocks once this many rows are read.
+    n_rows: Option<usize>,
+    rows_read: usize,
+    decoded: VecDeque<RecordBatch>,
+}
+
+impl<R: Read> ParallelBlockReader<R> {
+    fn new(
+        reader: R,
+        metadata: FileMetadata,
+        fields: Vec<Field>,
+        projection: Option<Vec<bool>>,
+        n_rows: Option<usize>,
+    ) -> Self {
+        let projection = projection.unwrap_or_else(|| vec![true; fields.len()]);
+
+        Self {
+            blocks: block_iterator(reader, metadata.compression, metadata.marker),
+            fields,
+            avro_fields: metadata.record.fields,
+            projection,
+            n_rows,
+            rows_read: 0,
+            decoded: VecDeque::new(),
+        }
+    }
+
+    /// Decodes the next blocks, returns the number of rows in them.
+    fn decode_blocks(&mut self) -> PolarsResult<usize> {
+        let n_blocks = POOL.current_num_threads();
+        let mut blocks = Vec::with_capacity(n_blocks);
+        let mut num_rows = 0;
+        while blocks.len() < n_blocks && self.n_rows.map_or(true, |n| self.rows_read < n) {
+            let Some(block) = self.blocks.next()? else {
+                break;
+            };
+            self.rows_read += block.number_of_rows;
+            num_rows += block.number_of_rows;
+            blocks.push(block.clone());
+        }
+
+        let fields = &self.fields;
+        let avro_fields = &self.avro_fields;
+        let projection = &self.projection;
+        let batches = POOL.install(|| {
+            blocks
+                .par_iter()
+                .map(|block| read::deserialize(block, fields, avro_fields, projection))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        self.decoded.extend(batches);
+        Ok(num_rows)
+    }
+}
+
+impl<R: Read> ArrowReader for ParallelBlockReader<R> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        if self.decoded.is_empty() {
+            self.decode_blocks()?;
+        }
+        Ok(self.decoded.pop_front())
+    }
+}
+
+/// Reads an Avro file in batches of blocks, see [`AvroReader::batched`].
+pub struct BatchedAvroReader<R: Read> {
+    blocks: ParallelBlockReader<R>,
+    schema: ArrowSchema,
+    projected_schema: ArrowSchema,
+    /// The names of the output columns, if the projection is not in the order of the file.
+    names: Option<Vec<String>>,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    hive_partition_columns: Option<Vec<Series>>,
+    include_file_path: Option<(Arc<str>, Arc<str>)>,
+    rows_read: usize,
+}
+
+impl<R: Read> BatchedAvroReader<R> {
+    /// Returns the next batch, or `None` once the file is read.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let n_rows_left = self
+            .n_rows
+            .map_or(usize::MAX, |n| n.saturating_sub(self.rows_read));
+        if n_rows_left == 0 {
+            return Ok(None);
+        }
+        let num_rows = self.blocks.decode_blocks()?.min(n_rows_left);
+        if self.blocks.decoded.is_empty() {
+            return Ok(None);
+        }
+
+        let fields = self.projected_schema.fields.as_slice();
+        let mut df = if fields.is_empty() {
+            self.blocks.decoded.clear();
+            DataFrame::empty()
+        } else {
+            let dfs = self
+                .blocks
+                .decoded
+                .drain(..)
+                .map(|batch| DataFrame::try_from((batch, fields)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            accumulate_dataframes_vertical_unchecked(dfs).slice(0, num_rows)
+        };
+
+        if let Some(row_index) = &self.row_index {
+            let offset = row_index.offset + self.rows_read as IdxSize;
+            let index = IdxCa::from_vec(
+                &row_index.name,
+                (offset..offset + num_rows as IdxSize).collect(),
+            );
+            // SAFETY: The index has the height of the batch.
+            unsafe { df.get_columns_mut().insert(0, index.into_series()) };
+        }
+        if let Some(names) = &self.names {
+            df = df.select(names)?;
+        }
+        materialize_hive_partitions(
+            &mut df,
+            &self.schema,
+            self.hive_partition_columns.as_deref(),
+            num_rows,
+        );
+        if let Some((col, value)) = &self.include_file_path {
+            unsafe {
+                df.with_column_unchecked(StringChunked::full(col, value, num_rows).into_series())
+            };
+        }
+
+        self.rows_read += num_rows;
+        Ok(Some(df))
+    }
+}
+
 impl<R> SerReader<R> for AvroReader<R>
 where
     R: Read + Seek,
@@ -90,6 +368,9 @@ where
             n_rows: None,
             columns: None,
             projection: None,
+            row_index: None,
+            hive_partition_columns: None,
+            include_file_path: None,
         }
     }
 
@@ -108,26 +389,62 @@ where
             self.projection = Some(columns_to_projection(columns, &schema)?);
         }
 
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
-            let mut prj = vec![false; schema.fields.len()];
-            for &index in projection.iter() {
-                prj[index] = true;
-            }
-            (Some(prj), apply_projection(&schema, &projection))
-        } else {
-            (None, schema.clone())
+        // In case only hive columns are projected, the df would be empty, but we need the row count
+        // of the file in order to project the correct number of rows for the hive columns.
+        let (mut df, row_count) = match self.projection.as_deref() {
+            Some([]) => {
+                self.reader.seek(SeekFrom::Start(0))?;
+                let row_count = count_rows(&mut self.reader)?;
+                let row_count = self.n_rows.map_or(row_count, |n| row_count.min(n));
+                let mut df = DataFrame::empty();
+                if let Some(row_index) = &self.row_index {
+                    let offset = row_index.offset;
+                    df = DataFrame::new(vec![IdxCa::from_vec(
+                        &row_index.name,
+                        (offset..offset + row_count as IdxSize).collect(),
+                    )
+                    .into_series()])?;
+                }
+                (df, row_count)
+            },
+            _ => {
+                let (mask, projected_schema, names) = self.block_projection(&schema);
+
+                let avro_reader = ParallelBlockReader::new(
+                    &mut self.reader,
+                    metadata,
+                    schema.fields.clone(),
+                    mask,
+                    self.n_rows,
+                );
+
+                let mut df = finish_reader(
+                    avro_reader,
+                    rechunk,
+                    self.n_rows,
+                    None,
+                    &projected_schema,
+                    self.row_index.clone(),
+                )?;
+
+                if let Some(names) = names {
+                    df = df.select(names)?;
+                }
+                let row_count = df.height();
+                (df, row_count)
+            },
         };
 
-        let avro_reader =
-            avro::read::Reader::new(&mut self.reader, metadata, schema.fields, projection);
+        if let Some(hive_cols) = self.hive_partition_columns.take() {
+            materialize_hive_partitions(&mut df, &schema, Some(hive_cols.as_slice()), row_count);
+        };
 
-        finish_reader(
-            avro_reader,
-            rechunk,
-            self.n_rows,
-            None,
-            &projected_schema,
-            None,
-        )
+        if let Some((col, value)) = self.include_file_path.take() {
+            unsafe {
+                df.with_column_unchecked(StringChunked::full(&col, &value, row_count).into_series())
+            };
+        }
+
+        Ok(df)
     }
 }
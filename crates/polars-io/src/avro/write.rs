@@ -5,10 +5,30 @@ use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
-pub use Compression as AvroCompression;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{schema_to_arrow_checked, SerWriter};
 
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    pub compression: Option<AvroCompression>,
+    /// Name of the record in the Avro schema
+    pub name: String,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_compression(self.compression)
+            .with_name(self.name.clone())
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -49,6 +69,25 @@ where
         self.name = name;
         self
     }
+
+    /// Write the header of the Avro file and return a writer to which the blocks can be
+    /// written batch by batch.
+    pub fn batched(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&schema, self.name)?;
+        let compression = self.compression.map(Into::into);
+
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), compression)
+            .map_err(to_compute_err)?;
+
+        Ok(BatchedWriter {
+            writer: self.writer,
+            record,
+            compression,
+            data: vec![],
+            compressed_block: Default::default(),
+        })
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -64,39 +103,89 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let writer = AvroWriter {
+            writer: &mut self.writer,
+            compression: self.compression,
+            name: self.name.clone(),
+        };
+        let mut batched = writer.batched(&df.schema())?;
+        df.align_chunks();
+        batched.write_batch(df)?;
+        batched.finish()
+    }
+}
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+/// Writes the blocks of an Avro file, one block per chunk of the written [`DataFrame`]s.
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    record: avro_schema::schema::Record,
+    compression: Option<Compression>,
+    data: Vec<u8>,
+    compressed_block: avro_schema::file::CompressedBlock,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the Avro writer.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
+            if chunk.is_empty() {
+                continue;
+            }
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
             let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
+                avro_schema::file::Block::new(chunk.len(), std::mem::take(&mut self.data));
             write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+            let _was_compressed = avro_schema::write::compress(
+                &mut block,
+                &mut self.compressed_block,
+                self.compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            avro_schema::write::write_block(&mut self.writer, &self.compressed_block)
                 .map_err(to_compute_err)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
             // reuse block for next iteration.
-            data = block.data;
-            data.clear();
+            self.data = block.data;
+            self.data.clear();
 
             // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0
         }
+        Ok(())
+    }
 
+    /// Flushes the written blocks. Avro files have no footer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.flush()?;
         Ok(())
     }
 }
+
+/// Block compression codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AvroCompression {
+    /// Deflate
+    Deflate,
+    /// Snappy
+    Snappy,
+}
+
+impl From<AvroCompression> for Compression {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Compression::Deflate,
+            AvroCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
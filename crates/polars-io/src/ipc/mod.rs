@@ -17,3 +17,5 @@ pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOptions};
+#[cfg(all(feature = "async", feature = "ipc"))]
+pub use write_async::BatchedWriterAsync;
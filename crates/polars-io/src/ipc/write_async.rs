@@ -14,7 +14,10 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
         }
     }
 
-    pub fn batched_async(self, schema: &Schema) -> PolarsResult<BatchedWriterAsync<W>> {
+    pub fn batched_async<'a>(self, schema: &Schema) -> PolarsResult<BatchedWriterAsync<'a, W>>
+    where
+        W: 'a,
+    {
         let writer = FileSink::new(
             self.writer,
             schema.to_arrow(CompatLevel::oldest()),
@@ -39,7 +42,7 @@ impl<'a, W> BatchedWriterAsync<'a, W>
 where
     W: AsyncWrite + Unpin + Send + 'a,
 {
-    /// Write a batch to the parquet writer.
+    /// Write a batch to the IPC writer.
     ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
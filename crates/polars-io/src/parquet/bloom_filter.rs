@@ -0,0 +1,73 @@
+//! Hashing of values into Parquet (split block) bloom filters.
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native};
+use polars_parquet::parquet::schema::types::PhysicalType;
+
+/// The false positive probability of the bloom filters that are written.
+pub(crate) const BLOOM_FILTER_FPP: f64 = 0.01;
+
+/// Returns whether bloom filters are supported for columns of the given [`DataType`].
+pub(crate) fn supports_bloom_filter(dtype: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        dtype,
+        Int8 | Int16
+            | Int32
+            | Int64
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Date
+            | Datetime(_, _)
+            | Duration(_)
+            | Time
+            | String
+            | Binary
+    )
+}
+
+/// Hashes the non-null values of `s` the way they are stored in a column chunk of the given
+/// [`PhysicalType`].
+///
+/// Returns `None` if the values cannot be looked up in a bloom filter of such a column.
+pub(crate) fn hash_values(s: &Series, physical_type: PhysicalType) -> Option<Vec<u64>> {
+    use DataType::*;
+    let hashes = match (s.dtype(), physical_type) {
+        // Unsigned integers are stored with the bit pattern of the signed type of the same width.
+        (UInt32, PhysicalType::Int32) => s
+            .u32()
+            .unwrap()
+            .iter()
+            .flatten()
+            .map(|v| hash_native(v as i32))
+            .collect(),
+        (UInt64, PhysicalType::Int64) => s
+            .u64()
+            .unwrap()
+            .iter()
+            .flatten()
+            .map(|v| hash_native(v as i64))
+            .collect(),
+        (Int8 | Int16 | Int32 | UInt8 | UInt16 | Date, PhysicalType::Int32) => {
+            let s = s.to_physical_repr().cast(&Int32).ok()?;
+            s.i32().unwrap().iter().flatten().map(hash_native).collect()
+        },
+        (Int64 | Datetime(_, _) | Duration(_) | Time, PhysicalType::Int64) => {
+            let s = s.to_physical_repr();
+            s.i64().unwrap().iter().flatten().map(hash_native).collect()
+        },
+        (String, PhysicalType::ByteArray) => {
+            s.str().unwrap().iter().flatten().map(hash_byte).collect()
+        },
+        (Binary, PhysicalType::ByteArray) => s
+            .binary()
+            .unwrap()
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        _ => return None,
+    };
+    Some(hashes)
+}
@@ -6,7 +6,7 @@ use bytes::Bytes;
 use object_store::path::Path as ObjectPath;
 use polars_core::config::{get_rg_prefetch_size, verbose};
 use polars_core::prelude::*;
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{FileDecryptionProperties, RowGroupMetaData};
 use polars_parquet::write::FileMetaData;
 use smartstring::alias::String as SmartString;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
@@ -30,6 +30,7 @@ pub struct ParquetObjectStore {
     path: ObjectPath,
     length: Option<usize>,
     metadata: Option<FileMetaDataRef>,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl ParquetObjectStore {
@@ -46,9 +47,16 @@ impl ParquetObjectStore {
             path,
             length: None,
             metadata,
+            decryption: None,
         })
     }
 
+    /// Decrypt encrypted files with the keys retrieved by these properties.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     async fn get_range(&self, start: usize, length: usize) -> PolarsResult<Bytes> {
         self.store
             .get_range(&self.path, start..start + length)
@@ -76,7 +84,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetaData> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -102,11 +110,12 @@ fn read_i32le(reader: &mut &[u8]) -> Option<i32> {
     read_n(reader).map(i32::from_le_bytes)
 }
 
-/// Asynchronously reads the files' metadata
+/// Asynchronously reads the files' metadata, decrypting it if the file is encrypted.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetaData> {
     let footer_header_bytes = store
         .get_range(
@@ -121,22 +130,24 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    let footer_byte_length: usize = {
+    let (footer_byte_length, encrypted_footer): (usize, bool) = {
         let reader = &mut footer_header_bytes.as_ref();
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        let encrypted_footer = magic == polars_parquet::parquet::PARQUET_ENCRYPTED_MAGIC;
+        if !encrypted_footer && magic != polars_parquet::parquet::PARQUET_MAGIC {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
             .into());
         }
-        footer_byte_size.try_into().map_err(|_| {
+        let footer_byte_length = footer_byte_size.try_into().map_err(|_| {
             polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "negative footer byte length".to_string(),
             )
-        })?
+        })?;
+        (footer_byte_length, encrypted_footer)
     };
 
     let footer_bytes = store
@@ -152,13 +163,17 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    Ok(
+        polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+            &footer_bytes[..footer_byte_length],
+            encrypted_footer,
+            // TODO: Describe why this makes sense. Taken from the previous
+            // implementation which said "a highly nested but sparse struct could
+            // result in many allocations".
+            footer_bytes.as_ref().len() * 2 + 1024,
+            decryption,
+        )?,
+    )
 }
 
 /// Download rowgroups for the column whose indexes are given in `projection`.
@@ -278,7 +293,7 @@ impl FetchRowGroupsFromObjectStore {
                 .filter_map(|i| {
                     let rg = &row_groups[i];
                     let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
+                        matches!(read_this_row_group(Some(pred), rg, &schema, None), Ok(true));
 
                     // Already add the row groups that will be skipped to the prefetched data.
                     if !should_be_read {
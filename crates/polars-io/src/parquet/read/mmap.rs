@@ -6,7 +6,7 @@ use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    Filter, PageReader,
+    Filter, PageFilter, PageReader,
 };
 use polars_utils::mmap::{MemReader, MemSlice};
 
@@ -27,6 +27,17 @@ pub enum ColumnStore {
     Fetched(PlHashMap<u64, Bytes>),
 }
 
+impl ColumnStore {
+    /// Returns the bytes of the whole file, which are only available for local files.
+    pub(super) fn file(&self) -> Option<&MemSlice> {
+        match self {
+            ColumnStore::Local(mem_slice) => Some(mem_slice),
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(_) => None,
+        }
+    }
+}
+
 /// For local files memory maps all columns that are part of the parquet field `field_name`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
@@ -62,21 +73,29 @@ fn _mmap_single_column<'a>(
 
 // similar to arrow2 serializer, except this accepts a slice instead of a vec.
 // this allows us to memory map
+//
+// If a `pages_filter` is given, only the data pages it selects are read, and `filter` applies
+// to the rows of those pages.
 pub(super) fn to_deserializer<'a>(
     columns: Vec<(&ColumnChunkMetaData, MemSlice)>,
     field: Field,
-    num_rows: usize,
+    filter: Filter,
+    pages_filter: Option<PageFilter>,
 ) -> PolarsResult<ArrayIter<'a>> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
         .map(|(column_meta, chunk)| {
-            // Advise fetching the data for the column chunk
-            chunk.prefetch();
+            // Advise fetching the data for the column chunk, unless we skip some of its pages
+            if pages_filter.is_none() {
+                chunk.prefetch();
+            }
 
             let pages = PageReader::new(
                 MemReader::new(chunk),
                 column_meta,
-                std::sync::Arc::new(|_, _| true),
+                pages_filter
+                    .clone()
+                    .unwrap_or_else(|| std::sync::Arc::new(|_, _| true)),
                 vec![],
                 usize::MAX,
             );
@@ -87,5 +106,5 @@ pub(super) fn to_deserializer<'a>(
         })
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, Some(Filter::new_limited(num_rows)))
+    column_iter_to_arrays(columns, types, field, Some(filter))
 }
@@ -18,6 +18,7 @@
 mod async_impl;
 mod mmap;
 mod options;
+mod page_index;
 mod predicates;
 mod read_impl;
 mod reader;
@@ -25,6 +26,7 @@ mod to_metadata;
 mod utils;
 
 pub use options::{ParallelStrategy, ParquetOptions};
+pub use polars_parquet::read::{FileDecryptionProperties, KeyRetriever};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
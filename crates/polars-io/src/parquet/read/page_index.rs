@@ -0,0 +1,240 @@
+//! Page-level predicate pushdown, using the page indexes (column and offset indexes) of a
+//! row group.
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_parquet::read::indexes::{
+    compute_page_row_intervals, has_indexes, read_columns_indexes, FieldPageStatistics, Interval,
+};
+use polars_parquet::read::{
+    get_field_columns, get_field_pages, read_pages_locations, Filter, PageFilter, RowGroupMetaData,
+};
+use polars_utils::mmap::MemSlice;
+
+use super::predicates::collect_statistics;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+/// The rows of a row group that may match a predicate, according to its page indexes.
+pub(super) struct PageSelection {
+    /// Whether each row of the row group may match the predicate.
+    rows: Bitmap,
+    /// The row intervals of the pages of each column chunk in the row group.
+    page_rows: Vec<Vec<Interval>>,
+}
+
+impl PageSelection {
+    /// Returns whether each row of the row group may match the predicate.
+    pub(super) fn rows(&self) -> &Bitmap {
+        &self.rows
+    }
+
+    /// Returns the filters to read the selected rows of a field.
+    ///
+    /// The [`PageFilter`] skips the data pages without selected rows and the [`Filter`] selects
+    /// the rows in the remaining pages. Returns `None` for nested fields, of which the rows
+    /// cannot be mapped to the values in the pages.
+    pub(super) fn column_filters(
+        &self,
+        md: &RowGroupMetaData,
+        field: &ArrowField,
+    ) -> Option<(PageFilter, Filter)> {
+        use arrow::datatypes::PhysicalType as P;
+        if matches!(
+            field.data_type.to_physical_type(),
+            P::List | P::LargeList | P::FixedSizeList | P::Struct | P::Map | P::Union
+        ) || get_field_columns(md.columns(), &field.name).len() != 1
+        {
+            return None;
+        }
+        let column = md
+            .columns()
+            .iter()
+            .position(|column| column.descriptor().path_in_schema[0] == field.name)?;
+        let pages = self
+            .page_rows
+            .get(column)
+            .filter(|pages| !pages.is_empty())?;
+
+        let mut mask = MutableBitmap::with_capacity(self.rows.set_bits());
+        let keep = pages
+            .iter()
+            .map(|page| {
+                let rows = self.rows.clone().sliced(page.start, page.length);
+                let keep = rows.set_bits() > 0;
+                if keep {
+                    mask.extend_from_bitmap(&rows);
+                }
+                keep
+            })
+            .collect::<Vec<_>>();
+
+        // The filter is called once for every data page, in order.
+        let page = AtomicUsize::new(0);
+        let page_filter: PageFilter = Arc::new(move |_, _| {
+            keep.get(page.fetch_add(1, Ordering::Relaxed))
+                .copied()
+                .unwrap_or(true)
+        });
+        Some((page_filter, Filter::new_masked(mask.freeze())))
+    }
+}
+
+/// Returns whether the page statistics of a field can be compared with a predicate.
+fn supports_page_statistics(md: &RowGroupMetaData, field: &ArrowField) -> bool {
+    use ArrowDataType as D;
+    get_field_columns(md.columns(), &field.name).len() == 1
+        && matches!(
+            field.data_type.to_logical_type(),
+            D::Boolean
+                | D::Int8
+                | D::Int16
+                | D::Int32
+                | D::Int64
+                | D::UInt8
+                | D::UInt16
+                | D::UInt32
+                | D::UInt64
+                | D::Float32
+                | D::Float64
+                | D::Date32
+                | D::Time64(_)
+                | D::Timestamp(_, _)
+                | D::Duration(_)
+                | D::Utf8View
+                | D::BinaryView
+        )
+}
+
+/// Select the rows of a row group that may match the predicate, by evaluating the predicate
+/// on the statistics of the pages of every column.
+///
+/// Returns `None` if the row group has no page indexes, or if all rows may match.
+pub(super) fn select_pages(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    file: &MemSlice,
+) -> PolarsResult<Option<PageSelection>> {
+    let Some(pred) = predicate.and_then(|pred| pred.as_stats_evaluator()) else {
+        return Ok(None);
+    };
+    if !has_indexes(md) {
+        return Ok(None);
+    }
+    let Some(row_group_stats) = collect_statistics(md, schema, None)? else {
+        return Ok(None);
+    };
+    let num_rows = md.num_rows();
+
+    // A page index that cannot be read is ignored, just like missing statistics.
+    let mut reader = Cursor::new(&file[..]);
+    let Ok(page_rows) = read_pages_locations(&mut reader, md.columns()) else {
+        return Ok(None);
+    };
+    let Ok(page_rows) = page_rows
+        .iter()
+        .map(|locations| compute_page_row_intervals(locations, num_rows))
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return Ok(None);
+    };
+
+    let fields = schema
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| supports_page_statistics(md, field))
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
+        return Ok(None);
+    }
+    let Ok(page_stats) = read_columns_indexes(
+        &mut reader,
+        md.columns(),
+        &fields
+            .iter()
+            .map(|(_, field)| (*field).clone())
+            .collect::<Vec<_>>(),
+    ) else {
+        return Ok(None);
+    };
+
+    // The statistics of every page of the supported fields, as (min, max, null count) Series.
+    let mut columns = vec![];
+    for ((i, field), page_stats) in fields.into_iter().zip(page_stats) {
+        let FieldPageStatistics::Single(page_stats) = page_stats else {
+            continue;
+        };
+        let [pages] = get_field_pages(md.columns(), &page_rows, &field.name)[..] else {
+            continue;
+        };
+        if pages.is_empty() || pages.len() != page_stats.min.len() {
+            continue;
+        }
+        let min = Series::try_from(("", page_stats.min))?;
+        let max = Series::try_from(("", page_stats.max))?;
+        let null_count = Series::try_from(("", page_stats.null_count.boxed()))?;
+        columns.push((i, field, pages, min, max, null_count));
+    }
+    if columns.is_empty() {
+        return Ok(None);
+    }
+
+    // Evaluate the predicate on every range of rows that lies within a single page of each
+    // column.
+    let mut bounds = columns
+        .iter()
+        .flat_map(|(_, _, pages, ..)| pages.iter().map(|page| page.start))
+        .chain([0, num_rows])
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let schema = Arc::new(Schema::from(schema));
+    let mut page_idx = vec![0; columns.len()];
+    let mut rows = MutableBitmap::with_capacity(num_rows);
+    for range in bounds.windows(2) {
+        let (start, end) = (range[0], range[1]);
+        let mut stats = row_group_stats.column_stats().to_vec();
+        for ((i, field, pages, min, max, null_count), page_idx) in
+            columns.iter().zip(page_idx.iter_mut())
+        {
+            while *page_idx + 1 < pages.len()
+                && pages[*page_idx].start + pages[*page_idx].length <= start
+            {
+                *page_idx += 1;
+            }
+            let offset = *page_idx as i64;
+            stats[*i] = ColumnStats::new(
+                (*field).into(),
+                Some(null_count.slice(offset, 1)),
+                Some(min.slice(offset, 1)),
+                Some(max.slice(offset, 1)),
+            );
+        }
+        // The null counts are those of whole pages (or of the row group, for the columns
+        // without page statistics), so they only count the nulls of the range if it covers
+        // all of them exactly.
+        let covers_pages = columns
+            .iter()
+            .zip(&page_idx)
+            .all(|((_, _, pages, ..), idx)| {
+                pages[*idx].start == start && pages[*idx].start + pages[*idx].length == end
+            });
+        let covers_row_group = start == 0 && end == num_rows;
+        let exact = covers_pages && (columns.len() == stats.len() || covers_row_group);
+        let stats = BatchStats::new(schema.clone(), stats, exact.then_some(end - start));
+        let read = !matches!(pred.should_read(&stats), Ok(false));
+        rows.extend_constant(end - start, read);
+    }
+
+    if rows.unset_bits() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(PageSelection {
+        rows: rows.freeze(),
+        page_rows,
+    }))
+}
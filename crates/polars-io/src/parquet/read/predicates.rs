@@ -1,9 +1,67 @@
+use std::io::Cursor;
+use std::sync::OnceLock;
+
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter;
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{get_field_columns, ColumnChunkMetaData, RowGroupMetaData};
+use polars_utils::mmap::MemSlice;
+
+use crate::parquet::bloom_filter::hash_values;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, ValueFilter};
+
+/// The bloom filter of a column chunk, read from the file on first use.
+struct ParquetBloomFilter {
+    file: MemSlice,
+    column: ColumnChunkMetaData,
+    dtype: DataType,
+    bitset: OnceLock<Vec<u8>>,
+}
+
+impl std::fmt::Debug for ParquetBloomFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetBloomFilter")
+            .field("column", &self.column.descriptor().path_in_schema)
+            .finish()
+    }
+}
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+impl ParquetBloomFilter {
+    /// Returns the bitset of the bloom filter; empty if it cannot be read.
+    fn bitset(&self) -> &[u8] {
+        self.bitset.get_or_init(|| {
+            let mut bitset = vec![];
+            let mut reader = Cursor::new(&self.file[..]);
+            // A split block bloom filter consists of blocks of 32 bytes.
+            if bloom_filter::read(&self.column, &mut reader, &mut bitset).is_err()
+                || bitset.len() % 32 != 0
+            {
+                bitset.clear();
+            }
+            bitset
+        })
+    }
+}
+
+impl ValueFilter for ParquetBloomFilter {
+    fn may_contain_any(&self, values: &Series) -> bool {
+        let bitset = self.bitset();
+        if bitset.is_empty() {
+            return true;
+        }
+        // Values that cannot be represented in the column may not be hashed as stored.
+        let Ok(values) = values.strict_cast(&self.dtype) else {
+            return true;
+        };
+        match hash_values(&values, self.column.physical_type()) {
+            Some(hashes) => hashes
+                .into_iter()
+                .any(|hash| bloom_filter::is_in_set(bitset, hash)),
+            None => true,
+        }
+    }
+}
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -16,16 +74,42 @@ impl ColumnStats {
     }
 }
 
+/// Returns the bloom filter of the column chunk of a (non-nested) field, if it has one.
+fn column_bloom_filter(
+    md: &RowGroupMetaData,
+    field: &ArrowField,
+    file: &MemSlice,
+) -> Option<Arc<dyn ValueFilter>> {
+    let [column] = get_field_columns(md.columns(), &field.name)[..] else {
+        return None;
+    };
+    column.metadata().bloom_filter_offset?;
+    Some(Arc::new(ParquetBloomFilter {
+        file: file.clone(),
+        column: column.clone(),
+        dtype: DataType::from_arrow(&field.data_type, true),
+        bitset: OnceLock::new(),
+    }))
+}
+
 /// Collect the statistics in a column chunk.
+///
+/// If the bytes of the file are given, the bloom filters of the columns are attached as
+/// [`ValueFilter`]s.
 pub(crate) fn collect_statistics(
     md: &RowGroupMetaData,
     schema: &ArrowSchema,
+    file: Option<&MemSlice>,
 ) -> PolarsResult<Option<BatchStats>> {
     let mut stats = vec![];
 
     for field in schema.fields.iter() {
         let st = deserialize(field, md)?;
-        stats.push(ColumnStats::from_arrow_stats(st, field));
+        let mut column_stats = ColumnStats::from_arrow_stats(st, field);
+        if let Some(value_filter) = file.and_then(|file| column_bloom_filter(md, field, file)) {
+            column_stats = column_stats.with_value_filter(value_filter);
+        }
+        stats.push(column_stats);
     }
 
     Ok(if stats.is_empty() {
@@ -43,10 +127,11 @@ pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
     schema: &ArrowSchemaRef,
+    file: Option<&MemSlice>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(md, schema)? {
+            if let Some(stats) = collect_statistics(md, schema, file)? {
                 let should_read = pred.should_read(&stats);
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
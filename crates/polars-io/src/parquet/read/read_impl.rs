@@ -2,18 +2,19 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
-use arrow::array::new_empty_array;
+use arrow::array::{new_empty_array, BooleanArray};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
-use polars_parquet::read::{self, ArrayIter, FileMetaData, PhysicalType, RowGroupMetaData};
+use polars_parquet::read::{self, ArrayIter, FileMetaData, Filter, PhysicalType, RowGroupMetaData};
 use polars_utils::mmap::MemSlice;
 use rayon::prelude::*;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
+use super::page_index::{select_pages, PageSelection};
 use super::predicates::read_this_row_group;
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
@@ -58,6 +59,7 @@ fn column_idx_to_series(
     remaining_rows: usize,
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
+    selection: Option<&PageSelection>,
 ) -> PolarsResult<Series> {
     let field = &file_schema.fields[column_i];
 
@@ -67,7 +69,33 @@ fn column_idx_to_series(
     }
 
     let columns = mmap_columns(store, md.columns(), &field.name);
-    let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows)?;
+
+    if let Some(selection) = selection {
+        // Only read the pages that hold selected rows. Nested columns are read in full and
+        // filtered afterwards.
+        return match selection.column_filters(md, field) {
+            Some((pages_filter, filter)) => {
+                let iter =
+                    mmap::to_deserializer(columns, field.clone(), filter, Some(pages_filter))?;
+                array_iter_to_series(iter, field, None)
+            },
+            None => {
+                let filter = Filter::new_limited(md.num_rows());
+                let iter = mmap::to_deserializer(columns, field.clone(), filter, None)?;
+                let mask = BooleanChunked::from_chunk_iter(
+                    "",
+                    [BooleanArray::from_data_default(
+                        selection.rows().clone(),
+                        None,
+                    )],
+                );
+                array_iter_to_series(iter, field, None)?.filter(&mask)
+            },
+        };
+    }
+
+    let filter = Filter::new_limited(remaining_rows);
+    let iter = mmap::to_deserializer(columns, field.clone(), filter, None)?;
 
     let mut series = if remaining_rows < md.num_rows() {
         array_iter_to_series(iter, field, Some(remaining_rows))
@@ -151,6 +179,30 @@ pub(super) fn array_iter_to_series(
     }
 }
 
+/// Returns the rows of a row group that may match the predicate according to its page indexes,
+/// or `None` if the whole row group is read.
+///
+/// Pages are only skipped when the full row group is read without a row index, so that the
+/// selected rows need not be aligned with a slice or row numbers.
+fn select_row_group_pages(
+    store: &mmap::ColumnStore,
+    md: &RowGroupMetaData,
+    rg_slice: (usize, usize),
+    schema: &ArrowSchema,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    row_index: Option<&RowIndex>,
+    projection: &[usize],
+) -> PolarsResult<Option<PageSelection>> {
+    match store.file() {
+        Some(file)
+            if row_index.is_none() && !projection.is_empty() && rg_slice == (0, md.num_rows()) =>
+        {
+            select_pages(predicate, md, schema, file)
+        },
+        _ => Ok(None),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -232,11 +284,36 @@ fn rg_to_dfs_optionally_par_over_columns(
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && !read_this_row_group(
+                predicate,
+                &file_metadata.row_groups[rg_idx],
+                schema,
+                store.file(),
+            )?
         {
             *previous_row_count += rg_slice.1 as IdxSize;
             continue;
         }
+        let selection = if use_statistics {
+            select_row_group_pages(
+                store,
+                md,
+                rg_slice,
+                schema,
+                predicate,
+                row_index.as_ref(),
+                projection,
+            )?
+        } else {
+            None
+        };
+        let height = selection
+            .as_ref()
+            .map_or(rg_slice.1, |selection| selection.rows().set_bits());
+        if height == 0 {
+            *previous_row_count += rg_slice.1 as IdxSize;
+            continue;
+        }
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
@@ -255,6 +332,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                             idx_to_series_projection_height,
                             schema,
                             store,
+                            selection.as_ref(),
                         )
                         .map(|s| s.slice(rg_slice.0 as i64, rg_slice.1))
                     })
@@ -270,6 +348,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         idx_to_series_projection_height,
                         schema,
                         store,
+                        selection.as_ref(),
                     )
                     .map(|s| s.slice(rg_slice.0 as i64, rg_slice.1))
                 })
@@ -281,7 +360,7 @@ fn rg_to_dfs_optionally_par_over_columns(
             df.with_row_index_mut(&rc.name, Some(*previous_row_count + rc.offset));
         }
 
-        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, rg_slice.1);
+        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, height);
         apply_predicate(&mut df, predicate, true)?;
 
         *previous_row_count += current_row_count;
@@ -343,10 +422,30 @@ fn rg_to_dfs_par_over_rg(
                             predicate,
                             &file_metadata.row_groups[rg_idx],
                             schema,
+                            store.file(),
                         )?
                 {
                     return Ok(None);
                 }
+                let selection = if use_statistics {
+                    select_row_group_pages(
+                        store,
+                        md,
+                        slice,
+                        schema,
+                        predicate,
+                        row_index.as_ref(),
+                        projection,
+                    )?
+                } else {
+                    None
+                };
+                let height = selection
+                    .as_ref()
+                    .map_or(slice.1, |selection| selection.rows().set_bits());
+                if height == 0 {
+                    return Ok(None);
+                }
                 // test we don't read the parquet file if this env var is set
                 #[cfg(debug_assertions)]
                 {
@@ -356,8 +455,15 @@ fn rg_to_dfs_par_over_rg(
                 let columns = projection
                     .iter()
                     .map(|column_i| {
-                        column_idx_to_series(*column_i, md, slice.0 + slice.1, schema, store)
-                            .map(|x| x.slice(slice.0 as i64, slice.1))
+                        column_idx_to_series(
+                            *column_i,
+                            md,
+                            slice.0 + slice.1,
+                            schema,
+                            store,
+                            selection.as_ref(),
+                        )
+                        .map(|x| x.slice(slice.0 as i64, slice.1))
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -371,7 +477,7 @@ fn rg_to_dfs_par_over_rg(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    slice.1,
+                    height,
                 );
                 apply_predicate(&mut df, predicate, false)?;
 
@@ -785,7 +891,7 @@ impl BatchedParquetReader {
 
                 // Re-use the same ChunkedArray
                 if ca.len() < max_len {
-                    *ca = ca.new_from_index(max_len, 0);
+                    *ca = ca.new_from_index(0, max_len);
                 }
 
                 for df in &mut dfs {
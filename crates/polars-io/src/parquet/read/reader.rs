@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_parquet::read;
+use polars_parquet::read::FileDecryptionProperties;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
@@ -40,6 +41,7 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(Arc<str>, Arc<str>)>,
     use_statistics: bool,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -140,9 +142,18 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Decrypt encrypted files with the keys retrieved by these properties.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -196,6 +207,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             use_statistics: true,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
@@ -373,6 +385,12 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Decrypt encrypted files with the keys retrieved by these properties.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.reader = self.reader.with_decryption(decryption);
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -4,38 +4,67 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 use polars_core::POOL;
+use polars_parquet::parquet::bloom_filter::{insert, optimal_num_of_bytes};
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    FallibleStreamingIterator, FileWriter, Page, ParquetPhysicalType, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, WriteOptions,
 };
 use rayon::prelude::*;
 
+use crate::parquet::bloom_filter::{hash_values, BLOOM_FILTER_FPP};
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
-    pub(super) options: WriteOptions,
+    /// The write options of every column.
+    pub(super) options: Vec<WriteOptions>,
     pub(super) parallel: bool,
+    pub(super) bloom_filter_columns: Vec<BloomFilterColumn>,
+}
+
+/// A column for which a bloom filter is written.
+pub(super) struct BloomFilterColumn {
+    /// The index of the column in the [`DataFrame`].
+    pub(super) column: usize,
+    /// The index of the (single) leaf of the column in the parquet schema.
+    pub(super) leaf: usize,
+    pub(super) physical_type: ParquetPhysicalType,
+}
+
+/// A row group that is encoded and compressed, together with the bloom filters
+/// (leaf column, bitset) of its column chunks.
+pub struct EncodedRowGroup {
+    columns: RowGroupIterColumns<'static, PolarsError>,
+    bloom_filters: Vec<(usize, Vec<u8>)>,
 }
 
 impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
-                let row_group = create_eager_serializer(
-                    batch,
-                    self.parquet_schema.fields(),
-                    self.encodings.as_ref(),
-                    self.options,
+                let row_group = compute_bloom_filters(&batch, &self.bloom_filter_columns).and_then(
+                    |bloom_filters| {
+                        let columns = create_eager_serializer(
+                            batch,
+                            self.parquet_schema.fields(),
+                            self.encodings.as_ref(),
+                            &self.options,
+                        )?;
+                        Ok(EncodedRowGroup {
+                            columns,
+                            bloom_filters,
+                        })
+                    },
                 );
 
                 Some(row_group)
@@ -52,13 +81,14 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
-            self.options,
+            &self.bloom_filter_columns,
+            &self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            write_row_group(&mut writer, group?)?;
         }
         Ok(())
     }
@@ -67,14 +97,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            write_row_group(&mut writer, group)?;
         }
         Ok(())
     }
@@ -87,26 +114,75 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+fn write_row_group<W: Write>(
+    writer: &mut FileWriter<W>,
+    row_group: EncodedRowGroup,
+) -> PolarsResult<()> {
+    writer.write(row_group.columns)?;
+    for (leaf, bitset) in row_group.bloom_filters {
+        writer.write_bloom_filter(leaf, &bitset)?;
+    }
+    Ok(())
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    bloom_filter_columns: &'a [BloomFilterColumn],
+    options: &'a [WriteOptions],
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
             let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+                compute_bloom_filters(&batch, bloom_filter_columns).and_then(|bloom_filters| {
+                    let columns = create_serializer(
+                        batch,
+                        parquet_schema.fields(),
+                        encodings,
+                        options,
+                        parallel,
+                    )?;
+                    Ok(EncodedRowGroup {
+                        columns,
+                        bloom_filters,
+                    })
+                });
 
             Some(row_group)
         },
     })
 }
 
+/// Builds the bloom filters (leaf column, bitset) of the given columns of a row group.
+fn compute_bloom_filters(
+    batch: &RecordBatch,
+    bloom_filter_columns: &[BloomFilterColumn],
+) -> PolarsResult<Vec<(usize, Vec<u8>)>> {
+    bloom_filter_columns
+        .iter()
+        .map(|bloom_filter_column| {
+            let array = batch.columns()[bloom_filter_column.column].clone();
+            let s = Series::from_arrow("", array)?;
+            let mut hashes = hash_values(&s, bloom_filter_column.physical_type).ok_or_else(
+                || polars_err!(ComputeError: "cannot write a bloom filter for type {}", s.dtype()),
+            )?;
+            hashes.sort_unstable();
+            hashes.dedup();
+
+            let mut bitset = vec![0; optimal_num_of_bytes(hashes.len(), BLOOM_FILTER_FPP)];
+            for hash in hashes {
+                insert(&mut bitset, hash);
+            }
+            Ok((bloom_filter_column.leaf, bitset))
+        })
+        .collect()
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
@@ -147,12 +223,13 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    options: &[WriteOptions],
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = if parallel {
         POOL.install(|| {
@@ -161,6 +238,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(options)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -170,6 +248,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(options)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -185,17 +264,19 @@ fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    options: &[WriteOptions],
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = batch
         .columns()
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(options)
         .flat_map(func)
         .collect::<Vec<_>>();
 
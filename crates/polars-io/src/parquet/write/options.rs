@@ -1,12 +1,12 @@
-use polars_error::PolarsResult;
+use polars_error::{PolarsError, PolarsResult};
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding, FileEncryptionProperties,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +19,125 @@ pub struct ParquetWriteOptions {
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Columns for which bloom filters are written.
+    pub bloom_filter_columns: Vec<String>,
+    /// Write options of individual columns, which override the options above.
+    pub column_options: Vec<(String, ParquetColumnWriteOptions)>,
+    /// Encrypt the file with these keys.
+    pub encryption: Option<ParquetEncryptionOptions>,
+}
+
+/// The keys with which a Parquet file is encrypted, using AES-GCM.
+///
+/// The footer is encrypted with the footer key. If no column keys are given, all columns are
+/// encrypted with the footer key; otherwise only the given columns are encrypted, each with its
+/// own key. Keys must have 16 or 32 bytes.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetEncryptionOptions {
+    pub footer_key: Vec<u8>,
+    /// Stored in the file to let readers retrieve the footer key.
+    pub footer_key_metadata: Option<Vec<u8>>,
+    pub column_keys: Vec<ParquetColumnEncryptionKey>,
+}
+
+/// The key with which a column of a Parquet file is encrypted.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnEncryptionKey {
+    pub column: String,
+    pub key: Vec<u8>,
+    /// Stored in the file to let readers retrieve the key.
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+// Keys are left out of the debug output.
+impl std::fmt::Debug for ParquetEncryptionOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetEncryptionOptions")
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("column_keys", &self.column_keys)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for ParquetColumnEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetColumnEncryptionKey")
+            .field("column", &self.column)
+            .field("key_metadata", &self.key_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TryFrom<&ParquetEncryptionOptions> for FileEncryptionProperties {
+    type Error = PolarsError;
+
+    fn try_from(value: &ParquetEncryptionOptions) -> PolarsResult<Self> {
+        value.column_keys.iter().try_fold(
+            FileEncryptionProperties::try_new(
+                value.footer_key.clone(),
+                value.footer_key_metadata.clone(),
+            )?,
+            |properties, column_key| {
+                Ok(properties.try_with_column_key(
+                    vec![column_key.column.clone()],
+                    column_key.key.clone(),
+                    column_key.key_metadata.clone(),
+                )?)
+            },
+        )
+    }
+}
+
+/// Write options of a single column of a Parquet file.
+///
+/// Unset options fall back to the options of the file, or to a default that depends on the
+/// type of the column.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnWriteOptions {
+    /// The encoding of the values that are not dictionary encoded. Defaults to `Plain`.
+    pub encoding: Option<ParquetEncoding>,
+    /// Whether to dictionary encode the column. Defaults to `true` for integer, temporal, string
+    /// and binary columns.
+    pub dictionary: Option<bool>,
+    /// The maximum number of distinct values of a dictionary encoded row group. Row groups with
+    /// more distinct values fall back to `encoding`. If `None`, dictionary encoding is given up
+    /// when more than 75% of the values are distinct.
+    pub dictionary_max_cardinality: Option<usize>,
+    /// Data page compression of the column.
+    pub compression: Option<ParquetCompression>,
+}
+
+/// The encoding of the values of a column in a Parquet file.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Delta encoding of integers, which suits sorted and slowly changing values such as
+    /// timestamps.
+    DeltaBinaryPacked,
+    /// Delta encoding of the lengths of strings and binary values.
+    DeltaLengthByteArray,
+    /// Incremental encoding of strings and binary values, which suits sorted values with common
+    /// prefixes.
+    DeltaByteArray,
+    /// Splits the bytes of floats and integers into separate streams, which makes them compress
+    /// better.
+    ByteStreamSplit,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            ParquetEncoding::DeltaByteArray => Encoding::DeltaByteArray,
+            ParquetEncoding::ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
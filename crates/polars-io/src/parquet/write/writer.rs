@@ -4,13 +4,16 @@ use std::sync::Mutex;
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, StatisticsOptions,
-    Version, WriteOptions,
+    to_parquet_schema, transverse, CompressionOptions, DictionaryOptions, Encoding, FileWriter,
+    StatisticsOptions, Version, WriteOptions,
 };
 
-use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::batched_writer::{BatchedWriter, BloomFilterColumn};
+use super::options::{
+    ParquetColumnWriteOptions, ParquetCompression, ParquetEncoding, ParquetEncryptionOptions,
+};
 use super::ParquetWriteOptions;
+use crate::parquet::bloom_filter::supports_bloom_filter;
 use crate::prelude::chunk_df_for_writing;
 use crate::shared::schema_to_arrow_checked;
 
@@ -24,6 +27,9 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_bloom_filter_columns(self.bloom_filter_columns.clone())
+            .with_column_options(self.column_options.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -41,6 +47,12 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Columns for which bloom filters are written
+    bloom_filter_columns: Vec<String>,
+    /// Write options of individual columns
+    column_options: Vec<(String, ParquetColumnWriteOptions)>,
+    /// Keys to encrypt the file with
+    encryption: Option<ParquetEncryptionOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -59,6 +71,9 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            bloom_filter_columns: vec![],
+            column_options: vec![],
+            encryption: None,
         }
     }
 
@@ -96,19 +111,93 @@ where
         self
     }
 
-    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+    /// Write a bloom filter for each of the given columns in every row group.
+    ///
+    /// Bloom filters allow readers to skip row groups when filtering on equality or membership,
+    /// and are most useful for columns with many distinct values.
+    pub fn with_bloom_filter_columns(mut self, columns: Vec<String>) -> Self {
+        self.bloom_filter_columns = columns;
+        self
+    }
+
+    /// Set the encoding, dictionary encoding and compression of individual columns.
+    ///
+    /// For example, `ByteStreamSplit` makes floating point data compress better and
+    /// `DeltaBinaryPacked` shrinks sorted integers and timestamps.
+    pub fn with_column_options(
+        mut self,
+        options: Vec<(String, ParquetColumnWriteOptions)>,
+    ) -> Self {
+        self.column_options = options;
+        self
+    }
+
+    /// Encrypt the file with AES-GCM, using the footer key and, optionally, keys of individual
+    /// columns.
+    ///
+    /// Readers need the footer key to read the file, and the key of a column to read that column.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    pub fn batched(self, polars_schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let schema = schema_to_arrow_checked(polars_schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let mut encodings = get_encodings(&schema);
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let mut column_options = vec![options; schema.fields.len()];
+        for (name, column_write_options) in &self.column_options {
+            let (column, _, dtype) = polars_schema.try_get_full(name)?;
+            let (column_encodings, write_options) = materialize_column_options(
+                name,
+                dtype,
+                &encodings[column],
+                column_write_options,
+                options,
+            )?;
+            if let Some(column_encodings) = column_encodings {
+                encodings[column] = column_encodings;
+            }
+            column_options[column] = write_options;
+        }
+        let bloom_filter_columns = self
+            .bloom_filter_columns
+            .iter()
+            .map(|name| {
+                let (column, _, dtype) = polars_schema.try_get_full(name)?;
+                polars_ensure!(
+                    supports_bloom_filter(dtype),
+                    InvalidOperation: "cannot write a bloom filter for column '{}' of type {}", name, dtype
+                );
+                let leaf = encodings[..column].iter().map(Vec::len).sum::<usize>();
+                let physical_type = parquet_schema.columns()[leaf]
+                    .descriptor
+                    .primitive_type
+                    .physical_type;
+                Ok(BloomFilterColumn {
+                    column,
+                    leaf,
+                    physical_type,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut writer = FileWriter::try_new(self.writer, schema, options)?;
+        if let Some(encryption) = &self.encryption {
+            for column_key in &encryption.column_keys {
+                polars_schema.try_get(&column_key.column)?;
+            }
+            writer = writer.with_encryption(encryption.try_into()?)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
-            options,
+            options: column_options,
             parallel: self.parallel,
+            bloom_filter_columns,
         })
     }
 
@@ -118,6 +207,7 @@ where
             compression: self.compression,
             version: Version::V1,
             data_page_size: self.data_page_size,
+            dictionary: DictionaryOptions::default(),
         }
     }
 
@@ -138,6 +228,63 @@ fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
         .collect()
 }
 
+/// Returns the encodings (if they are set) and the write options of a column.
+fn materialize_column_options(
+    name: &str,
+    dtype: &DataType,
+    default_encodings: &[Encoding],
+    column_options: &ParquetColumnWriteOptions,
+    options: WriteOptions,
+) -> PolarsResult<(Option<Vec<Encoding>>, WriteOptions)> {
+    let mut options = options;
+    if let Some(compression) = column_options.compression {
+        options.compression = compression.into();
+    }
+    options.dictionary.max_cardinality = column_options.dictionary_max_cardinality;
+
+    if column_options.encoding.is_none() && column_options.dictionary.is_none() {
+        return Ok((None, options));
+    }
+    polars_ensure!(
+        !dtype.is_nested(),
+        InvalidOperation: "cannot set the encoding of nested column '{}'", name
+    );
+    polars_ensure!(
+        !(dtype.is_categorical() || dtype.is_enum()),
+        InvalidOperation: "categorical column '{}' is always dictionary encoded", name
+    );
+    let encoding = column_options.encoding.unwrap_or(ParquetEncoding::Plain);
+    polars_ensure!(
+        supports_encoding(dtype, encoding),
+        InvalidOperation: "cannot write column '{}' of type {} with encoding {:?}", name, dtype, encoding
+    );
+    options.dictionary.fallback = encoding.into();
+
+    let dictionary = column_options
+        .dictionary
+        .unwrap_or(default_encodings == [Encoding::RleDictionary]);
+    let encoding = if dictionary {
+        Encoding::RleDictionary
+    } else {
+        encoding.into()
+    };
+    Ok((Some(vec![encoding]), options))
+}
+
+/// Whether the values of a (non-nested) column can be written with the given encoding.
+fn supports_encoding(dtype: &DataType, encoding: ParquetEncoding) -> bool {
+    use ParquetEncoding::*;
+    let is_integer = dtype.is_integer() || dtype.is_temporal();
+    match encoding {
+        Plain => true,
+        DeltaBinaryPacked => is_integer,
+        DeltaLengthByteArray | DeltaByteArray => {
+            matches!(dtype, DataType::String | DataType::Binary)
+        },
+        ByteStreamSplit => is_integer || dtype.is_float(),
+    }
+}
+
 /// Declare encodings
 fn encoding_map(data_type: &ArrowDataType) -> Encoding {
     match data_type.to_physical_type() {
@@ -19,7 +19,7 @@ pub trait StatsEvaluator {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool>;
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 pub fn apply_predicate(
     df: &mut DataFrame,
     predicate: Option<&dyn PhysicalIoExpr>,
@@ -38,12 +38,21 @@ pub fn apply_predicate(
     Ok(())
 }
 
+/// A probabilistic filter over the values of a column, such as a bloom filter.
+pub trait ValueFilter: Send + Sync + std::fmt::Debug {
+    /// Returns whether any of the non-null `values` may be in the column.
+    ///
+    /// A filter may return false positives, but never false negatives.
+    fn may_contain_any(&self, values: &Series) -> bool;
+}
+
 /// Statistics of the values in a column.
 ///
 /// The following statistics are tracked for each row group:
 /// - Null count
 /// - Minimum value
 /// - Maximum value
+/// - Value filter (e.g. a bloom filter)
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -52,6 +61,8 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value_filter: Option<Arc<dyn ValueFilter>>,
 }
 
 impl ColumnStats {
@@ -67,9 +78,16 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            value_filter: None,
         }
     }
 
+    /// Sets the [`ValueFilter`] of the column.
+    pub fn with_value_filter(mut self, value_filter: Arc<dyn ValueFilter>) -> Self {
+        self.value_filter = Some(value_filter);
+        self
+    }
+
     /// Constructs a new [`ColumnStats`] with only the [`Field`] information and no statistics.
     pub fn from_field(field: Field) -> Self {
         Self {
@@ -77,6 +95,7 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            value_filter: None,
         }
     }
 
@@ -88,6 +107,7 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            value_filter: None,
         }
     }
 
@@ -115,6 +135,17 @@ impl ColumnStats {
         self.max_value.as_ref()
     }
 
+    /// Returns whether any of the `values` may be in the column, as determined by its
+    /// [`ValueFilter`].
+    ///
+    /// Returns `true` if the column has no [`ValueFilter`] or `values` contains nulls.
+    pub fn may_contain_any(&self, values: &Series) -> bool {
+        match &self.value_filter {
+            Some(value_filter) => values.null_count() > 0 || value_filter.may_contain_any(values),
+            None => true,
+        }
+    }
+
     /// Returns the null count of the column.
     pub fn null_count(&self) -> Option<usize> {
         match self.dtype() {
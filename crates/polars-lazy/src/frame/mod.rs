@@ -13,12 +13,15 @@ pub mod pivot;
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro"
 ))]
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
@@ -34,6 +37,8 @@ use polars_core::prelude::*;
 use polars_expr::{create_physical_expr, ExpressionConversionState};
 use polars_io::RowIndex;
 use polars_mem_engine::{create_physical_plan, Executor};
+#[cfg(feature = "interval_join")]
+use polars_ops::frame::IntervalJoinOptions;
 use polars_ops::frame::JoinCoalesce;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
@@ -875,14 +880,40 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into an avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(self, path: impl AsRef<Path>, options: AvroWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            SinkType::File {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Avro(options),
+            },
+            "collect().write_avro()",
+        )
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
         feature = "cloud_write",
         feature = "csv",
         feature = "json",
+        feature = "avro",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
+        #[cfg(feature = "new_streaming")]
+        if self.opt_state.contains(OptState::NEW_STREAMING) {
+            self.logical_plan = DslPlan::Sink {
+                input: Arc::new(self.logical_plan),
+                payload,
+            };
+            let alp_plan = self.to_alp_optimized()?;
+            polars_stream::run_query(alp_plan.lp_top, alp_plan.lp_arena, &alp_plan.expr_arena)?;
+            return Ok(());
+        }
+
         self.opt_state |= OptState::STREAMING;
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
@@ -1011,6 +1042,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                grouping_sets: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1023,6 +1055,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                grouping_sets: None,
             }
         }
     }
@@ -1059,6 +1092,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            grouping_sets: None,
             dynamic_options: None,
             rolling_options: Some(options),
         }
@@ -1104,6 +1138,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            grouping_sets: None,
             dynamic_options: Some(options),
             rolling_options: None,
         }
@@ -1125,6 +1160,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                grouping_sets: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1137,10 +1173,63 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                grouping_sets: None,
             }
         }
     }
 
+    /// Group by each of the `grouping_sets` of the keys `by` in a single pass over this
+    /// query, as in `GROUP BY GROUPING SETS (...)` in SQL.
+    ///
+    /// The groups of all sets are concatenated, the keys that are not part of a set
+    /// are null and an extra `UInt32` column identifies the set of a row. See
+    /// [`GroupingSets`] for the layout of that column and [`GroupingSets::grouping`]
+    /// for the `GROUPING(...)` indicator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       // Sales per region and product, per region, and in total.
+    ///       df.lazy()
+    ///        .group_by_grouping_sets(
+    ///            [col("region"), col("product")],
+    ///            GroupingSets::new(vec![vec![0, 1], vec![0], vec![]]),
+    ///        )
+    ///        .agg([col("sales").sum()])
+    /// }
+    /// ```
+    pub fn group_by_grouping_sets<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        grouping_sets: GroupingSets,
+    ) -> LazyGroupBy {
+        let mut lgb = self.group_by(by);
+        lgb.grouping_sets = Some(grouping_sets);
+        lgb
+    }
+
+    /// Group by all prefixes of the keys `by`, as in `GROUP BY ROLLUP (...)` in SQL.
+    ///
+    /// See [`group_by_grouping_sets`][`Self::group_by_grouping_sets`].
+    pub fn rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
+        let grouping_sets = GroupingSets::rollup(by.as_ref().len());
+        self.group_by_grouping_sets(by, grouping_sets)
+    }
+
+    /// Group by all subsets of the keys `by`, as in `GROUP BY CUBE (...)` in SQL.
+    /// Fails if there are more than 12 keys, which would exceed
+    /// [`GroupingSets::MAX_SETS`].
+    ///
+    /// See [`group_by_grouping_sets`][`Self::group_by_grouping_sets`].
+    pub fn cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> PolarsResult<LazyGroupBy> {
+        let grouping_sets = GroupingSets::cube(by.as_ref().len())?;
+        Ok(self.group_by_grouping_sets(by, grouping_sets))
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1178,6 +1267,32 @@ impl LazyFrame {
         )
     }
 
+    /// Join this query with another lazy query on the rows for which all `predicates` hold.
+    ///
+    /// The predicates refer to the columns as they are named in the cartesian product
+    /// of both frames, so columns of `other` that already exist in this frame are
+    /// suffixed with `"_right"`. Comparisons between the tables are executed as an
+    /// equi join or an inequality join instead of a cross join followed by a filter.
+    /// The row order of the result is not guaranteed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn attribute_events(sessions: LazyFrame, events: LazyFrame) -> LazyFrame {
+    ///     sessions.join_where(
+    ///         events,
+    ///         [col("start").lt_eq(col("ts")), col("ts").lt(col("end"))],
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "iejoin")]
+    pub fn join_where<E: AsRef<[Expr]>>(self, other: LazyFrame, predicates: E) -> LazyFrame {
+        self.join_builder().with(other).join_where(predicates)
+    }
+
     /// Left outer join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1770,6 +1885,7 @@ pub struct LazyGroupBy {
     opt_state: OptState,
     keys: Vec<Expr>,
     maintain_order: bool,
+    grouping_sets: Option<GroupingSets>,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
@@ -1817,6 +1933,7 @@ impl LazyGroupBy {
                 aggs,
                 None,
                 self.maintain_order,
+                self.grouping_sets,
                 self.dynamic_options,
                 self.rolling_options,
             )
@@ -1824,7 +1941,13 @@ impl LazyGroupBy {
 
         #[cfg(not(feature = "dynamic_group_by"))]
         let lp = DslBuilder::from(self.logical_plan)
-            .group_by(self.keys, aggs, None, self.maintain_order)
+            .group_by(
+                self.keys,
+                aggs,
+                None,
+                self.maintain_order,
+                self.grouping_sets,
+            )
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -1865,11 +1988,15 @@ impl LazyGroupBy {
         let options = GroupbyOptions {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
+            grouping_sets: self.grouping_sets,
             slice: None,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            grouping_sets: self.grouping_sets,
+            slice: None,
+        };
 
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
@@ -1896,6 +2023,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    join_where: bool,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1909,6 +2037,7 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             join_nulls: false,
+            join_where: false,
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
@@ -1989,6 +2118,50 @@ impl JoinBuilder {
         self
     }
 
+    /// Join the rows of which the intervals overlap.
+    ///
+    /// The first two expressions of `left_on` and `right_on` are the start and
+    /// the end of the intervals, any further expressions have to be equal.
+    ///
+    /// ```rust
+    /// use polars_lazy::prelude::*;
+    /// use polars_ops::prelude::*;
+    ///
+    /// /// Find the genes that overlap with a read on the same chromosome.
+    /// fn example(reads: LazyFrame, genes: LazyFrame) -> LazyFrame {
+    ///     reads
+    ///         .join_builder()
+    ///         .with(genes)
+    ///         .left_on([col("start"), col("end"), col("chrom")])
+    ///         .right_on([col("gene_start"), col("gene_end"), col("chrom")])
+    ///         .interval(IntervalJoinOptions {
+    ///             how: IntervalJoinType::Left,
+    ///             closed: ClosedInterval::Left,
+    ///         })
+    ///         .finish()
+    /// }
+    /// ```
+    #[cfg(feature = "interval_join")]
+    pub fn interval(mut self, options: IntervalJoinOptions) -> Self {
+        self.how = JoinType::Interval(options);
+        self
+    }
+
+    /// Finish the builder as a join on the rows for which all `predicates` hold,
+    /// see [`LazyFrame::join_where`]. The join type and keys are ignored.
+    #[cfg(feature = "iejoin")]
+    pub fn join_where<E: AsRef<[Expr]>>(mut self, predicates: E) -> LazyFrame {
+        self.how = JoinType::Cross;
+        self.left_on.clear();
+        self.right_on.clear();
+        self.join_where = true;
+        // Separate filters, so that predicates on a single table are pushed down.
+        predicates
+            .as_ref()
+            .iter()
+            .fold(self.finish(), |lf, predicate| lf.filter(predicate.clone()))
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -2019,6 +2192,7 @@ impl JoinBuilder {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
                     args,
+                    join_where: self.join_where,
                     ..Default::default()
                 }
                 .into(),
@@ -28,7 +28,9 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
     let supported = match args.how {
         #[cfg(feature = "cross_join")]
         JoinType::Cross => true,
-        JoinType::Left => true,
+        JoinType::Left | JoinType::Right => true,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => true,
         JoinType::Inner => {
             // no-coalescing not yet supported in streaming
             matches!(
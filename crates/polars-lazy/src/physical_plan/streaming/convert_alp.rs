@@ -366,7 +366,7 @@ pub(crate) fn insert_streaming_nodes(
                 }
                 let input_schema = lp_arena.get(*input).schema(lp_arena);
                 #[allow(unused_mut)]
-                let mut can_stream = true;
+                let mut can_stream = options.grouping_sets.is_none();
 
                 #[cfg(feature = "dynamic_group_by")]
                 {
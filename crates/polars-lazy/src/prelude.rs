@@ -1,4 +1,6 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -16,8 +18,8 @@ pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{GroupingSets, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::read::{FileDecryptionProperties, ParallelStrategy};
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
@@ -21,6 +21,8 @@ pub struct ScanArgsParquet {
     /// Expand path given via globbing rules.
     pub glob: bool,
     pub include_file_paths: Option<Arc<str>>,
+    /// Decrypt encrypted files with these properties.
+    pub decryption: Option<FileDecryptionProperties>,
 }
 
 impl Default for ScanArgsParquet {
@@ -37,6 +39,7 @@ impl Default for ScanArgsParquet {
             cache: true,
             glob: true,
             include_file_paths: None,
+            decryption: None,
         }
     }
 }
@@ -74,6 +77,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.hive_options,
             self.args.glob,
             self.args.include_file_paths,
+            self.args.decryption,
         )?
         .build()
         .into();
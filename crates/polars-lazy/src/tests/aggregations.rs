@@ -571,3 +571,74 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df![
+        "region" => ["eu", "eu", "us", "us", "us"],
+        "product" => ["a", "b", "a", "a", "b"],
+        "sales" => [1, 2, 3, 4, 5],
+    ]?;
+    let sort = SortMultipleOptions::default().with_nulls_last(true);
+
+    let out = df
+        .clone()
+        .lazy()
+        .rollup([col("region"), col("product")])
+        .agg([col("sales").sum()])
+        .sort(["region", "product"], sort.clone())
+        .collect()?;
+    let expected = df![
+        "region" => [Some("eu"), Some("eu"), Some("eu"), Some("us"), Some("us"), Some("us"), None],
+        "product" => [Some("a"), Some("b"), None, Some("a"), Some("b"), None, None],
+        "sales" => [1, 2, 3, 7, 5, 12, 15],
+        "grouping_id" => [0u32, 0, 2, 0, 0, 2, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let grouping_sets = GroupingSets::new(vec![vec![1], vec![]]).with_id_name("gid");
+    let out = df
+        .lazy()
+        .group_by_grouping_sets([col("region"), col("product")], grouping_sets.clone())
+        .agg([col("sales").sum(), len()])
+        .with_column(grouping_sets.grouping(&[0, 1]).alias("grouping"))
+        .sort(["product"], sort)
+        .collect()?;
+    let expected = df![
+        "region" => [None::<&str>, None, None],
+        "product" => [Some("a"), Some("b"), None],
+        "sales" => [8, 7, 15],
+        "len" => [3 as IdxSize, 2, 5],
+        "gid" => [1u32, 1, 3],
+        "grouping" => [2u32, 2, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_group_by_cube() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 1, 2],
+        "b" => [1, 2, 2],
+        "x" => [10, 20, 30],
+    ]?;
+
+    let out = df
+        .lazy()
+        .cube([col("a"), col("b")])?
+        .agg([col("x").sum()])
+        .sort(
+            ["grouping_id", "a", "b"],
+            SortMultipleOptions::default().with_nulls_last(true),
+        )
+        .collect()?;
+    let expected = df![
+        "a" => [Some(1), Some(1), Some(2), None, None, Some(1), Some(2), None],
+        "b" => [Some(1), Some(2), Some(2), Some(1), Some(2), None, None, None],
+        "x" => [10, 20, 30, 10, 50, 30, 30, 60],
+        "grouping_id" => [0u32, 0, 0, 1, 1, 2, 2, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
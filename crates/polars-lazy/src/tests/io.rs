@@ -355,6 +355,235 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "parquet", feature = "is_in"))]
+fn test_parquet_bloom_filters() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    // Even ids only, so that the odd ids are within the min/max statistics of a row group.
+    let ids = (0..1000i64).map(|i| i * 2).collect::<Vec<_>>();
+    let names = ids.iter().map(|i| format!("name{i}")).collect::<Vec<_>>();
+    let mut df = df![
+        "id" => ids,
+        "name" => names,
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_parquet_bloom_filters.parquet");
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_row_group_size(Some(500))
+        .with_bloom_filter_columns(vec!["id".into(), "name".into()])
+        .finish(&mut df)?;
+
+    // The bloom filters of a row group are written right after it.
+    let metadata = ParquetReader::new(std::fs::File::open(&path).unwrap())
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 2);
+    let (first, second) = (&metadata.row_groups[0], &metadata.row_groups[1]);
+    for column in first.columns() {
+        let offset = column.metadata().bloom_filter_offset.unwrap();
+        assert!(offset > column.byte_range().0 as i64);
+        assert!(offset < second.columns()[0].byte_range().0 as i64);
+    }
+
+    let scan = || LazyFrame::scan_parquet(&path, ScanArgsParquet::default()).unwrap();
+
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan().filter(col("id").eq(lit(501i64))).collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    let out = scan().filter(lit("name501").eq(col("name"))).collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    let out = scan()
+        .filter(col("id").is_in(lit(Series::new("", [1i64, 3, 1001]))))
+        .collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+
+    let out = scan().filter(col("id").eq(lit(500i64))).collect()?;
+    assert_eq!(out.shape(), (1, 2));
+    let out = scan()
+        .filter(col("name").is_in(lit(Series::new("", ["name2", "name1002"]))))
+        .collect()?;
+    assert_eq!(out.shape(), (2, 2));
+
+    // Bloom filters are not supported for floats.
+    let mut df = df!["x" => [1.0f64]]?;
+    let f = std::fs::File::create(&path).unwrap();
+    assert!(ParquetWriter::new(f)
+        .with_bloom_filter_columns(vec!["x".into()])
+        .finish(&mut df)
+        .is_err());
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    // A single row group of many pages, with a gap in the ids that lies within the min/max
+    // statistics of the row group, but not within those of most pages.
+    let ids = (0..5000i64).chain(10000..15000).collect::<Vec<_>>();
+    let names = ids.iter().map(|i| format!("name{i}")).collect::<Vec<_>>();
+    let lists = ids
+        .iter()
+        .map(|i| Series::new("", [*i, *i + 1]))
+        .collect::<Vec<_>>();
+    let mut df = df![
+        "id" => ids,
+        "name" => names,
+        "list" => lists,
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_data_page_size(Some(1024))
+        .finish(&mut df)?;
+
+    let scan = |parallel| {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args).unwrap()
+    };
+
+    for parallel in [ParallelStrategy::Columns, ParallelStrategy::RowGroups] {
+        let out = scan(parallel)
+            .filter(col("id").eq(lit(7000i64)))
+            .collect()?;
+        assert_eq!(out.shape(), (0, 3));
+
+        let out = scan(parallel)
+            .filter(col("id").gt(lit(4990i64)).and(col("id").lt(lit(10005i64))))
+            .collect()?;
+        let expected =
+            df.filter(&(df.column("id")?.gt(4990i64)? & df.column("id")?.lt(10005i64)?))?;
+        assert_eq!(out.shape(), (14, 3));
+        assert!(out.equals(&expected));
+
+        let out = scan(parallel)
+            .filter(col("name").eq(lit("name12345")))
+            .collect()?;
+        assert_eq!(out.column("id")?.get(0)?, AnyValue::Int64(12345));
+        assert_eq!(
+            out.column("list")?.list()?.get_as_series(0).unwrap().len(),
+            2
+        );
+    }
+
+    // Pages are not skipped when the rows need to be numbered.
+    let out = scan(ParallelStrategy::Auto)
+        .with_row_index("index", None)
+        .filter(col("id").eq(lit(14999i64)))
+        .collect()?;
+    assert_eq!(
+        out.column("index")?.get(0)?,
+        AnyValue::from(9999 as IdxSize)
+    );
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index_null_counts() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    // The pages of the narrow column span many pages of the wide column, so the predicate is
+    // evaluated on ranges of rows that cover only a part of a page of the narrow column.
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index_null_counts.parquet");
+    for n_nulls in 1..30 {
+        let n = 2000;
+        let a = (0..n)
+            .map(|i| (!(100..100 + n_nulls).contains(&i)).then_some(i as i32))
+            .collect::<Vec<_>>();
+        let wide = (0..n).map(|i| format!("{i:0>100}")).collect::<Vec<_>>();
+        let mut df = df![
+            "a" => a,
+            "wide" => wide,
+        ]?;
+        let f = std::fs::File::create(&path).unwrap();
+        ParquetWriter::new(f)
+            .with_data_page_size(Some(1024))
+            .finish(&mut df)?;
+
+        let out = LazyFrame::scan_parquet(&path, Default::default())?
+            .filter(col("a").is_not_null())
+            .collect()?;
+        assert_eq!(out.height(), n - n_nulls, "{n_nulls} nulls");
+    }
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_scan_encrypted_parquet() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let mut df = df![
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name{i}")).collect::<Vec<_>>(),
+    ]?;
+    let key = vec![1u8; 16];
+    let path = std::env::temp_dir().join("polars_test_scan_encrypted_parquet.parquet");
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_row_group_size(Some(300))
+        .with_encryption(Some(ParquetEncryptionOptions {
+            footer_key: key.clone(),
+            footer_key_metadata: Some(b"footer".to_vec()),
+            column_keys: vec![],
+        }))
+        .finish(&mut df)?;
+
+    let keys = PlHashMap::from_iter([(b"footer".to_vec(), key)]);
+    let decryption = FileDecryptionProperties::new(Arc::new(keys));
+    let scan_files = |paths: Arc<[std::path::PathBuf]>, decryption| {
+        let args = ScanArgsParquet {
+            decryption,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet_files(paths, args).unwrap()
+    };
+    assert!(scan_files([path.clone()].into(), None).collect().is_err());
+    let scan = || scan_files([path.clone()].into(), Some(decryption.clone()));
+
+    let out = scan().collect()?;
+    assert!(out.equals(&df));
+    let out = scan().filter(col("id").gt_eq(lit(900i64))).collect()?;
+    assert_eq!(out.shape(), (100, 2));
+    let out = scan().select([len()]).collect()?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::from(1000 as IdxSize));
+    #[cfg(feature = "streaming")]
+    {
+        let out = scan()
+            .filter(col("name").eq(lit("name42")))
+            .with_streaming(true)
+            .collect()?;
+        assert_eq!(out.column("id")?.get(0)?, AnyValue::Int64(42));
+    }
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = scan().with_new_streaming(true).collect()?;
+        assert!(out.equals(&df));
+    }
+    #[cfg(feature = "cloud")]
+    {
+        // Read the file twice with the async reader, the metadata of the second file is fetched
+        // by the reader.
+        std::env::set_var("POLARS_FORCE_ASYNC", "1");
+        let out = scan_files(
+            [path.clone(), path.clone()].into(),
+            Some(decryption.clone()),
+        )
+        .filter(col("id").gt_eq(lit(900i64)))
+        .collect();
+        std::env::remove_var("POLARS_FORCE_ASYNC");
+        assert_eq!(out?.shape(), (200, 2));
+    }
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -432,6 +661,76 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "avro", not(target_os = "windows")))]
+fn test_avro_globbing() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let glob = "../../examples/datasets/foods*.avro";
+    let df = LazyFrame::scan_avro(glob, Default::default())?.collect()?;
+    assert_eq!(df.shape(), (54, 4));
+    let cal = df.column("calories")?;
+    assert_eq!(cal.get(0)?, AnyValue::Int64(45));
+    assert_eq!(cal.get(53)?, AnyValue::Int64(194));
+
+    // The slice spans both files.
+    let out = LazyFrame::scan_avro(glob, Default::default())?
+        .slice(0, 30)
+        .collect()?;
+    assert!(out.equals(&df.slice(0, 30)));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_avro_projection_and_predicate() -> PolarsResult<()> {
+    init_files();
+    let expected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(FOODS_CSV.into()))?
+        .finish()?;
+
+    // Projected in a different order than in the file.
+    let out = LazyFrame::scan_avro(FOODS_AVRO, Default::default())?
+        .select([col("sugars_g"), col("category")])
+        .collect()?;
+    assert!(out.equals(&expected.select(["sugars_g", "category"])?));
+
+    let out = LazyFrame::scan_avro(FOODS_AVRO, Default::default())?
+        .with_row_index("index", None)
+        .filter(col("calories").gt(lit(100)))
+        .select([col("index"), col("calories")])
+        .collect()?;
+    let mask = expected.column("calories")?.gt(100)?;
+    let expected_index = IdxCa::from_vec("index", (0..27).collect()).filter(&mask)?;
+    assert!(out.column("index")?.equals(&expected_index.into_series()));
+    assert_eq!(out.height(), 11);
+
+    // Only the number of rows is needed.
+    let out = LazyFrame::scan_avro(FOODS_AVRO, Default::default())?
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::from(27 as IdxSize));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "avro", feature = "streaming"))]
+fn test_sink_avro() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_sink.avro");
+    let expected = LazyCsvReader::new(FOODS_CSV).finish()?.collect()?;
+    LazyCsvReader::new(FOODS_CSV)
+        .finish()?
+        .sink_avro(&path, Default::default())?;
+
+    let out = LazyFrame::scan_avro(&path, Default::default())?.collect()?;
+    std::fs::remove_file(&path)?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
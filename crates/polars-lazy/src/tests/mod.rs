@@ -5,6 +5,8 @@ mod cse;
 #[cfg(feature = "parquet")]
 mod io;
 mod logical;
+#[cfg(feature = "new_streaming")]
+mod new_streaming;
 mod optimization_checks;
 mod predicate_queries;
 mod projection_queries;
@@ -59,6 +61,8 @@ static NUTRI_SCORE_NULL_COLUMN_PARQUET: &str = "../../examples/datasets/null_nut
 static FOODS_CSV: &str = "../../examples/datasets/foods1.csv";
 #[cfg(feature = "ipc")]
 static FOODS_IPC: &str = "../../examples/datasets/foods1.ipc";
+#[cfg(feature = "avro")]
+static FOODS_AVRO: &str = "../../examples/datasets/foods1.avro";
 
 #[cfg(feature = "csv")]
 fn scan_foods_csv() -> LazyFrame {
@@ -78,7 +82,10 @@ fn init_files() {
         "../../examples/datasets/foods2.csv",
         "../../examples/datasets/null_nutriscore.csv",
     ] {
-        for ext in [".parquet", ".ipc", ".ndjson"] {
+        for ext in [".parquet", ".ipc", ".ndjson", ".avro"] {
+            if ext == ".avro" && cfg!(not(feature = "avro")) {
+                continue;
+            }
             let out_path = path.replace(".csv", ext);
 
             if std::fs::metadata(&out_path).is_err() {
@@ -108,6 +115,12 @@ fn init_files() {
                             JsonWriter::new(f).finish(&mut df).unwrap()
                         }
                     },
+                    ".avro" => {
+                        #[cfg(feature = "avro")]
+                        {
+                            polars_io::avro::AvroWriter::new(f).finish(&mut df).unwrap()
+                        }
+                    },
                     _ => panic!(),
                 }
             }
@@ -0,0 +1,366 @@
+use super::*;
+
+fn assert_new_streaming(q: LazyFrame) {
+    let out = q.clone().with_new_streaming(true).collect().unwrap();
+    let expected = q.collect().unwrap();
+    assert_eq!(out, expected);
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_new_streaming_parquet_scan() -> PolarsResult<()> {
+    init_files();
+    let glob = "../../examples/datasets/foods*.parquet";
+    let q = LazyFrame::scan_parquet(glob, Default::default())?;
+
+    assert_new_streaming(q.clone());
+    assert_new_streaming(q.clone().with_row_index("idx", Some(3)).slice(25, 40));
+    assert_new_streaming(
+        q.with_row_index("idx", None)
+            .slice(10, 30)
+            .filter(col("fats_g").gt(lit(1))),
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_new_streaming_ipc_scan() -> PolarsResult<()> {
+    init_files();
+    let glob = "../../examples/datasets/foods*.ipc";
+    let q = LazyFrame::scan_ipc(glob, Default::default())?;
+
+    assert_new_streaming(q.clone());
+    assert_new_streaming(q.with_row_index("idx", None).slice(25, 40));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_new_streaming_csv_scan() -> PolarsResult<()> {
+    let q = LazyCsvReader::new(GLOB_CSV).finish()?;
+
+    assert_new_streaming(q.clone());
+    assert_new_streaming(q.clone().with_row_index("idx", None).slice(30, 50));
+    assert_new_streaming(q.slice(200, 10));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_new_streaming_ndjson_scan() -> PolarsResult<()> {
+    init_files();
+    let glob = "../../examples/datasets/foods*.ndjson";
+    let q = LazyJsonLineReader::new(glob).finish()?;
+
+    assert_new_streaming(q.clone());
+    assert_new_streaming(
+        q.with_row_index("idx", None)
+            .slice(25, 40)
+            .filter(col("calories").gt(lit(50))),
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_new_streaming_avro_scan() -> PolarsResult<()> {
+    init_files();
+    let glob = "../../examples/datasets/foods*.avro";
+    let q = LazyFrame::scan_avro(glob, Default::default())?;
+
+    assert_new_streaming(q.clone());
+    assert_new_streaming(q.with_row_index("idx", None).slice(25, 20));
+
+    // A file of many blocks, which are emitted in batches.
+    let mut df = df![
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("b{i}")).collect::<Vec<_>>(),
+    ]?;
+    for _ in 0..6 {
+        df.vstack_mut(&df.clone())?;
+    }
+    assert!(df.n_chunks() > 32);
+    let path = std::env::temp_dir().join("polars_test_new_streaming_avro_scan.avro");
+    polars_io::avro::AvroWriter::new(std::fs::File::create(&path).unwrap()).finish(&mut df)?;
+
+    let q = LazyFrame::scan_avro(&path, Default::default())?;
+    assert_new_streaming(q.clone());
+    assert_new_streaming(
+        q.clone()
+            .with_row_index("idx", Some(10))
+            .slice(150, 3000)
+            .select([col("b"), col("idx")]),
+    );
+    assert_new_streaming(q.clone().with_row_index("idx", None).limit(1000));
+    assert_new_streaming(q.filter(col("a").eq(lit(42i64))));
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+fn ooc_df(n: i64) -> DataFrame {
+    df![
+        "key" => (0..n).map(|i| (i * 7919) % 1000).collect::<Vec<_>>(),
+        "value" => (0..n).map(|i| if i % 13 == 0 { None } else { Some(i as f64) }).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_new_streaming_force_ooc_sort() {
+    // Each pipeline only spills once it buffered a morsel worth of rows.
+    let q = ooc_df(500_000).lazy().sort(
+        ["key"],
+        SortMultipleOptions::default().with_maintain_order(true),
+    );
+    polars_stream::with_force_spill(|| assert_new_streaming(q));
+}
+
+#[test]
+fn test_new_streaming_force_ooc_group_by() {
+    let q = ooc_df(50_000)
+        .lazy()
+        .group_by([col("key")])
+        .agg([col("value").sum(), col("value").count().alias("count")])
+        .sort(["key"], Default::default());
+    polars_stream::with_force_spill(|| assert_new_streaming(q));
+}
+
+#[test]
+fn test_new_streaming_force_ooc_join() {
+    let right = df![
+        "key" => (0..1000i64).collect::<Vec<_>>(),
+        "payload" => (0..1000i64).map(|i| i * 2).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    let q = ooc_df(50_000)
+        .lazy()
+        .with_row_index("idx", None)
+        .join(
+            right.lazy(),
+            [col("key")],
+            [col("key")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["idx"], Default::default());
+    polars_stream::with_force_spill(|| assert_new_streaming(q));
+}
+
+#[test]
+fn test_new_streaming_join() {
+    let left = df![
+        "key" => [Some(1i64), Some(2), None, Some(3), Some(2), Some(5)],
+        "a" => ["a", "b", "c", "d", "e", "f"],
+    ]
+    .unwrap()
+    .lazy()
+    .with_row_index("idx", None);
+    let right = df![
+        "key" => [Some(2i64), Some(3), Some(3), None, Some(4)],
+        "b" => [1, 2, 3, 4, 5],
+    ]
+    .unwrap()
+    .lazy();
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        let q = left
+            .clone()
+            .join(
+                right.clone(),
+                [col("key")],
+                [col("key")],
+                JoinArgs::new(how),
+            )
+            .sort(["idx", "b"], Default::default());
+        assert_new_streaming(q);
+    }
+
+    #[cfg(feature = "semi_anti_join")]
+    for how in [JoinType::Semi, JoinType::Anti] {
+        let q = left
+            .clone()
+            .join(
+                right.clone(),
+                [col("key")],
+                [col("key")],
+                JoinArgs::new(how),
+            )
+            .sort(["idx"], Default::default());
+        assert_new_streaming(q);
+    }
+}
+
+#[test]
+fn test_new_streaming_group_by() {
+    let n = 20_000i64;
+    let df = df![
+        "key" => (0..n).map(|i| if i % 17 == 0 { None } else { Some(i % 100) }).collect::<Vec<_>>(),
+        "value" => (0..n).map(|i| if i % 11 == 0 { None } else { Some(i % 37) }).collect::<Vec<_>>(),
+        "by" => (0..n).map(|i| (i * 7919) % n).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+
+    let q = df
+        .lazy()
+        .group_by([col("key")])
+        .agg([
+            len(),
+            col("value").count().alias("count"),
+            col("value").sum().alias("sum"),
+            col("value").min().alias("min"),
+            col("value").max().alias("max"),
+            col("value").first().alias("first"),
+            col("value").last().alias("last"),
+            col("value").var(1).alias("var"),
+            col("value").std(1).alias("std"),
+            col("value").n_unique().alias("n_unique"),
+            col("value")
+                .sort_by([col("by")], Default::default())
+                .first()
+                .alias("min_by"),
+            col("value")
+                .sort_by([col("by")], Default::default())
+                .last()
+                .alias("max_by"),
+        ])
+        .with_columns([col("var").round(6), col("std").round(6)])
+        .sort(["key"], Default::default());
+    assert_new_streaming(q);
+}
+
+/// Sinks `q` with the new streaming engine and checks that scanning the file
+/// gives the in-memory result.
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+fn assert_new_streaming_sink(
+    q: LazyFrame,
+    name: &str,
+    sink: impl FnOnce(LazyFrame, &std::path::Path) -> PolarsResult<()>,
+    scan: impl FnOnce(&std::path::Path) -> PolarsResult<LazyFrame>,
+) -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars_test_new_streaming_sink.{name}"));
+    let expected = q.clone().collect()?;
+    sink(q.with_new_streaming(true), &path)?;
+    let out = scan(&path)?.collect();
+    std::fs::remove_file(&path)?;
+    assert_eq!(out?, expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+fn test_new_streaming_sinks() -> PolarsResult<()> {
+    // Multiple morsels, which must be written in order.
+    let q = ooc_df(250_000)
+        .lazy()
+        .filter(col("key").neq(lit(3)))
+        .with_row_index("idx", None);
+
+    #[cfg(feature = "parquet")]
+    assert_new_streaming_sink(
+        q.clone(),
+        "parquet",
+        |q, path| q.sink_parquet(path, Default::default()),
+        |path| LazyFrame::scan_parquet(path, Default::default()),
+    )?;
+    #[cfg(feature = "ipc")]
+    assert_new_streaming_sink(
+        q.clone(),
+        "ipc",
+        |q, path| q.sink_ipc(path, Default::default()),
+        |path| LazyFrame::scan_ipc(path, Default::default()),
+    )?;
+    #[cfg(feature = "csv")]
+    assert_new_streaming_sink(
+        q,
+        "csv",
+        |q, path| q.sink_csv(path, Default::default()),
+        |path| {
+            LazyCsvReader::new(path)
+                .with_schema(Some(Arc::new(Schema::from_iter([
+                    Field::new("idx", IDX_DTYPE),
+                    Field::new("key", DataType::Int64),
+                    Field::new("value", DataType::Float64),
+                ]))))
+                .finish()
+        },
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_new_streaming_sort() {
+    let q = ooc_df(250_000)
+        .lazy()
+        .with_columns([(col("key") % lit(7)).alias("key2")]);
+
+    assert_new_streaming(
+        q.clone().sort(
+            ["key2", "value"],
+            SortMultipleOptions::default()
+                .with_order_descending_multi([true, false])
+                .with_nulls_last_multi([false, true])
+                .with_maintain_order(true),
+        ),
+    );
+    assert_new_streaming(
+        q.clone().sort(
+            ["value"],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_maintain_order(true),
+        ),
+    );
+    assert_new_streaming(
+        q.sort(
+            ["key"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )
+        .slice(1000, 500),
+    );
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_new_streaming_asof_join() {
+    use polars_ops::prelude::{AsOfOptions, AsofStrategy};
+
+    let n = 250_000i64;
+    let left = df![
+        "time" => (0..n).map(|i| i * 3).collect::<Vec<_>>(),
+        "sym" => (0..n).map(|i| ["a", "b", "c"][(i % 3) as usize]).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+    .lazy();
+    let right = df![
+        "time" => (0..n).map(|i| i * 5 + 1).collect::<Vec<_>>(),
+        "sym" => (0..n).map(|i| ["a", "b", "c"][(i % 2) as usize]).collect::<Vec<_>>(),
+        "price" => (0..n).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+    .lazy();
+
+    for strategy in [
+        AsofStrategy::Backward,
+        AsofStrategy::Forward,
+        AsofStrategy::Nearest,
+    ] {
+        for (by, tolerance) in [(false, None), (true, Some(AnyValue::Int64(4)))] {
+            let by = by.then(|| vec!["sym".into()]);
+            let q = left
+                .clone()
+                .join_builder()
+                .with(right.clone())
+                .left_on([col("time")])
+                .right_on([col("time")])
+                .how(JoinType::AsOf(AsOfOptions {
+                    strategy,
+                    tolerance,
+                    left_by: by.clone(),
+                    right_by: by,
+                    ..Default::default()
+                }))
+                .finish();
+            assert_new_streaming(q);
+        }
+    }
+}
@@ -691,3 +691,34 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "iejoin")]
+fn test_join_where_to_iejoin() -> PolarsResult<()> {
+    let sessions = df![
+        "id" => [1, 2, 3],
+        "start" => [Some(0), Some(10), None],
+        "end" => [10, 20, 30],
+    ]?;
+    let events = df![
+        "ts" => [Some(1), Some(5), Some(10), None, Some(12), Some(25)],
+    ]?;
+    let predicates = [col("start").lt_eq(col("ts")), col("ts").lt(col("end"))];
+
+    let q = sessions
+        .clone()
+        .lazy()
+        .join_where(events.clone().lazy(), predicates.clone());
+    let plan = q.clone().to_alp_optimized()?.describe();
+    assert!(plan.contains("IEJOIN"), "{plan}");
+    assert!(!plan.contains("CROSS"), "{plan}");
+
+    let expected = sessions
+        .lazy()
+        .cross_join(events.lazy(), None)
+        .filter(predicates[0].clone().and(predicates[1].clone()))
+        .with_predicate_pushdown(false)
+        .collect()?;
+    assert!(q.collect()?.equals_missing(&expected));
+    Ok(())
+}
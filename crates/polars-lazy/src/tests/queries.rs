@@ -1,5 +1,7 @@
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
+#[cfg(feature = "interval_join")]
+use polars_ops::prelude::{ClosedInterval, IntervalJoinOptions, IntervalJoinType};
 
 use super::*;
 
@@ -1180,6 +1182,57 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "interval_join")]
+#[test]
+fn test_interval_join() -> PolarsResult<()> {
+    let reads = df![
+        "id" => [0, 1, 2, 3, 4],
+        "start" => [Some(1), Some(5), Some(10), Some(20), None],
+        "end" => [5, 12, 15, 25, 3],
+        "chrom" => ["a", "a", "b", "a", "a"],
+    ]?;
+    let genes = df![
+        "gene_start" => [0, 5, 12, 30],
+        "gene_end" => [5, 10, 20, 40],
+        "chrom" => ["a", "a", "b", "a"],
+        "name" => ["g1", "g2", "g3", "g4"],
+    ]?;
+    let join = |options| {
+        reads
+            .clone()
+            .lazy()
+            .join_builder()
+            .with(genes.clone().lazy())
+            .left_on([col("start"), col("end"), col("chrom")])
+            .right_on([col("gene_start"), col("gene_end"), col("chrom")])
+            .interval(options)
+            .finish()
+            .select([col("id"), col("name")])
+            .collect()
+    };
+
+    let out = join(IntervalJoinOptions {
+        how: IntervalJoinType::Left,
+        closed: ClosedInterval::Left,
+    })?;
+    let expected = df![
+        "id" => [0, 1, 2, 3, 4],
+        "name" => [Some("g1"), Some("g2"), Some("g3"), None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = join(IntervalJoinOptions {
+        how: IntervalJoinType::Inner,
+        closed: ClosedInterval::Both,
+    })?;
+    let expected = df![
+        "id" => [0, 0, 1, 1, 2],
+        "name" => ["g1", "g2", "g1", "g2", "g3"],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
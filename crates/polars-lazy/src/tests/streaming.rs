@@ -199,6 +199,25 @@ fn test_streaming_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "cross_join")]
+fn test_streaming_cross_join_filter_equal() -> PolarsResult<()> {
+    // Unlike `join_where`, a cross join followed by a filter stays a cross join,
+    // which streams and keeps its row order.
+    let q = df![
+        "a" => [1, 2, 1, 3],
+        "b" => [1, 2, 3, 4],
+    ]?
+    .lazy();
+    let q = q
+        .clone()
+        .cross_join(q, None)
+        .filter(col("a").eq(col("a_right")));
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_inner_join3() -> PolarsResult<()> {
     let lf_left = df![
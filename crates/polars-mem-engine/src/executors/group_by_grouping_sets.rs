@@ -0,0 +1,126 @@
+use super::*;
+
+/// Group by every grouping set and concatenate the results.
+pub struct GroupByGroupingSetsExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) grouping_sets: GroupingSets,
+    pub(crate) maintain_order: bool,
+    pub(crate) input_schema: SchemaRef,
+    pub(crate) slice: Option<(i64, usize)>,
+}
+
+impl GroupByGroupingSetsExec {
+    /// Aggregate over all rows, as in the grouping set `()`.
+    fn aggregate_all(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Vec<Series>> {
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, df.height() as IdxSize]],
+            rolling: false,
+        };
+        evaluate_aggs(df, &self.aggs, &groups, state)
+    }
+
+    fn execute_impl(
+        &mut self,
+        state: &ExecutionState,
+        mut df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        df.as_single_chunk_par();
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut out: Option<DataFrame> = None;
+        for set in &self.grouping_sets.sets {
+            let mut set = set.clone();
+            set.sort_unstable();
+            set.dedup();
+
+            let (set_keys, agg_columns) = if set.is_empty() {
+                (vec![], self.aggregate_all(&df, state)?)
+            } else {
+                let set_keys = set.iter().map(|i| keys[*i].clone()).collect();
+                let mut columns: Vec<Series> = group_by_helper(
+                    df.clone(),
+                    set_keys,
+                    &self.aggs,
+                    None,
+                    state,
+                    self.maintain_order,
+                    None,
+                )?
+                .into();
+                let agg_columns = columns.split_off(set.len());
+                (columns, agg_columns)
+            };
+            let height = set_keys
+                .first()
+                .or(agg_columns.first())
+                .map_or(1, |s| s.len());
+
+            // The keys that are not part of the set are null.
+            let mut columns = keys
+                .iter()
+                .enumerate()
+                .map(|(i, key)| match set.binary_search(&i) {
+                    Ok(pos) => set_keys[pos].clone(),
+                    Err(_) => Series::full_null(key.name(), height, key.dtype()),
+                })
+                .collect::<Vec<_>>();
+            columns.extend(agg_columns);
+            let id = GroupingSets::grouping_id(&set, keys.len());
+            columns
+                .push(UInt32Chunked::full(&self.grouping_sets.id_name, id, height).into_series());
+
+            let set_df = DataFrame::new(columns)?;
+            match out.as_mut() {
+                None => out = Some(set_df),
+                Some(out) => {
+                    out.vstack_mut(&set_df)?;
+                },
+            }
+        }
+
+        let mut out = out.unwrap_or_default();
+        out.as_single_chunk_par();
+        Ok(match self.slice {
+            Some((offset, len)) => out.slice(offset, len),
+            None => out,
+        })
+    }
+}
+
+impl Executor for GroupByGroupingSetsExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run GroupByGroupingSetsExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+
+        let profile_name = if state.has_node_timer() {
+            let by = self
+                .keys
+                .iter()
+                .map(|s| Ok(s.to_field(&self.input_schema)?.name))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = comma_delimited("group_by_grouping_sets".to_string(), &by);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        if state.has_node_timer() {
+            let new_state = state.clone();
+            new_state.record(|| self.execute_impl(state, df), profile_name)
+        } else {
+            self.execute_impl(state, df)
+        }
+    }
+}
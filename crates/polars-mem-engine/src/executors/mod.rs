@@ -4,6 +4,7 @@ mod ext_context;
 mod filter;
 mod group_by;
 mod group_by_dynamic;
+mod group_by_grouping_sets;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod hconcat;
@@ -34,6 +35,7 @@ pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_dynamic::*;
+pub(super) use self::group_by_grouping_sets::*;
 pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
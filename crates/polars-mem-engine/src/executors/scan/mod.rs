@@ -1,3 +1,5 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(feature = "ipc")]
@@ -11,6 +13,8 @@ mod python_scan;
 
 use std::mem;
 
+#[cfg(feature = "avro")]
+pub(crate) use avro::AvroExec;
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvExec;
 #[cfg(feature = "ipc")]
@@ -21,7 +25,13 @@ pub(crate) use ndjson::JsonExec;
 pub(crate) use parquet::ParquetExec;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use polars_io::predicates::PhysicalIoExpr;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc", feature = "cse"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "avro",
+    feature = "cse"
+))]
 use polars_io::prelude::*;
 use polars_plan::global::_set_n_rows_for_scan;
 
@@ -7,6 +7,7 @@ use polars_core::config::{get_file_prefetch_size, verbose};
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
+use polars_io::parquet::read::FileDecryptionProperties;
 use polars_io::path_utils::is_cloud_url;
 use polars_io::utils::slice::split_slice_at_file;
 use polars_io::RowIndex;
@@ -24,6 +25,7 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<FileMetaDataRef>,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl ParquetExec {
@@ -37,6 +39,7 @@ impl ParquetExec {
         cloud_options: Option<CloudOptions>,
         file_options: FileScanOptions,
         metadata: Option<FileMetaDataRef>,
+        decryption: Option<FileDecryptionProperties>,
     ) -> Self {
         ParquetExec {
             paths,
@@ -47,6 +50,7 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            decryption,
         }
     }
 
@@ -83,7 +87,9 @@ impl ParquetExec {
                         let row_counts = path_indexes
                             .into_par_iter()
                             .map(|i| {
-                                ParquetReader::new(std::fs::File::open(&self.paths[*i])?).num_rows()
+                                ParquetReader::new(std::fs::File::open(&self.paths[*i])?)
+                                    .with_decryption(self.decryption.clone())
+                                    .num_rows()
                             })
                             .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -141,6 +147,7 @@ impl ParquetExec {
                 );
 
                 let mut reader = ParquetReader::new(file)
+                    .with_decryption(self.decryption.clone())
                     .read_parallel(parallel)
                     .set_low_memory(self.options.low_memory)
                     .use_statistics(self.options.use_statistics)
@@ -238,6 +245,7 @@ impl ParquetExec {
         let verbose = verbose();
         let first_metadata = &self.metadata;
         let cloud_options = self.cloud_options.as_ref();
+        let decryption = &self.decryption;
 
         let mut result = vec![];
         let batch_size = get_file_prefetch_size();
@@ -268,6 +276,7 @@ impl ParquetExec {
                 let mut iter = stream::iter((0..self.paths.len()).rev().map(|i| {
                     let paths = paths.clone();
                     let cloud_options = cloud_options.clone();
+                    let decryption = decryption.clone();
 
                     pl_async::get_runtime().spawn(async move {
                         PolarsResult::Ok((
@@ -278,6 +287,7 @@ impl ParquetExec {
                                 None,
                             )
                             .await?
+                            .with_decryption(decryption)
                             .num_rows()
                             .await?,
                         ))
@@ -335,7 +345,8 @@ impl ParquetExec {
                 };
                 let mut reader =
                     ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, metadata)
-                        .await?;
+                        .await?
+                        .with_decryption(decryption.clone());
 
                 let num_rows = reader.num_rows().await?;
                 PolarsResult::Ok((num_rows, reader))
@@ -331,6 +331,7 @@ fn create_physical_plan_impl(
                     options,
                     cloud_options,
                     metadata,
+                    decryption,
                 } => Ok(Box::new(executors::ParquetExec::new(
                     paths,
                     file_info,
@@ -340,7 +341,17 @@ fn create_physical_plan_impl(
                     cloud_options,
                     file_options,
                     metadata,
+                    decryption,
                 ))),
+                #[cfg(feature = "avro")]
+                FileScan::Avro { cloud_options } => Ok(Box::new(executors::AvroExec {
+                    paths,
+                    file_info,
+                    predicate,
+                    file_options,
+                    hive_parts,
+                    cloud_options,
+                })),
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options, .. } => Ok(Box::new(executors::JsonExec::new(
                     paths,
@@ -527,6 +538,19 @@ fn create_physical_plan_impl(
                 }));
             }
 
+            if let Some(grouping_sets) = options.grouping_sets {
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                return Ok(Box::new(executors::GroupByGroupingSetsExec {
+                    input,
+                    keys: phys_keys,
+                    aggs: phys_aggs,
+                    grouping_sets,
+                    maintain_order,
+                    input_schema,
+                    slice: options.slice,
+                }));
+            }
+
             // We first check if we can partition the group_by on the latest moment.
             let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
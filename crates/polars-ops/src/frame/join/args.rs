@@ -58,6 +58,10 @@ impl JoinCoalesce {
             #[cfg(feature = "asof_join")]
             AsOf(_) => matches!(self, JoinSpecific | CoalesceColumns),
             Cross => false,
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => false,
+            #[cfg(feature = "interval_join")]
+            Interval(_) => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
         }
@@ -114,6 +118,10 @@ pub enum JoinType {
     #[cfg(feature = "asof_join")]
     AsOf(AsOfOptions),
     Cross,
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinOptions),
+    #[cfg(feature = "interval_join")]
+    Interval(IntervalJoinOptions),
     #[cfg(feature = "semi_anti_join")]
     Semi,
     #[cfg(feature = "semi_anti_join")]
@@ -137,6 +145,10 @@ impl Display for JoinType {
             #[cfg(feature = "asof_join")]
             AsOf(_) => "ASOF",
             Cross => "CROSS",
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => "IEJOIN",
+            #[cfg(feature = "interval_join")]
+            Interval(_) => "INTERVAL",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
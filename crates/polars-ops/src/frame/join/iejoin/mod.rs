@@ -0,0 +1,272 @@
+use polars_core::chunked_array::ops::sort::arg_sort_multiple::_get_rows_encoded_arr;
+use polars_core::utils::try_get_supertype;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Comparison operator of an inequality join predicate: `left <op> right`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl InequalityOperator {
+    fn is_strict(&self) -> bool {
+        matches!(self, InequalityOperator::Lt | InequalityOperator::Gt)
+    }
+}
+
+impl Display for InequalityOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use InequalityOperator::*;
+        let val = match self {
+            Lt => "<",
+            LtEq => "<=",
+            Gt => ">",
+            GtEq => ">=",
+        };
+        write!(f, "{val}")
+    }
+}
+
+/// Options of an inequality join. A row of the left and a row of the right table
+/// are joined if `left_on[0] <operator1> right_on[0]` holds and, if given,
+/// `left_on[1] <operator2> right_on[1]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IEJoinOptions {
+    pub operator1: InequalityOperator,
+    pub operator2: Option<InequalityOperator>,
+}
+
+/// Join the rows of `left` and `right` for which the inequalities of `options` hold.
+///
+/// The output has the same row order as a cross join followed by a filter on the
+/// inequalities, but never materializes the cross join.
+pub(super) fn iejoin(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &IEJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let n_keys = 1 + options.operator2.is_some() as usize;
+    polars_ensure!(
+        selected_left.len() == n_keys && selected_right.len() == n_keys,
+        ComputeError: "an inequality join with {} operator(s) expects {} join key(s) on both sides",
+        n_keys, n_keys
+    );
+
+    let mut tuples = iejoin_tuples(&selected_left, &selected_right, options)?;
+    // Restore the order of a cross join: left row major, then the right rows.
+    POOL.install(|| tuples.par_sort_unstable());
+    let tuples = match slice {
+        Some((offset, len)) => slice_slice(&tuples, offset, len),
+        None => &tuples,
+    };
+    let (left_idx, right_idx): (Vec<IdxSize>, Vec<IdxSize>) = tuples.iter().copied().unzip();
+
+    let (df_left, df_right) = POOL.join(
+        // SAFETY: join indices are known to be in bounds
+        || unsafe { left._take_unchecked_slice_sorted(&left_idx, true, IsSorted::Ascending) },
+        || unsafe { right._take_unchecked_slice(&right_idx, true) },
+    );
+    _finish_join(df_left, df_right, suffix)
+}
+
+/// Indices of the rows that have no null in any of the keys, rows with a null key never match.
+fn valid_rows<'a>(keys: impl Iterator<Item = &'a Series>, len: usize) -> Vec<IdxSize> {
+    let mut mask: Option<BooleanChunked> = None;
+    for s in keys.filter(|s| s.null_count() > 0) {
+        let valid = s.is_not_null();
+        mask = Some(match mask {
+            None => valid,
+            Some(mask) => &mask & &valid,
+        });
+    }
+    match mask {
+        None => (0..len as IdxSize).collect(),
+        Some(mask) => mask
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, valid)| (valid == Some(true)).then_some(i as IdxSize))
+            .collect(),
+    }
+}
+
+/// Concatenate the valid keys of both sides, the left rows come first.
+fn union_keys(
+    left: &Series,
+    right: &Series,
+    left_valid: &[IdxSize],
+    right_valid: &[IdxSize],
+) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(left.dtype(), right.dtype())?;
+    // SAFETY: the valid indices are in bounds.
+    let mut out = unsafe { left.cast(&dtype)?.take_slice_unchecked(left_valid) };
+    out.append(&unsafe { right.cast(&dtype)?.take_slice_unchecked(right_valid) })?;
+    Ok(out)
+}
+
+/// Order the union of the keys, `n_left` is the number of keys of the left table.
+/// Equal keys of the left table are placed before those of the right table if
+/// `left_first` is set, and after them otherwise.
+fn sort_union(
+    keys: &Series,
+    descending: bool,
+    left_first: bool,
+    n_left: usize,
+) -> PolarsResult<Vec<usize>> {
+    let rows = _get_rows_encoded_arr(&[keys.clone()], &[descending], &[false])?;
+    let side = |i: usize| (i < n_left) != left_first;
+    let mut order = (0..rows.len()).collect::<Vec<_>>();
+    POOL.install(|| {
+        order.par_sort_unstable_by(|&a, &b| {
+            rows.value(a)
+                .cmp(rows.value(b))
+                .then_with(|| side(a).cmp(&side(b)))
+        })
+    });
+    Ok(order)
+}
+
+fn iejoin_tuples(
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &IEJoinOptions,
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    let left_valid = valid_rows(selected_left.iter(), selected_left[0].len());
+    let right_valid = valid_rows(selected_right.iter(), selected_right[0].len());
+    if left_valid.is_empty() || right_valid.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let x = union_keys(
+        &selected_left[0],
+        &selected_right[0],
+        &left_valid,
+        &right_valid,
+    )?;
+    let Some(operator2) = options.operator2 else {
+        return range_join(&x, options.operator1, &left_valid, &right_valid);
+    };
+    let y = union_keys(
+        &selected_left[1],
+        &selected_right[1],
+        &left_valid,
+        &right_valid,
+    )?;
+    ie_join(
+        &x,
+        &y,
+        options.operator1,
+        operator2,
+        &left_valid,
+        &right_valid,
+    )
+}
+
+/// Join on a single inequality by binary searching every left key in the sorted right keys.
+fn range_join(
+    x: &Series,
+    operator: InequalityOperator,
+    left_valid: &[IdxSize],
+    right_valid: &[IdxSize],
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    use InequalityOperator::*;
+    let n_left = left_valid.len();
+    let rows = _get_rows_encoded_arr(&[x.clone()], &[false], &[false])?;
+    let mut right_order = (n_left..rows.len()).collect::<Vec<_>>();
+    POOL.install(|| right_order.par_sort_unstable_by(|&a, &b| rows.value(a).cmp(rows.value(b))));
+
+    let mut out = vec![];
+    for (i, &left_idx) in left_valid.iter().enumerate() {
+        let key = rows.value(i);
+        let matches = match operator {
+            Lt => &right_order[right_order.partition_point(|&r| rows.value(r) <= key)..],
+            LtEq => &right_order[right_order.partition_point(|&r| rows.value(r) < key)..],
+            Gt => &right_order[..right_order.partition_point(|&r| rows.value(r) < key)],
+            GtEq => &right_order[..right_order.partition_point(|&r| rows.value(r) <= key)],
+        };
+        out.extend(matches.iter().map(|&r| (left_idx, right_valid[r - n_left])));
+    }
+    Ok(out)
+}
+
+/// Join on two inequalities with the IEJoin algorithm of Khayyat et al.
+///
+/// The union of the keys is ordered twice: `l1` is ordered such that the right
+/// rows that satisfy the first inequality for a left row are placed after it,
+/// and `l2` such that the right rows that satisfy the second inequality for a
+/// left row are placed before it. Walking `l2` we mark the positions of the
+/// right rows in `l1`, so for a left row the marked positions after its own
+/// position in `l1` are the matches.
+fn ie_join(
+    x: &Series,
+    y: &Series,
+    operator1: InequalityOperator,
+    operator2: InequalityOperator,
+    left_valid: &[IdxSize],
+    right_valid: &[IdxSize],
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    use InequalityOperator::*;
+    let n_left = left_valid.len();
+    let n = x.len();
+
+    let l1 = sort_union(
+        x,
+        matches!(operator1, Gt | GtEq),
+        !operator1.is_strict(),
+        n_left,
+    )?;
+    let mut positions = vec![0; n];
+    for (pos, &i) in l1.iter().enumerate() {
+        positions[i] = pos;
+    }
+    let l2 = sort_union(
+        y,
+        matches!(operator2, Lt | LtEq),
+        operator2.is_strict(),
+        n_left,
+    )?;
+
+    let mut marked = vec![0u64; n.div_ceil(64)];
+    // One past the last word that has a marked position.
+    let mut end_word = 0;
+    let mut out = vec![];
+    for i in l2 {
+        let pos = positions[i];
+        if i >= n_left {
+            marked[pos / 64] |= 1 << (pos % 64);
+            end_word = end_word.max(pos / 64 + 1);
+            continue;
+        }
+
+        let start = pos + 1;
+        let mut word_idx = start / 64;
+        if word_idx >= end_word {
+            continue;
+        }
+        let mut word = marked[word_idx] & (u64::MAX << (start % 64));
+        loop {
+            while word != 0 {
+                let r = l1[word_idx * 64 + word.trailing_zeros() as usize];
+                out.push((left_valid[i], right_valid[r - n_left]));
+                word &= word - 1;
+            }
+            word_idx += 1;
+            if word_idx >= end_word {
+                break;
+            }
+            word = marked[word_idx];
+        }
+    }
+    Ok(out)
+}
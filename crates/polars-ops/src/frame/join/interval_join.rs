@@ -0,0 +1,289 @@
+use arrow::array::BinaryArray;
+use polars_core::chunked_array::ops::sort::arg_sort_multiple::{
+    _get_rows_encoded_arr, _get_rows_encoded_ca_unordered,
+};
+use polars_core::utils::try_get_supertype;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::series::ClosedInterval;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntervalJoinType {
+    /// Returns the pairs of rows with overlapping intervals.
+    #[default]
+    Inner,
+    /// Returns the pairs of rows with overlapping intervals and the rows of the
+    /// left table that overlap with no row of the right table.
+    Left,
+}
+
+/// Options of an interval join.
+///
+/// The first two join keys of both tables are the start and the end of the
+/// intervals, and a pair of rows is joined if their intervals overlap. Any
+/// further join keys have to be equal, e.g. a chromosome or a device id.
+/// Intervals that are empty, or of which a bound or equality key is null, never
+/// overlap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntervalJoinOptions {
+    pub how: IntervalJoinType,
+    /// Which bounds belong to the intervals of both tables.
+    pub closed: ClosedInterval,
+}
+
+pub(super) fn interval_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &IntervalJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        selected_left.len() >= 2 && selected_left.len() == selected_right.len(),
+        ComputeError: "an interval join expects the start and the end of the intervals, \
+        followed by the equality keys, on both sides"
+    );
+
+    let mut tuples = interval_join_tuples(&selected_left, &selected_right, options.closed)?;
+    // Keep the order of the left table.
+    POOL.install(|| tuples.par_sort_unstable());
+
+    match options.how {
+        IntervalJoinType::Inner => {
+            let tuples = match slice {
+                Some((offset, len)) => slice_slice(&tuples, offset, len),
+                None => &tuples,
+            };
+            let (left_idx, right_idx): (Vec<IdxSize>, Vec<IdxSize>) =
+                tuples.iter().copied().unzip();
+            let (df_left, df_right) = POOL.join(
+                // SAFETY: join indices are known to be in bounds
+                || unsafe {
+                    left._take_unchecked_slice_sorted(&left_idx, true, IsSorted::Ascending)
+                },
+                || unsafe { right._take_unchecked_slice(&right_idx, true) },
+            );
+            _finish_join(df_left, df_right, suffix)
+        },
+        IntervalJoinType::Left => {
+            let mut left_idx = Vec::with_capacity(tuples.len());
+            let mut right_idx = Vec::with_capacity(tuples.len());
+            let mut tuples = tuples.into_iter().peekable();
+            for l in 0..left.height() as IdxSize {
+                match tuples.peek() {
+                    Some((tuple_left, _)) if *tuple_left == l => {
+                        while let Some((_, r)) = tuples.next_if(|(tuple_left, _)| *tuple_left == l)
+                        {
+                            left_idx.push(l);
+                            right_idx.push(Some(r));
+                        }
+                    },
+                    _ => {
+                        left_idx.push(l);
+                        right_idx.push(None);
+                    },
+                }
+            }
+            let (left_idx, right_idx) = match slice {
+                Some((offset, len)) => (
+                    slice_slice(&left_idx, offset, len),
+                    slice_slice(&right_idx, offset, len),
+                ),
+                None => (left_idx.as_slice(), right_idx.as_slice()),
+            };
+            let right_idx = right_idx.iter().copied().collect::<IdxCa>();
+            let (df_left, df_right) = POOL.join(
+                // SAFETY: join indices are known to be in bounds
+                || unsafe {
+                    left._take_unchecked_slice_sorted(left_idx, true, IsSorted::Ascending)
+                },
+                || unsafe { right.take_unchecked(&right_idx) },
+            );
+            _finish_join(df_left, df_right, suffix)
+        },
+    }
+}
+
+/// Concatenate the keys of both tables, the left rows come first.
+fn concat_keys(keys: &[&Series]) -> PolarsResult<Series> {
+    let mut dtype = keys[0].dtype().clone();
+    for s in &keys[1..] {
+        dtype = try_get_supertype(&dtype, s.dtype())?;
+    }
+    let mut out = keys[0].cast(&dtype)?;
+    for s in &keys[1..] {
+        out.append(&s.cast(&dtype)?)?;
+    }
+    Ok(out)
+}
+
+fn is_valid(s: &Series) -> Option<Vec<bool>> {
+    (s.null_count() > 0).then(|| s.is_not_null().into_no_null_iter().collect())
+}
+
+fn interval_join_tuples(
+    selected_left: &[Series],
+    selected_right: &[Series],
+    closed: ClosedInterval,
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    let n_left = selected_left[0].len();
+    let n_right = selected_right[0].len();
+
+    // All bounds are encoded together, so that they can be compared across the tables.
+    let bounds = concat_keys(&[
+        &selected_left[0],
+        &selected_left[1],
+        &selected_right[0],
+        &selected_right[1],
+    ])?;
+    let bounds_valid = is_valid(&bounds);
+    let bounds = _get_rows_encoded_arr(&[bounds], &[false], &[false])?;
+    let intervals = Intervals {
+        bounds: &bounds,
+        n_left,
+        n_right,
+        strict: closed != ClosedInterval::Both,
+    };
+
+    let mut by = Vec::with_capacity(selected_left.len() - 2);
+    for (l, r) in selected_left[2..].iter().zip(&selected_right[2..]) {
+        by.push(concat_keys(&[l, r])?);
+    }
+    let by_valid = by.iter().filter_map(is_valid).collect::<Vec<_>>();
+
+    let is_valid = |idx: usize, start: usize, end: usize| {
+        bounds_valid
+            .as_ref()
+            .map_or(true, |valid| valid[start] && valid[end])
+            && by_valid.iter().all(|valid| valid[idx])
+    };
+    let left_valid = (0..n_left)
+        .filter(|&i| is_valid(i, i, n_left + i) && intervals.left_non_empty(i))
+        .map(|i| i as IdxSize);
+    let right_valid = (0..n_right)
+        .filter(|&i| {
+            is_valid(n_left + i, 2 * n_left + i, 2 * n_left + n_right + i)
+                && intervals.right_non_empty(i)
+        })
+        .map(|i| i as IdxSize);
+
+    let mut out = vec![];
+    if by.is_empty() {
+        intervals.sweep(left_valid.collect(), right_valid.collect(), &mut out);
+    } else {
+        let by = _get_rows_encoded_ca_unordered("", &by)?;
+        let by = by.downcast_iter().next().unwrap();
+        let mut groups = PlHashMap::<&[u8], (Vec<IdxSize>, Vec<IdxSize>)>::new();
+        for i in left_valid {
+            groups.entry(by.value(i as usize)).or_default().0.push(i);
+        }
+        for i in right_valid {
+            if let Some((_, rights)) = groups.get_mut(by.value(n_left + i as usize)) {
+                rights.push(i);
+            }
+        }
+        for (lefts, rights) in groups.into_values() {
+            intervals.sweep(lefts, rights, &mut out);
+        }
+    }
+    Ok(out)
+}
+
+/// The row encoded bounds of the intervals of both tables: first the starts of
+/// the left table, then its ends, then the starts and the ends of the right table.
+struct Intervals<'a> {
+    bounds: &'a BinaryArray<i64>,
+    n_left: usize,
+    n_right: usize,
+    /// Whether the intervals are open at one of their bounds.
+    strict: bool,
+}
+
+impl Intervals<'_> {
+    fn left_start(&self, i: IdxSize) -> &[u8] {
+        self.bounds.value(i as usize)
+    }
+
+    fn left_end(&self, i: IdxSize) -> &[u8] {
+        self.bounds.value(self.n_left + i as usize)
+    }
+
+    fn right_start(&self, i: IdxSize) -> &[u8] {
+        self.bounds.value(2 * self.n_left + i as usize)
+    }
+
+    fn right_end(&self, i: IdxSize) -> &[u8] {
+        self.bounds
+            .value(2 * self.n_left + self.n_right + i as usize)
+    }
+
+    /// Whether an interval that ends at `end` overlaps with an interval that
+    /// starts at `start`, given that it doesn't start after it.
+    fn reaches(&self, end: &[u8], start: &[u8]) -> bool {
+        if self.strict {
+            end > start
+        } else {
+            end >= start
+        }
+    }
+
+    fn left_non_empty(&self, i: usize) -> bool {
+        let i = i as IdxSize;
+        self.reaches(self.left_end(i), self.left_start(i))
+    }
+
+    fn right_non_empty(&self, i: usize) -> bool {
+        let i = i as IdxSize;
+        self.reaches(self.right_end(i), self.right_start(i))
+    }
+
+    /// Find the overlapping pairs of the non-empty intervals with a sweep over their starts.
+    ///
+    /// An overlapping pair is found when the interval that starts last is
+    /// reached, all intervals of the other table that started before it and
+    /// that have not yet ended overlap with it. The intervals that have ended
+    /// are dropped, as they can't overlap with any interval that starts later.
+    fn sweep(
+        &self,
+        mut lefts: Vec<IdxSize>,
+        mut rights: Vec<IdxSize>,
+        out: &mut Vec<(IdxSize, IdxSize)>,
+    ) {
+        if lefts.is_empty() || rights.is_empty() {
+            return;
+        }
+        lefts.sort_unstable_by(|a, b| self.left_start(*a).cmp(self.left_start(*b)));
+        rights.sort_unstable_by(|a, b| self.right_start(*a).cmp(self.right_start(*b)));
+
+        let mut active_lefts = vec![];
+        let mut active_rights = vec![];
+        let (mut lefts, mut rights) = (lefts.into_iter().peekable(), rights.into_iter().peekable());
+        loop {
+            let next_left = match (lefts.peek(), rights.peek()) {
+                (Some(l), Some(r)) => self.left_start(*l) <= self.right_start(*r),
+                (Some(_), None) if !active_rights.is_empty() => true,
+                (None, Some(_)) if !active_lefts.is_empty() => false,
+                _ => break,
+            };
+            if next_left {
+                let l = lefts.next().unwrap();
+                let start = self.left_start(l);
+                active_rights.retain(|r| self.reaches(self.right_end(*r), start));
+                out.extend(active_rights.iter().map(|r| (l, *r)));
+                active_lefts.push(l);
+            } else {
+                let r = rights.next().unwrap();
+                let start = self.right_start(r);
+                active_lefts.retain(|l| self.reaches(self.left_end(*l), start));
+                out.extend(active_lefts.iter().map(|l| (*l, r)));
+                active_rights.push(r);
+            }
+        }
+    }
+}
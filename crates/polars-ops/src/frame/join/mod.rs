@@ -7,6 +7,10 @@ mod cross_join;
 mod dispatch_left_right;
 mod general;
 mod hash_join;
+#[cfg(feature = "iejoin")]
+mod iejoin;
+#[cfg(feature = "interval_join")]
+mod interval_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -29,6 +33,10 @@ use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "iejoin")]
+pub use iejoin::{IEJoinOptions, InequalityOperator};
+#[cfg(feature = "interval_join")]
+pub use interval_join::{IntervalJoinOptions, IntervalJoinType};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
@@ -118,6 +126,32 @@ pub trait DataFrameJoinOps: IntoDf {
             return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
         }
 
+        #[cfg(feature = "iejoin")]
+        if let JoinType::IEJoin(options) = &args.how {
+            return iejoin::iejoin(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
+        #[cfg(feature = "interval_join")]
+        if let JoinType::Interval(options) = &args.how {
+            return interval_join::interval_join(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
         // Clear literals if a frame is empty. Otherwise we could get an oob
         fn clear(s: &mut [Series]) {
             for s in s.iter_mut() {
@@ -276,6 +310,14 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => {
+                    unreachable!()
+                },
+                #[cfg(feature = "interval_join")]
+                JoinType::Interval(_) => {
+                    unreachable!()
+                },
             };
         }
 
@@ -297,6 +339,14 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(_) => {
+                unreachable!()
+            },
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(_) => {
+                unreachable!()
+            },
             JoinType::Full => {
                 let names_left = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
                 args.coalesce = JoinCoalesce::KeepColumns;
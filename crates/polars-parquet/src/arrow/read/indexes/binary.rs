@@ -1,4 +1,4 @@
-use arrow::array::{Array, BinaryArray, PrimitiveArray, Utf8Array};
+use arrow::array::{Array, BinaryArray, MutableBinaryViewArray, PrimitiveArray, Utf8Array};
 use arrow::datatypes::{ArrowDataType, PhysicalType};
 use arrow::trusted_len::TrustedLen;
 use polars_error::{to_compute_err, PolarsResult};
@@ -39,6 +39,16 @@ fn deserialize_binary_iter<'a, I: TrustedLen<Item = Option<&'a Vec<u8>>>>(
                 Utf8Array::<i64>::try_from_trusted_len_iter(iter).map_err(to_compute_err)?,
             ))
         },
+        PhysicalType::BinaryView => Ok(Box::new(
+            MutableBinaryViewArray::<[u8]>::from_iter(iter).freeze(),
+        )),
+        PhysicalType::Utf8View => {
+            let iter = iter.map(|x| x.map(|x| std::str::from_utf8(x)).transpose());
+            let array = iter
+                .collect::<Result<MutableBinaryViewArray<str>, _>>()
+                .map_err(to_compute_err)?;
+            Ok(Box::new(array.freeze()))
+        },
         _ => Ok(Box::new(BinaryArray::<i32>::from_iter(iter))),
     }
 }
@@ -18,10 +18,18 @@ fn deserialize_int32<I: TrustedLen<Item = Option<i32>>>(
 ) -> Box<dyn Array> {
     use ArrowDataType::*;
     match data_type.to_logical_type() {
+        Int8 => Box::new(
+            PrimitiveArray::<i8>::from_trusted_len_iter(iter.map(|x| x.map(|x| x as i8)))
+                .to(data_type),
+        ) as _,
+        Int16 => Box::new(
+            PrimitiveArray::<i16>::from_trusted_len_iter(iter.map(|x| x.map(|x| x as i16)))
+                .to(data_type),
+        ),
         UInt8 => Box::new(
             PrimitiveArray::<u8>::from_trusted_len_iter(iter.map(|x| x.map(|x| x as u8)))
                 .to(data_type),
-        ) as _,
+        ),
         UInt16 => Box::new(
             PrimitiveArray::<u16>::from_trusted_len_iter(iter.map(|x| x.map(|x| x as u16)))
                 .to(data_type),
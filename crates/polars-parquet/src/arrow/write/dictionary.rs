@@ -46,7 +46,11 @@ pub(crate) fn encode_as_dictionary_optional(
         .downcast_ref::<DictionaryArray<u32>>()
         .unwrap();
 
-    if (array.values().len() as f64) / (len_before as f64) > 0.75 {
+    let too_many_values = match options.dictionary.max_cardinality {
+        Some(max_cardinality) => array.values().len() > max_cardinality,
+        None => (array.values().len() as f64) / (len_before as f64) > 0.75,
+    };
+    if too_many_values {
         return None;
     }
 
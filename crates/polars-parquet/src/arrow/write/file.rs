@@ -5,6 +5,7 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{to_parquet_schema, ThriftFileMetaData, WriteOptions};
+use crate::parquet::encryption::FileEncryptionProperties;
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
@@ -61,7 +62,7 @@ impl<W: Write> FileWriter<W> {
                 parquet_schema,
                 FileWriteOptions {
                     version: options.version,
-                    write_statistics: options.has_statistics(),
+                    write_statistics: options.has_page_index(),
                 },
                 created_by,
             ),
@@ -70,11 +71,23 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Encrypts the file with the given properties.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIterColumns<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes the bloom filter of the leaf column `column` of the last written row group.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filter(&mut self, column: usize, bitset: &[u8]) -> PolarsResult<()> {
+        Ok(self.writer.write_bloom_filter(column, bitset)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
@@ -36,6 +36,7 @@ pub use utils::write_def_levels;
 
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::FileEncryptionProperties;
 pub use crate::parquet::metadata::{
     Descriptor, FileMetaData, KeyValue, SchemaDescriptor, ThriftFileMetaData,
 };
@@ -82,6 +83,28 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_page_size: Option<usize>,
+    /// How to write the columns encoded with [`Encoding::RleDictionary`]
+    pub dictionary: DictionaryOptions,
+}
+
+/// Options for the columns that are dictionary encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryOptions {
+    /// The maximum number of distinct values of a dictionary encoded column chunk. Column chunks
+    /// with more distinct values fall back to the `fallback` encoding. Defaults to 75% of the
+    /// number of values if None.
+    pub max_cardinality: Option<usize>,
+    /// The encoding of the column chunks that are not dictionary encoded
+    pub fallback: Encoding,
+}
+
+impl Default for DictionaryOptions {
+    fn default() -> Self {
+        Self {
+            max_cardinality: None,
+            fallback: Encoding::Plain,
+        }
+    }
 }
 
 use arrow::compute::aggregate::estimated_bytes_size;
@@ -129,6 +152,12 @@ impl WriteOptions {
     pub fn has_statistics(&self) -> bool {
         !self.statistics.is_empty()
     }
+
+    /// Whether to write the page indexes, which allow readers to skip the pages whose
+    /// min and max values cannot match a predicate.
+    pub fn has_page_index(&self) -> bool {
+        self.statistics.min_value && self.statistics.max_value
+    }
 }
 
 /// returns offset and length to slice the leaf values
@@ -285,8 +314,8 @@ pub fn array_to_pages(
             }
         }
 
-        // We didn't succeed, fallback to plain
-        encoding = Encoding::Plain;
+        // We didn't succeed, fallback to the value encoding
+        encoding = options.dictionary.fallback;
     }
 
     let nested = nested.to_vec();
@@ -433,16 +462,22 @@ pub fn array_to_page_simple(
                 encoding,
             )
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            )
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            )
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 arrow::compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
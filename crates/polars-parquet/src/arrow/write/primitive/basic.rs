@@ -7,7 +7,7 @@ use super::super::{utils, WriteOptions};
 use crate::arrow::read::schema::is_nullable;
 use crate::arrow::write::utils::ExactSizedIter;
 use crate::parquet::encoding::delta_bitpacked::encode;
-use crate::parquet::encoding::Encoding;
+use crate::parquet::encoding::{byte_stream_split, Encoding};
 use crate::parquet::page::DataPage;
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::PrimitiveStatistics;
@@ -75,6 +75,29 @@ where
     buffer
 }
 
+pub(crate) fn encode_byte_stream_split<T, P>(
+    array: &PrimitiveArray<T>,
+    is_optional: bool,
+    mut buffer: Vec<u8>,
+) -> Vec<u8>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    let values = if is_optional {
+        // only the non-null values are encoded
+        array
+            .non_null_values_iter()
+            .map(|x| x.as_())
+            .collect::<Vec<P>>()
+    } else {
+        array.values().iter().map(|x| x.as_()).collect::<Vec<P>>()
+    };
+    byte_stream_split::encode(&values, &mut buffer);
+    buffer
+}
+
 pub fn array_to_page_plain<T, P>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -103,11 +126,35 @@ where
     match encoding {
         Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
         Encoding::DeltaBinaryPacked => array_to_page(array, options, type_, encoding, encode_delta),
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        },
         other => polars_bail!(nyi = "Encoding integer as {other:?}"),
     }
     .map(Page::Data)
 }
 
+pub fn array_to_page_float<T, P>(
+    array: &PrimitiveArray<T>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+    encoding: Encoding,
+) -> PolarsResult<Page>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    match encoding {
+        Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        },
+        other => polars_bail!(nyi = "Encoding float as {other:?}"),
+    }
+    .map(Page::Data)
+}
+
 pub fn array_to_page<T, P, F: Fn(&PrimitiveArray<T>, bool, Vec<u8>) -> Vec<u8>>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -2,10 +2,12 @@
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
 pub use read::read;
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_of_bytes, write, write_header};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,21 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_of_bytes() {
+        assert_eq!(optimal_num_of_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_of_bytes(10, 0.01), 32);
+        assert_eq!(optimal_num_of_bytes(1_000, 0.01), 2048);
+        assert_eq!(optimal_num_of_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+
+        let mut bitset = vec![0; optimal_num_of_bytes(1_000, 0.01)];
+        for a in 0..1_000i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+        let false_positives = (1_000..101_000i64)
+            .filter(|a| is_in_set(&bitset, hash_native(*a)))
+            .count();
+        assert!(false_positives < 1_000);
+    }
 }
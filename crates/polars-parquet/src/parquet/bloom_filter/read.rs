@@ -6,6 +6,7 @@ use parquet_format_safe::{
     Uncompressed,
 };
 
+use crate::parquet::encryption::{read_module, ModuleType};
 use crate::parquet::error::ParquetResult;
 use crate::parquet::metadata::ColumnChunkMetaData;
 
@@ -28,9 +29,22 @@ pub fn read<R: Read + Seek>(
     };
     reader.seek(SeekFrom::Start(offset))?;
 
+    // The header and the bitset of an encrypted column chunk are encrypted as separate modules.
+    let decryptor = column_metadata.decryptor();
+
     // deserialize header
-    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
-    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+    let header = match decryptor {
+        Some(decryptor) => {
+            let module = read_module(reader, 1024)?;
+            let header = decryptor.decrypt(ModuleType::BloomFilterHeader, None, &module)?;
+            let mut prot = TCompactInputProtocol::new(header.as_slice(), usize::MAX);
+            BloomFilterHeader::read_from_in_protocol(&mut prot)?
+        },
+        None => {
+            let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+            BloomFilterHeader::read_from_in_protocol(&mut prot)?
+        },
+    };
 
     if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
         bitset.clear();
@@ -44,6 +58,12 @@ pub fn read<R: Read + Seek>(
     let length: usize = header.num_bytes.try_into()?;
 
     bitset.clear();
+    if let Some(decryptor) = decryptor {
+        // The module holds a nonce and a tag besides the bitset.
+        let module = read_module(reader, length + 1024)?;
+        *bitset = decryptor.decrypt(ModuleType::BloomFilterBitset, None, &module)?;
+        return Ok(());
+    }
     bitset.try_reserve(length)?;
     reader.by_ref().take(length as u64).read_to_end(bitset)?;
 
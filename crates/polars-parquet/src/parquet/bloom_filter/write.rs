@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+use parquet_format_safe::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// The minimum size of a bitset: a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The maximum size of a bitset, as used by parquet-mr.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the number of bytes of a bitset holding `num_distinct` values with a false positive
+/// probability of (at most) `fpp`.
+///
+/// The result is a power of two between 32 bytes and 128MiB.
+pub fn optimal_num_of_bytes(num_distinct: usize, fpp: f64) -> usize {
+    // See https://github.com/apache/parquet-format/blob/master/BloomFilter.md#sizing-an-sbbf
+    let num_bits = -8.0 * num_distinct as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+        .min(MAX_NUM_BYTES)
+}
+
+/// Writes a split block bloom filter (header followed by `bitset`) to `writer`.
+/// Returns the number of bytes written.
+pub fn write<W: Write>(writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header_len = write_header(writer, bitset.len())?;
+    writer.write_all(bitset)?;
+    Ok(header_len + bitset.len() as u64)
+}
+
+/// Writes the header of a split block bloom filter with a bitset of `num_bytes` to `writer`.
+/// Returns the number of bytes written.
+pub fn write_header<W: Write>(writer: &mut W, num_bytes: usize) -> ParquetResult<u64> {
+    let header = BloomFilterHeader::new(
+        num_bytes.try_into()?,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+    let mut protocol = TCompactOutputProtocol::new(writer);
+    Ok(header.write_to_out_protocol(&mut protocol)? as u64)
+}
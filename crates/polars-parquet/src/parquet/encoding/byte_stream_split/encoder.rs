@@ -0,0 +1,20 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes a slice of [`NativeType`] according to parquet's `BYTE_STREAM_SPLIT`, appending the
+/// encoded values to `buffer`.
+/// # Implementation
+/// The `k`-th byte of every value is written to the `k`-th stream, so that the bytes of similar
+/// values end up next to each other and compress better.
+pub fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
+    let num_elements = data.len();
+    let offset = buffer.len();
+    buffer.resize(offset + std::mem::size_of_val(data), 0);
+    let streams = &mut buffer[offset..];
+
+    for (i, v) in data.iter().enumerate() {
+        let value_bytes = v.to_le_bytes();
+        for (n, byte) in value_bytes.as_ref().iter().enumerate() {
+            streams[(num_elements * n) + i] = *byte;
+        }
+    }
+}
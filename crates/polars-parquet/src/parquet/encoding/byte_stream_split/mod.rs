@@ -1,12 +1,13 @@
 mod decoder;
+mod encoder;
 
 pub use decoder::Decoder;
+pub use encoder::encode;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parquet::error::ParquetError;
-    use crate::parquet::types::NativeType;
 
     #[test]
     fn round_trip_f32() -> Result<(), ParquetError> {
@@ -40,6 +41,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn encode_appends() -> Result<(), ParquetError> {
+        let data = vec![1i32, 256, 65536];
+        let mut buffer = vec![7u8];
+        encode(&data, &mut buffer);
+
+        assert_eq!(buffer[0], 7);
+        let mut decoder = Decoder::try_new(&buffer[1..], std::mem::size_of::<i32>())?;
+        let values = decoder
+            .iter_converted(|bytes| i32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(data, values);
+
+        Ok(())
+    }
+
     #[test]
     fn fails_for_invalid_values_size() -> Result<(), ParquetError> {
         let buffer = vec![0; 12];
@@ -59,19 +77,4 @@ mod tests {
 
         Ok(())
     }
-
-    fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
-        let element_size = std::mem::size_of::<T>();
-        let num_elements = data.len();
-        let total_length = std::mem::size_of_val(data);
-        buffer.resize(total_length, 0);
-
-        for (i, v) in data.iter().enumerate() {
-            let value_bytes = v.to_le_bytes();
-            let value_bytes_ref = value_bytes.as_ref();
-            for n in 0..element_size {
-                buffer[(num_elements * n) + i] = value_bytes_ref[n];
-            }
-        }
-    }
 }
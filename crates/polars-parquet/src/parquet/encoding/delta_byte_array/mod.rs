@@ -30,4 +30,20 @@ mod tests {
         assert_eq!(values, b"Helloicopter");
         Ok(())
     }
+
+    #[test]
+    fn prefix_of_previous() -> Result<(), ParquetError> {
+        let data = vec![b"Hello".as_ref(), b"Hell"];
+        let mut buffer = vec![];
+        encode(data.clone().into_iter(), &mut buffer);
+
+        let mut decoder = Decoder::try_new(&buffer)?;
+        let prefixes = decoder.by_ref().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(prefixes, vec![0, 4]);
+
+        let mut decoder = decoder.into_lengths()?;
+        let lengths = decoder.by_ref().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(lengths, vec![5, 0]);
+        Ok(())
+    }
 }
@@ -0,0 +1,722 @@
+//! Parquet [modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are written with the `AES_GCM_V1` algorithm: every module (footer, column metadata,
+//! page header and page) is encrypted with AES-GCM, using 128 or 256 bit keys. Files written with
+//! `AES_GCM_CTR_V1`, which encrypts pages with AES-CTR instead, can be read as well. Files are
+//! written with an encrypted footer; files with a plaintext footer and encrypted columns can be
+//! read.
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::sync::Arc;
+
+use parquet_format_safe::{
+    AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::aliases::PlHashMap;
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// The type of a module, which is part of its additional authenticated data (AAD).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+    BloomFilterHeader = 8,
+    BloomFilterBitset = 9,
+}
+
+/// The algorithm with which the modules of a file are encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    /// Every module is encrypted with AES-GCM.
+    AesGcmV1,
+    /// Pages are encrypted with AES-CTR, which is faster but not authenticated, and the other
+    /// modules with AES-GCM.
+    AesGcmCtrV1,
+}
+
+/// Returns the additional authenticated data of a module.
+///
+/// The page ordinal is only part of the AAD of data pages and their headers.
+fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> ParquetResult<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if module_type == ModuleType::Footer {
+        return Ok(aad);
+    }
+    for ordinal in [Some(row_group), Some(column), page].into_iter().flatten() {
+        let ordinal: i16 = ordinal.try_into().map_err(|_| {
+            ParquetError::not_supported(format!(
+                "encrypted files can only contain {} row groups, columns and pages, got {ordinal}",
+                i16::MAX
+            ))
+        })?;
+        aad.extend_from_slice(&ordinal.to_le_bytes());
+    }
+    Ok(aad)
+}
+
+#[cfg(feature = "encryption")]
+fn aead_key(key: &[u8]) -> ParquetResult<ring::aead::LessSafeKey> {
+    use ring::aead::{LessSafeKey, UnboundKey, AES_128_GCM, AES_256_GCM};
+
+    let algorithm = match key.len() {
+        16 => &AES_128_GCM,
+        32 => &AES_256_GCM,
+        len => return Err(invalid_key_length(len)),
+    };
+    let key = UnboundKey::new(algorithm, key).map_err(|_| invalid_key_length(key.len()))?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn invalid_key_length(len: usize) -> ParquetError {
+    ParquetError::InvalidParameter(format!(
+        "an AES-GCM key must have 16 or 32 bytes, got {len}"
+    ))
+}
+
+/// Fills `dest` with random bytes from the system's secure random number generator.
+#[cfg(feature = "encryption")]
+fn fill_random(dest: &mut [u8]) -> ParquetResult<()> {
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    SystemRandom::new()
+        .fill(dest)
+        .map_err(|_| ParquetError::InvalidParameter("failed to generate random bytes".to_string()))
+}
+
+#[cfg(not(feature = "encryption"))]
+fn fill_random(_dest: &mut [u8]) -> ParquetResult<()> {
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "encrypt parquet files".to_string(),
+    ))
+}
+
+/// Encrypts `plaintext` into a module, consisting of its length (4 bytes), a random nonce,
+/// the ciphertext and the authentication tag.
+#[cfg_attr(not(feature = "encryption"), allow(unused_variables))]
+fn encrypt_module(key: &[u8], aad: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+    #[cfg(feature = "encryption")]
+    {
+        use ring::aead::{Aad, Nonce};
+
+        let key = aead_key(key)?;
+        let len: u32 = (NONCE_LEN + plaintext.len() + TAG_LEN)
+            .try_into()
+            .map_err(|_| ParquetError::oos("An encrypted module can contain at most 4GB"))?;
+
+        let mut module = Vec::with_capacity(4 + len as usize);
+        module.extend_from_slice(&len.to_le_bytes());
+        let mut nonce = [0u8; NONCE_LEN];
+        fill_random(&mut nonce)?;
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(plaintext);
+
+        let tag = key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut module[4 + NONCE_LEN..],
+            )
+            .map_err(|_| ParquetError::oos("Failed to encrypt a module"))?;
+        module.extend_from_slice(tag.as_ref());
+        Ok(module)
+    }
+    #[cfg(not(feature = "encryption"))]
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "encrypt parquet files".to_string(),
+    ))
+}
+
+/// Checks that a module has at least `min_len` bytes and that its length matches its size.
+fn check_module_len(module: &[u8], min_len: usize) -> ParquetResult<()> {
+    if module.len() < min_len {
+        return Err(ParquetError::oos("An encrypted module is too short"));
+    }
+    let len = u32::from_le_bytes(module[..4].try_into().unwrap()) as usize;
+    if len != module.len() - 4 {
+        return Err(ParquetError::oos(
+            "The length of an encrypted module does not match its size",
+        ));
+    }
+    Ok(())
+}
+
+/// Decrypts a module, including its length, and returns the plaintext.
+#[cfg_attr(not(feature = "encryption"), allow(unused_variables))]
+fn decrypt_module(key: &[u8], aad: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    check_module_len(module, 4 + NONCE_LEN + TAG_LEN)?;
+
+    #[cfg(feature = "encryption")]
+    {
+        use ring::aead::{Aad, Nonce};
+
+        let key = aead_key(key)?;
+        let nonce = Nonce::try_assume_unique_for_key(&module[4..4 + NONCE_LEN]).unwrap();
+        let mut plaintext = module[4 + NONCE_LEN..].to_vec();
+        let len = key
+            .open_in_place(nonce, Aad::from(aad), &mut plaintext)
+            .map_err(|_| {
+                ParquetError::oos("Failed to decrypt a module: the key or the file is invalid")
+            })?
+            .len();
+        plaintext.truncate(len);
+        Ok(plaintext)
+    }
+    #[cfg(not(feature = "encryption"))]
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "decrypt parquet files".to_string(),
+    ))
+}
+
+/// Decrypts a page module of a file encrypted with `AES_GCM_CTR_V1`, which consists of its
+/// length, a nonce and the ciphertext, and returns the plaintext.
+#[cfg_attr(not(feature = "encryption"), allow(unused_variables))]
+fn decrypt_ctr_module(key: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    check_module_len(module, 4 + NONCE_LEN)?;
+
+    #[cfg(feature = "encryption")]
+    {
+        use aes::cipher::{KeyIvInit, StreamCipher};
+
+        // The counter takes the last 4 bytes of the IV, in big-endian, and starts at 1.
+        let mut iv = [0u8; 16];
+        iv[..NONCE_LEN].copy_from_slice(&module[4..4 + NONCE_LEN]);
+        iv[15] = 1;
+        let mut plaintext = module[4 + NONCE_LEN..].to_vec();
+        match key.len() {
+            16 => ctr::Ctr32BE::<aes::Aes128>::new_from_slices(key, &iv)
+                .unwrap()
+                .apply_keystream(&mut plaintext),
+            32 => ctr::Ctr32BE::<aes::Aes256>::new_from_slices(key, &iv)
+                .unwrap()
+                .apply_keystream(&mut plaintext),
+            len => return Err(invalid_key_length(len)),
+        }
+        Ok(plaintext)
+    }
+    #[cfg(not(feature = "encryption"))]
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "decrypt parquet files".to_string(),
+    ))
+}
+
+/// Reads a module, including its length, from `reader`.
+pub(crate) fn read_module<R: Read>(reader: &mut R, max_size: usize) -> ParquetResult<Vec<u8>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let module_len = u32::from_le_bytes(len) as usize;
+    if module_len > max_size {
+        return Err(ParquetError::WouldOverAllocate);
+    }
+    let mut module = Vec::with_capacity(4 + module_len);
+    module.extend_from_slice(&len);
+    reader.take(module_len as u64).read_to_end(&mut module)?;
+    if module.len() != 4 + module_len {
+        return Err(ParquetError::oos(
+            "The file ended in the middle of an encrypted module",
+        ));
+    }
+    Ok(module)
+}
+
+/// Reads a module, including its length, from the async `reader`.
+#[cfg(feature = "async")]
+pub(crate) async fn read_module_async<R: futures::AsyncRead + Unpin + Send>(
+    reader: &mut R,
+    max_size: usize,
+) -> ParquetResult<Vec<u8>> {
+    use futures::AsyncReadExt;
+
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len).await?;
+    let module_len = u32::from_le_bytes(len) as usize;
+    if module_len > max_size {
+        return Err(ParquetError::WouldOverAllocate);
+    }
+    let mut module = Vec::with_capacity(4 + module_len);
+    module.extend_from_slice(&len);
+    reader
+        .take(module_len as u64)
+        .read_to_end(&mut module)
+        .await?;
+    if module.len() != 4 + module_len {
+        return Err(ParquetError::oos(
+            "The file ended in the middle of an encrypted module",
+        ));
+    }
+    Ok(module)
+}
+
+/// Retrieves the keys of an encrypted file.
+///
+/// Keys are identified by the key metadata stored in the file, which is empty if the writer
+/// did not set it.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key (of 16 or 32 bytes) identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// A map from key metadata to keys.
+impl KeyRetriever for PlHashMap<Vec<u8>, Vec<u8>> {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        self.get(key_metadata).cloned().ok_or_else(|| {
+            ParquetError::InvalidParameter(format!(
+                "no key for key metadata {:?}",
+                String::from_utf8_lossy(key_metadata)
+            ))
+        })
+    }
+}
+
+/// The properties to decrypt a file.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    key_retriever: Arc<dyn KeyRetriever>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .field("aad_prefix", &self.aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Two [`FileDecryptionProperties`] are equal if they share the same key retriever.
+impl PartialEq for FileDecryptionProperties {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.key_retriever, &other.key_retriever)
+            && self.aad_prefix == other.aad_prefix
+    }
+}
+
+impl Eq for FileDecryptionProperties {}
+
+impl Hash for FileDecryptionProperties {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.key_retriever) as *const () as usize).hash(state);
+        self.aad_prefix.hash(state);
+    }
+}
+
+impl FileDecryptionProperties {
+    /// Returns new [`FileDecryptionProperties`] that retrieve keys with `key_retriever`.
+    pub fn new(key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        Self {
+            key_retriever,
+            aad_prefix: None,
+        }
+    }
+
+    /// Sets the AAD prefix, for files written with an AAD prefix that is not stored in the file.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    fn retrieve_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Vec<u8>> {
+        self.key_retriever
+            .retrieve_key(key_metadata.unwrap_or_default())
+    }
+
+    /// Returns the [`FileDecryptor`] of a file with the given algorithm and footer key metadata.
+    pub(crate) fn file_decryptor(
+        &self,
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<FileDecryptor> {
+        let (algorithm, file_aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+            EncryptionAlgorithm::AESGCMV1(algorithm) => (
+                Algorithm::AesGcmV1,
+                &algorithm.aad_prefix,
+                &algorithm.aad_file_unique,
+                algorithm.supply_aad_prefix,
+            ),
+            EncryptionAlgorithm::AESGCMCTRV1(algorithm) => (
+                Algorithm::AesGcmCtrV1,
+                &algorithm.aad_prefix,
+                &algorithm.aad_file_unique,
+                algorithm.supply_aad_prefix,
+            ),
+        };
+        let aad_prefix = match (file_aad_prefix, &self.aad_prefix) {
+            (_, Some(aad_prefix)) | (Some(aad_prefix), None) => aad_prefix.as_slice(),
+            (None, None) if supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file requires an AAD prefix to be supplied".to_string(),
+                ))
+            },
+            (None, None) => &[],
+        };
+        let mut file_aad = aad_prefix.to_vec();
+        file_aad.extend_from_slice(aad_file_unique.as_deref().unwrap_or_default());
+
+        Ok(FileDecryptor {
+            properties: self.clone(),
+            algorithm,
+            file_aad,
+            footer_key: self.retrieve_key(footer_key_metadata)?,
+        })
+    }
+}
+
+/// Decrypts the metadata of an encrypted file.
+pub(crate) struct FileDecryptor {
+    properties: FileDecryptionProperties,
+    algorithm: Algorithm,
+    file_aad: Vec<u8>,
+    footer_key: Vec<u8>,
+}
+
+impl FileDecryptor {
+    /// Decrypts the footer module of a file with an encrypted footer.
+    pub(crate) fn decrypt_footer(&self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        decrypt_module(&self.footer_key, &aad, module)
+    }
+
+    /// Returns the [`ColumnDecryptor`] of an encrypted column chunk.
+    pub(crate) fn column_decryptor(
+        &self,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<ColumnDecryptor> {
+        let key = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => self.footer_key.clone(),
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key) => self
+                .properties
+                .retrieve_key(column_key.key_metadata.as_deref())?,
+        };
+        Ok(ColumnDecryptor {
+            key,
+            algorithm: self.algorithm,
+            file_aad: self.file_aad.clone(),
+            row_group,
+            column,
+        })
+    }
+}
+
+/// Decrypts the modules of a column chunk.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnDecryptor {
+    key: Vec<u8>,
+    algorithm: Algorithm,
+    file_aad: Vec<u8>,
+    row_group: usize,
+    column: usize,
+}
+
+impl Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("row_group", &self.row_group)
+            .field("column", &self.column)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnDecryptor {
+    /// Decrypts a module of this column chunk. `page` is the ordinal of a data page.
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        if self.algorithm == Algorithm::AesGcmCtrV1
+            && matches!(
+                module_type,
+                ModuleType::DataPage | ModuleType::DictionaryPage
+            )
+        {
+            return decrypt_ctr_module(&self.key, module);
+        }
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )?;
+        decrypt_module(&self.key, &aad, module)
+    }
+}
+
+/// The key of a column of a file.
+#[derive(Clone)]
+struct ColumnKey {
+    path: Vec<String>,
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+/// The properties to encrypt a file.
+///
+/// The footer is encrypted with the footer key. If no column keys are set, all columns are
+/// encrypted with the footer key; otherwise only the columns with a key are encrypted.
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: Vec<ColumnKey>,
+}
+
+impl Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileEncryptionProperties")
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field(
+                "column_keys",
+                &self
+                    .column_keys
+                    .iter()
+                    .map(|column| (&column.path, &column.key_metadata))
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileEncryptionProperties {
+    /// Returns new [`FileEncryptionProperties`] with the footer key (of 16 or 32 bytes) and
+    /// the metadata that readers use to retrieve it.
+    pub fn try_new(
+        footer_key: Vec<u8>,
+        footer_key_metadata: Option<Vec<u8>>,
+    ) -> ParquetResult<Self> {
+        if !matches!(footer_key.len(), 16 | 32) {
+            return Err(invalid_key_length(footer_key.len()));
+        }
+        Ok(Self {
+            footer_key,
+            footer_key_metadata,
+            column_keys: vec![],
+        })
+    }
+
+    /// Encrypts the leaf columns of which the path in the schema starts with `path` with `key`.
+    pub fn try_with_column_key(
+        mut self,
+        path: Vec<String>,
+        key: Vec<u8>,
+        key_metadata: Option<Vec<u8>>,
+    ) -> ParquetResult<Self> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(invalid_key_length(key.len()));
+        }
+        self.column_keys.push(ColumnKey {
+            path,
+            key,
+            key_metadata,
+        });
+        Ok(self)
+    }
+}
+
+/// Encrypts the modules of a file.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    file_aad: Vec<u8>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        let mut file_aad = vec![0u8; AAD_FILE_UNIQUE_LEN];
+        fill_random(&mut file_aad)?;
+        Ok(Self {
+            properties,
+            file_aad,
+        })
+    }
+
+    /// Returns the [`ColumnEncryptor`] of a leaf column, or `None` if it is not encrypted.
+    pub(crate) fn column_encryptor(
+        &self,
+        path_in_schema: &[String],
+        row_group: usize,
+        column: usize,
+    ) -> Option<ColumnEncryptor> {
+        let (key, crypto_metadata) = if self.properties.column_keys.is_empty() {
+            (
+                self.properties.footer_key.clone(),
+                ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey::new()),
+            )
+        } else {
+            let column_key = self
+                .properties
+                .column_keys
+                .iter()
+                .find(|column_key| path_in_schema.starts_with(&column_key.path))?;
+            (
+                column_key.key.clone(),
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                    path_in_schema: path_in_schema.to_vec(),
+                    key_metadata: column_key.key_metadata.clone(),
+                }),
+            )
+        };
+        Some(ColumnEncryptor {
+            key,
+            file_aad: self.file_aad.clone(),
+            row_group,
+            column,
+            crypto_metadata,
+        })
+    }
+
+    /// Returns the crypto metadata, which is written in plaintext before the encrypted footer.
+    pub(crate) fn crypto_metadata(&self) -> FileCryptoMetaData {
+        FileCryptoMetaData {
+            encryption_algorithm: EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix: None,
+                aad_file_unique: Some(self.file_aad.clone()),
+                supply_aad_prefix: None,
+            }),
+            key_metadata: self.properties.footer_key_metadata.clone(),
+        }
+    }
+
+    /// Encrypts the footer of the file.
+    pub(crate) fn encrypt_footer(&self, footer: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        encrypt_module(&self.properties.footer_key, &aad, footer)
+    }
+}
+
+/// Encrypts the modules of a column chunk.
+pub(crate) struct ColumnEncryptor {
+    key: Vec<u8>,
+    file_aad: Vec<u8>,
+    row_group: usize,
+    column: usize,
+    crypto_metadata: ColumnCryptoMetaData,
+}
+
+impl ColumnEncryptor {
+    /// Encrypts a module of this column chunk. `page` is the ordinal of a data page.
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )?;
+        encrypt_module(&self.key, &aad, plaintext)
+    }
+
+    /// The crypto metadata of the column chunk.
+    pub(crate) fn crypto_metadata(&self) -> &ColumnCryptoMetaData {
+        &self.crypto_metadata
+    }
+
+    /// Whether the column is encrypted with a key of its own, in which case its metadata is
+    /// encrypted separately from the footer.
+    pub(crate) fn has_column_key(&self) -> bool {
+        matches!(
+            self.crypto_metadata,
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(_)
+        )
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_roundtrip() -> ParquetResult<()> {
+        let key = [7u8; 16];
+        let aad = module_aad(b"file", ModuleType::DataPage, 1, 2, Some(3))?;
+        assert_eq!(aad, [b'f', b'i', b'l', b'e', 2, 1, 0, 2, 0, 3, 0]);
+
+        let module = encrypt_module(&key, &aad, b"page")?;
+        assert_eq!(module.len(), 4 + NONCE_LEN + 4 + TAG_LEN);
+        assert_eq!(decrypt_module(&key, &aad, &module)?, b"page");
+
+        // the module is bound to its AAD
+        let other = module_aad(b"file", ModuleType::DataPage, 1, 2, Some(4))?;
+        assert!(decrypt_module(&key, &other, &module).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ctr_pages() -> ParquetResult<()> {
+        use aes::cipher::{KeyIvInit, StreamCipher};
+        use parquet_format_safe::AesGcmCtrV1;
+
+        let key = [7u8; 32];
+        let keys = PlHashMap::from_iter([(b"key".to_vec(), key.to_vec())]);
+        let algorithm = EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+            aad_prefix: None,
+            aad_file_unique: Some(b"file".to_vec()),
+            supply_aad_prefix: None,
+        });
+        let decryptor = FileDecryptionProperties::new(Arc::new(keys))
+            .file_decryptor(&algorithm, Some(b"key"))?
+            .column_decryptor(
+                &ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey::new()),
+                1,
+                2,
+            )?;
+
+        let nonce = [9u8; NONCE_LEN];
+        let mut iv = [0u8; 16];
+        iv[..NONCE_LEN].copy_from_slice(&nonce);
+        iv[15] = 1;
+        let mut ciphertext = vec![5u8; 100];
+        ctr::Ctr32BE::<aes::Aes256>::new_from_slices(&key, &iv)
+            .unwrap()
+            .apply_keystream(&mut ciphertext);
+        let mut module = ((NONCE_LEN + ciphertext.len()) as u32)
+            .to_le_bytes()
+            .to_vec();
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(&ciphertext);
+        assert_eq!(
+            decryptor.decrypt(ModuleType::DataPage, Some(0), &module)?,
+            [5u8; 100]
+        );
+
+        // the other modules are encrypted with AES-GCM
+        let aad = module_aad(b"file", ModuleType::DataPageHeader, 1, 2, Some(0))?;
+        let module = encrypt_module(&key, &aad, b"header")?;
+        assert_eq!(
+            decryptor.decrypt(ModuleType::DataPageHeader, Some(0), &module)?,
+            b"header"
+        );
+        Ok(())
+    }
+}
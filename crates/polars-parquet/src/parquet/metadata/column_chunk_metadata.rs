@@ -1,7 +1,11 @@
+use std::sync::Arc;
+
+use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
 use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnDecryptor, FileDecryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -10,9 +14,7 @@ use crate::parquet::statistics::Statistics;
 mod serde_types {
     pub use std::io::Cursor;
 
-    pub use parquet_format_safe::thrift::protocol::{
-        TCompactInputProtocol, TCompactOutputProtocol,
-    };
+    pub use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
     pub use serde::de::Error as DeserializeError;
     pub use serde::ser::Error as SerializeError;
     pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -36,6 +38,9 @@ pub struct ColumnChunkMetaData {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// Decrypts the pages of the column chunk, if it is encrypted.
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -73,6 +78,7 @@ impl ColumnChunkMetaData {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -99,6 +105,11 @@ impl ColumnChunkMetaData {
         self.column_chunk.meta_data.as_ref().unwrap()
     }
 
+    /// The [`ColumnDecryptor`] of this column chunk, if it is encrypted.
+    pub fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
     /// The [`ColumnDescriptor`] for this column. This descriptor contains the physical and logical type
     /// of the pages.
     pub fn descriptor(&self) -> &ColumnDescriptor {
@@ -176,10 +187,38 @@ impl ColumnChunkMetaData {
     }
 
     /// Method to convert from Thrift.
+    ///
+    /// The metadata of an encrypted column chunk is decrypted with `decryptor`, using the
+    /// ordinals of its row group and column.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
-        column_chunk: ColumnChunk,
+        mut column_chunk: ColumnChunk,
+        decryptor: Option<&FileDecryptor>,
+        row_group: usize,
+        column: usize,
     ) -> ParquetResult<Self> {
+        let decryptor = match (&column_chunk.crypto_metadata, decryptor) {
+            (None, _) => None,
+            (Some(_), None) => {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "column {:?} is encrypted and requires decryption properties to be read",
+                    column_descr.path_in_schema
+                )))
+            },
+            (Some(crypto_metadata), Some(decryptor)) => {
+                let decryptor = decryptor.column_decryptor(crypto_metadata, row_group, column)?;
+                if let Some(encrypted) = &column_chunk.encrypted_column_metadata {
+                    let metadata =
+                        decryptor.decrypt(ModuleType::ColumnMetaData, None, encrypted)?;
+                    let max_size = metadata.len() * 2 + 1024;
+                    let mut prot = TCompactInputProtocol::new(metadata.as_slice(), max_size);
+                    column_chunk.meta_data =
+                        Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+                }
+                Some(Arc::new(decryptor))
+            },
+        };
+
         // validate metadata
         if let Some(meta) = &column_chunk.meta_data {
             let _: u64 = meta.total_compressed_size.try_into()?;
@@ -197,6 +236,7 @@ impl ColumnChunkMetaData {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor,
         })
     }
 
@@ -1,8 +1,9 @@
-use parquet_format_safe::ColumnOrder as TColumnOrder;
+use parquet_format_safe::{ColumnOrder as TColumnOrder, EncryptionAlgorithm};
 
 use super::column_order::ColumnOrder;
 use super::schema_descriptor::SchemaDescriptor;
 use super::RowGroupMetaData;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::ParquetError;
 use crate::parquet::metadata::get_sort_order;
 pub use crate::parquet::thrift_format::KeyValue;
@@ -39,6 +40,8 @@ pub struct FileMetaData {
     /// When `None` is returned, there are no column orders available, and each column
     /// should be assumed to have undefined (legacy) column order.
     pub column_orders: Option<Vec<ColumnOrder>>,
+    /// The algorithm with which the file is encrypted, if it is encrypted.
+    pub encryption_algorithm: Option<EncryptionAlgorithm>,
 }
 
 impl FileMetaData {
@@ -64,13 +67,25 @@ impl FileMetaData {
     /// Deserializes [`crate::parquet::thrift_format::FileMetaData`] into this struct
     pub fn try_from_thrift(
         metadata: parquet_format_safe::FileMetaData,
+    ) -> Result<Self, ParquetError> {
+        Self::try_from_thrift_with_decryptor(metadata, None)
+    }
+
+    /// Deserializes [`crate::parquet::thrift_format::FileMetaData`] into this struct, decrypting
+    /// the metadata of encrypted columns with `decryptor`.
+    pub(crate) fn try_from_thrift_with_decryptor(
+        metadata: parquet_format_safe::FileMetaData,
+        decryptor: Option<&FileDecryptor>,
     ) -> Result<Self, ParquetError> {
         let schema_descr = SchemaDescriptor::try_from_thrift(&metadata.schema)?;
 
         let row_groups = metadata
             .row_groups
             .into_iter()
-            .map(|rg| RowGroupMetaData::try_from_thrift(&schema_descr, rg))
+            .enumerate()
+            .map(|(ordinal, rg)| {
+                RowGroupMetaData::try_from_thrift(&schema_descr, rg, decryptor, ordinal)
+            })
             .collect::<Result<_, ParquetError>>()?;
 
         let column_orders = metadata
@@ -85,6 +100,7 @@ impl FileMetaData {
             key_value_metadata: metadata.key_value_metadata,
             schema_descr,
             column_orders,
+            encryption_algorithm: metadata.encryption_algorithm,
         })
     }
 
@@ -102,7 +118,7 @@ impl FileMetaData {
             key_value_metadata: self.key_value_metadata,
             created_by: self.created_by,
             column_orders: None, // todo
-            encryption_algorithm: None,
+            encryption_algorithm: self.encryption_algorithm,
             footer_signing_key_metadata: None,
         }
     }
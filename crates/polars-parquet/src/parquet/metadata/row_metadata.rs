@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use super::column_chunk_metadata::ColumnChunkMetaData;
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::ColumnOffsetsMetadata;
 
@@ -53,10 +54,12 @@ impl RowGroupMetaData {
             .sum::<usize>()
     }
 
-    /// Method to convert from Thrift.
+    /// Method to convert from Thrift. `ordinal` is the position of the row group in the file.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
         rg: RowGroup,
+        decryptor: Option<&FileDecryptor>,
+        ordinal: usize,
     ) -> ParquetResult<RowGroupMetaData> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!("The number of columns in the row group ({}) must be equal to the number of columns in the schema ({})", rg.columns.len(), schema_descr.columns().len())));
@@ -67,8 +70,15 @@ impl RowGroupMetaData {
             .columns
             .into_iter()
             .zip(schema_descr.columns())
-            .map(|(column_chunk, descriptor)| {
-                ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
+            .enumerate()
+            .map(|(column, (column_chunk, descriptor))| {
+                ColumnChunkMetaData::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    decryptor,
+                    ordinal,
+                    column,
+                )
             })
             .collect::<ParquetResult<Vec<_>>>()?;
 
@@ -9,7 +9,9 @@ pub fn deserialize(
     data: &[u8],
     primitive_type: PrimitiveType,
 ) -> Result<Box<dyn Index>, ParquetError> {
-    let mut prot = TCompactInputProtocol::new(data, data.len() * 2 + 1024);
+    // Every element of a list is accounted for as a `usize`, but takes at least a byte.
+    let max_size = data.len() * std::mem::size_of::<usize>() + 1024;
+    let mut prot = TCompactInputProtocol::new(data, max_size);
 
     let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
 
@@ -1,9 +1,11 @@
-use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::borrow::Cow;
+use std::io::{Read, Seek, SeekFrom};
 
 use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
 use parquet_format_safe::{ColumnChunk, OffsetIndex, PageLocation};
 
 use super::deserialize::deserialize;
+use crate::parquet::encryption::ModuleType;
 use crate::parquet::error::ParquetError;
 use crate::parquet::indexes::Index;
 use crate::parquet::metadata::ColumnChunkMetaData;
@@ -57,24 +59,36 @@ fn prepare_offset_index_read(
     prepare_read(chunks, |x| x.offset_index_offset, |x| x.offset_index_length)
 }
 
+/// Splits `data` into the indexes of the column chunks, which are decrypted if the column chunk
+/// is encrypted.
+fn split_indexes<'a>(
+    chunks: &'a [ColumnChunkMetaData],
+    data: &'a [u8],
+    lengths: Vec<usize>,
+    module_type: ModuleType,
+) -> impl Iterator<Item = Result<(&'a ColumnChunkMetaData, Cow<'a, [u8]>), ParquetError>> {
+    let mut start = 0;
+    chunks.iter().zip(lengths).map(move |(chunk, length)| {
+        let data = &data[start..start + length];
+        start += length;
+        let data = match chunk.decryptor() {
+            Some(decryptor) => Cow::Owned(decryptor.decrypt(module_type, None, data)?),
+            None => Cow::Borrowed(data),
+        };
+        Ok((chunk, data))
+    })
+}
+
 fn deserialize_column_indexes(
     chunks: &[ColumnChunkMetaData],
     data: &[u8],
     lengths: Vec<usize>,
 ) -> Result<Vec<Box<dyn Index>>, ParquetError> {
-    let mut start = 0;
-    let data = lengths.into_iter().map(|length| {
-        let r = &data[start..start + length];
-        start += length;
-        r
-    });
-
-    chunks
-        .iter()
-        .zip(data)
-        .map(|(chunk, data)| {
+    split_indexes(chunks, data, lengths, ModuleType::ColumnIndex)
+        .map(|index| {
+            let (chunk, data) = index?;
             let primitive_type = chunk.descriptor().descriptor.primitive_type.clone();
-            deserialize(data, primitive_type)
+            deserialize(&data, primitive_type)
         })
         .collect()
 }
@@ -99,15 +113,17 @@ pub fn read_columns_indexes<R: Read + Seek>(
 }
 
 fn deserialize_page_locations(
+    chunks: &[ColumnChunkMetaData],
     data: &[u8],
-    column_number: usize,
+    lengths: Vec<usize>,
 ) -> Result<Vec<Vec<PageLocation>>, ParquetError> {
-    let len = data.len() * 2 + 1024;
-    let mut reader = Cursor::new(data);
+    // Every element of a list is accounted for as a `usize`, but takes at least a byte.
+    let len = data.len() * std::mem::size_of::<usize>() + 1024;
 
-    (0..column_number)
-        .map(|_| {
-            let mut prot = TCompactInputProtocol::new(&mut reader, len);
+    split_indexes(chunks, data, lengths, ModuleType::OffsetIndex)
+        .map(|index| {
+            let (_, data) = index?;
+            let mut prot = TCompactInputProtocol::new(&*data, len);
             let offset = OffsetIndex::read_from_in_protocol(&mut prot)?;
             Ok(offset.page_locations)
         })
@@ -130,5 +146,5 @@ pub fn read_pages_locations<R: Read + Seek>(
     data.try_reserve(length)?;
     reader.by_ref().take(length as u64).read_to_end(&mut data)?;
 
-    deserialize_page_locations(&data, chunks.len())
+    deserialize_page_locations(chunks, &data, lengths)
 }
@@ -2,10 +2,13 @@ use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
 use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
-use parquet_format_safe::FileMetaData as TFileMetaData;
+use parquet_format_safe::{FileCryptoMetaData, FileMetaData as TFileMetaData};
 
+use super::super::encryption::FileDecryptionProperties;
 use super::super::metadata::FileMetaData;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -37,6 +40,26 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetaDa
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetaData> {
+    read_metadata_impl(reader, file_size, None)
+}
+
+/// Reads a [`FileMetaData`] from the reader, located at the end of the file.
+///
+/// The footer and the metadata of the encrypted columns of an encrypted file are decrypted with
+/// `decryption`, which must be given to read encrypted files.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetaData> {
+    let file_size = stream_len(reader)?;
+    read_metadata_impl(reader, file_size, decryption)
+}
+
+fn read_metadata_impl<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetaData> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,7 +78,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let encrypted_footer = buffer[default_end_len - 4..] == PARQUET_ENCRYPTED_MAGIC;
+    if !encrypted_footer && buffer[default_end_len - 4..] != PARQUET_MAGIC {
         return Err(ParquetError::oos("The file must end with PAR1"));
     }
 
@@ -88,7 +112,66 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata_with_decryption(
+        &reader[..reader.len() - FOOTER_SIZE as usize],
+        encrypted_footer,
+        max_size,
+        decryption,
+    )
+}
+
+/// Parses the loaded metadata bytes of a file, which is decrypted with `decryption` if the file
+/// is encrypted. `encrypted_footer` is whether the file ends with the `PARE` magic.
+pub fn deserialize_metadata_with_decryption(
+    metadata: &[u8],
+    encrypted_footer: bool,
+    max_size: usize,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetaData> {
+    if encrypted_footer {
+        let decryption = decryption.ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "the file has an encrypted footer and requires decryption properties to be read"
+                    .to_string(),
+            )
+        })?;
+        return deserialize_encrypted_metadata(metadata, max_size, decryption);
+    }
+
+    let mut prot = TCompactInputProtocol::new(metadata, max_size);
+    let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+
+    // a file with a plaintext footer may still contain encrypted columns
+    let decryptor = match (&metadata.encryption_algorithm, decryption) {
+        (Some(algorithm), Some(decryption)) => Some(
+            decryption
+                .file_decryptor(algorithm, metadata.footer_signing_key_metadata.as_deref())?,
+        ),
+        _ => None,
+    };
+    FileMetaData::try_from_thrift_with_decryptor(metadata, decryptor.as_ref())
+}
+
+/// Deserializes the footer of a file with an encrypted footer, which consists of the plaintext
+/// [`FileCryptoMetaData`] followed by the encrypted [`FileMetaData`].
+fn deserialize_encrypted_metadata(
+    mut footer: &[u8],
+    max_size: usize,
+    decryption: &FileDecryptionProperties,
+) -> ParquetResult<FileMetaData> {
+    let mut prot = TCompactInputProtocol::new(&mut footer, max_size);
+    let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+
+    let decryptor = decryption.file_decryptor(
+        &crypto_metadata.encryption_algorithm,
+        crypto_metadata.key_metadata.as_deref(),
+    )?;
+    let footer = decryptor.decrypt_footer(footer)?;
+
+    let mut prot = TCompactInputProtocol::new(footer.as_slice(), max_size);
+    let mut metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+    metadata.encryption_algorithm = Some(crypto_metadata.encryption_algorithm);
+    FileMetaData::try_from_thrift_with_decryptor(metadata, Some(&decryptor))
 }
 
 /// Parse loaded metadata bytes
@@ -6,6 +6,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 
 use super::PageIterator;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{read_module, ColumnDecryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::indexes::Interval;
 use crate::parquet::metadata::{ColumnChunkMetaData, Descriptor};
@@ -27,6 +28,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// Decrypts the pages of this column chunk, if it is encrypted
+    pub decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 impl PageMetaData {
@@ -42,6 +45,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            decryptor: None,
         }
     }
 }
@@ -53,6 +57,7 @@ impl From<&ColumnChunkMetaData> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            decryptor: column.decryptor().cloned(),
         }
     }
 }
@@ -86,6 +91,12 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Decrypts the page headers and pages of an encrypted column chunk.
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of data pages we have seen so far, which is part of the AAD of encrypted pages.
+    seen_data_pages: usize,
 }
 
 impl PageReader {
@@ -122,6 +133,8 @@ impl PageReader {
             pages_filter,
             scratch,
             max_page_size,
+            decryptor: reader_meta.decryptor,
+            seen_data_pages: 0,
         }
     }
 
@@ -139,7 +152,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -154,13 +167,7 @@ impl PageReader {
             return Err(ParquetError::WouldOverAllocate);
         }
 
-        let buffer = self.reader.read_slice(read_size);
-
-        if buffer.len() != read_size {
-            return Err(ParquetError::oos(
-                "The page header reported the wrong page size",
-            ));
-        }
+        let buffer = self.read_page(read_size, page_type)?;
 
         finish_page(
             page_header,
@@ -177,6 +184,37 @@ impl PageReader {
             }
         })
     }
+
+    /// Reads the next page header, which is decrypted if the column chunk is encrypted.
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = &self.decryptor else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+        let module = read_module(&mut self.reader, self.max_page_size)?;
+        decrypt_page_header(decryptor, &module, self.seen_data_pages, self.max_page_size)
+    }
+
+    /// Reads the (compressed) page of `read_size` bytes that follows its header, which is
+    /// decrypted if the column chunk is encrypted.
+    fn read_page(&mut self, read_size: usize, page_type: PageType) -> ParquetResult<MemSlice> {
+        let buffer = self.reader.read_slice(read_size);
+
+        if buffer.len() != read_size {
+            return Err(ParquetError::oos(
+                "The page header reported the wrong page size",
+            ));
+        }
+
+        let Some(decryptor) = &self.decryptor else {
+            return Ok(buffer);
+        };
+        let page = self.seen_data_pages;
+        if !matches!(page_type, PageType::DictionaryPage) {
+            self.seen_data_pages += 1;
+        }
+        let buffer = decrypt_page(decryptor, page_type, page, &buffer)?;
+        Ok(MemSlice::from_vec(buffer))
+    }
 }
 
 impl PageIterator for PageReader {
@@ -218,6 +256,41 @@ pub(super) fn read_page_header(
     Ok(page_header)
 }
 
+/// Decrypts the module of a page header. `page` is the ordinal of the next data page.
+pub(super) fn decrypt_page_header(
+    decryptor: &ColumnDecryptor,
+    module: &[u8],
+    page: usize,
+    max_size: usize,
+) -> ParquetResult<ParquetPageHeader> {
+    // Only the first page can be a dictionary page, of which the header has its own AAD.
+    let header = if page == 0 {
+        decryptor
+            .decrypt(ModuleType::DictionaryPageHeader, None, module)
+            .or_else(|_| decryptor.decrypt(ModuleType::DataPageHeader, Some(page), module))
+    } else {
+        decryptor.decrypt(ModuleType::DataPageHeader, Some(page), module)
+    }?;
+    let mut prot = TCompactInputProtocol::new(header.as_slice(), max_size);
+    Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+}
+
+/// Decrypts the module of a (compressed) page. `page` is the ordinal of the page if it is a data
+/// page.
+pub(super) fn decrypt_page(
+    decryptor: &ColumnDecryptor,
+    page_type: PageType,
+    page: usize,
+    module: &[u8],
+) -> ParquetResult<Vec<u8>> {
+    match page_type {
+        PageType::DictionaryPage => decryptor.decrypt(ModuleType::DictionaryPage, None, module),
+        PageType::DataPage | PageType::DataPageV2 => {
+            decryptor.decrypt(ModuleType::DataPage, Some(page), module)
+        },
+    }
+}
+
 /// This function is lightweight and executes a minimal amount of work so that it is IO bounded.
 // Any un-necessary CPU-intensive tasks SHOULD be executed on individual pages.
 fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
@@ -228,7 +301,7 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_header(&page_header)?
         .map(|x| x.num_values() as i64)
@@ -240,13 +313,7 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
         return Err(ParquetError::WouldOverAllocate);
     }
 
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page(read_size, page_header.type_.try_into()?)?;
 
     finish_page(
         page_header,
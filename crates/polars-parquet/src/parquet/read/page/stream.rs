@@ -6,12 +6,14 @@ use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, Stream};
 use parquet_format_safe::thrift::protocol::TCompactInputStreamProtocol;
 use polars_utils::mmap::MemSlice;
 
-use super::reader::{finish_page, get_page_header, PageMetaData};
+use super::reader::{
+    decrypt_page, decrypt_page_header, finish_page, get_page_header, PageMetaData,
+};
 use super::PageFilter;
-use crate::parquet::compression::Compression;
+use crate::parquet::encryption::read_module_async;
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::metadata::{ColumnChunkMetaData, Descriptor};
-use crate::parquet::page::{CompressedPage, ParquetPageHeader};
+use crate::parquet::metadata::ColumnChunkMetaData;
+use crate::parquet::page::{CompressedPage, PageType, ParquetPageHeader};
 
 /// Returns a stream of compressed data pages
 pub async fn get_page_stream<'a, RR: AsyncRead + Unpin + Send + AsyncSeek>(
@@ -42,9 +44,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     let page_metadata: PageMetaData = column_metadata.into();
     Ok(_get_page_stream(
         reader,
-        page_metadata.num_values,
-        page_metadata.compression,
-        page_metadata.descriptor,
+        page_metadata,
         scratch,
         pages_filter,
         max_header_size,
@@ -63,9 +63,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     reader.seek(SeekFrom::Start(column_start)).await?;
     Ok(_get_page_stream(
         reader,
-        page_metadata.num_values,
-        page_metadata.compression,
-        page_metadata.descriptor,
+        page_metadata,
         scratch,
         pages_filter,
         max_page_size,
@@ -74,18 +72,36 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
 
 fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
-    total_num_values: i64,
-    compression: Compression,
-    descriptor: Descriptor,
+    page_metadata: PageMetaData,
     mut scratch: Vec<u8>,
     pages_filter: PageFilter,
     max_page_size: usize,
 ) -> impl Stream<Item = ParquetResult<CompressedPage>> + '_ {
+    let PageMetaData {
+        num_values: total_num_values,
+        compression,
+        descriptor,
+        decryptor,
+        ..
+    } = page_metadata;
     let mut seen_values = 0i64;
+    // The ordinal of data pages is part of the AAD of encrypted pages.
+    let mut seen_data_pages = 0usize;
     try_stream! {
         while seen_values < total_num_values {
             // the header
-            let page_header = read_page_header(reader, max_page_size).await?;
+            let page_header = match &decryptor {
+                Some(decryptor) => {
+                    let module = read_module_async(reader, max_page_size).await?;
+                    decrypt_page_header(decryptor, &module, seen_data_pages, max_page_size)?
+                },
+                None => read_page_header(reader, max_page_size).await?,
+            };
+            let page_type: PageType = page_header.type_.try_into()?;
+            let page = seen_data_pages;
+            if !matches!(page_type, PageType::DictionaryPage) {
+                seen_data_pages += 1;
+            }
 
             let data_header = get_page_header(&page_header)?;
             seen_values += data_header.as_ref().map(|x| x.num_values() as i64).unwrap_or_default();
@@ -117,9 +133,14 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
                 ))?
             }
 
+            let data = match &decryptor {
+                Some(decryptor) => decrypt_page(decryptor, page_type, page, &scratch)?,
+                None => std::mem::take(&mut scratch),
+            };
+
             yield finish_page(
                 page_header,
-                MemSlice::from_vec(std::mem::take(&mut scratch)),
+                MemSlice::from_vec(data),
                 compression,
                 &descriptor,
                 None,
@@ -10,21 +10,24 @@ use polars_utils::aliases::PlHashSet;
 
 #[cfg(feature = "async")]
 use super::page::write_page_async;
-use super::page::{write_page, PageWriteSpec};
+use super::page::{is_data_page, write_encrypted_page, write_page, PageWriteSpec};
 use super::statistics::reduce;
 use super::DynStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
 use crate::parquet::FallibleStreamingIterator;
 
+/// Writes the pages of a column chunk, encrypting them with `encryptor` if it is set.
 pub fn write_column_chunk<W, E>(
     writer: &mut W,
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<&ColumnEncryptor>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +39,27 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut num_data_pages = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = match encryptor {
+            Some(encryptor) => {
+                write_encrypted_page(writer, offset, compressed_page, encryptor, num_data_pages)?
+            },
+            None => write_page(writer, offset, compressed_page)?,
+        };
         offset += spec.bytes_written;
+        num_data_pages += is_data_page(&spec) as usize;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    // SPEC: the metadata of an encrypted column chunk is only stored in the footer
+    if let Some(encryptor) = encryptor {
+        column_chunk.crypto_metadata = Some(encryptor.crypto_metadata().clone());
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
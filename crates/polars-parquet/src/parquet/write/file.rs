@@ -7,17 +7,48 @@ use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    ColumnEncryptor, FileEncryptionProperties, FileEncryptor, ModuleType,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetaData};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Writes the encrypted footer of a file: the plaintext [`FileCryptoMetaData`] followed by the
+/// encrypted metadata.
+///
+/// [`FileCryptoMetaData`]: parquet_format_safe::FileCryptoMetaData
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetaData,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut footer = vec![];
+    encryptor
+        .crypto_metadata()
+        .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+    let mut plaintext = vec![];
+    metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut plaintext))?;
+    footer.extend(encryptor.encrypt_footer(&plaintext)?);
+
+    let footer_len: i32 = footer
+        .len()
+        .try_into()
+        .map_err(|_| ParquetError::oos("The footer can contain at most i32::MAX bytes"))?;
+    writer.write_all(&footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetaData,
@@ -39,6 +70,33 @@ pub(super) fn end_file<W: Write>(
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
+/// Returns the [`ColumnEncryptor`] of a column chunk, or `None` if it is not encrypted.
+fn column_encryptor(
+    encryptor: Option<&FileEncryptor>,
+    schema: &SchemaDescriptor,
+    row_group: usize,
+    column: usize,
+) -> Option<ColumnEncryptor> {
+    encryptor?.column_encryptor(&schema.columns()[column].path_in_schema, row_group, column)
+}
+
+/// Writes a page index with `write`, encrypting it with `encryptor` if the column chunk is
+/// encrypted. Returns the number of bytes written.
+fn write_index<W: Write>(
+    writer: &mut W,
+    encryptor: Option<ColumnEncryptor>,
+    module_type: ModuleType,
+    write: impl FnOnce(&mut Vec<u8>) -> ParquetResult<u64>,
+) -> ParquetResult<u64> {
+    let mut index = vec![];
+    write(&mut index)?;
+    if let Some(encryptor) = encryptor {
+        index = encryptor.encrypt(module_type, None, &index)?;
+    }
+    writer.write_all(&index)?;
+    Ok(index.len() as u64)
+}
+
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<parquet_format_safe::ColumnOrder> {
     // We only include ColumnOrder for leaf nodes.
     // Currently only supported ColumnOrder is TypeDefinedOrder so we set this
@@ -64,6 +122,8 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// Encrypts the file, if it is encrypted
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -123,11 +183,29 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
         }
     }
 
+    /// Encrypts the file with the given properties.
+    ///
+    /// The footer is encrypted, so readers need the footer key to read the file. The page
+    /// indexes and bloom filters of encrypted columns are encrypted as well.
+    ///
+    /// # Errors
+    /// Returns an error if data has been written to the file.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "Encryption must be set before writing to the file".to_string(),
+            ));
+        }
+        self.encryptor = Some(FileEncryptor::try_new(properties)?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -136,7 +214,13 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = match self.encryptor {
+                Some(_) => {
+                    self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                    PARQUET_ENCRYPTED_MAGIC.len() as u64
+                },
+                None => start_file(&mut self.writer)?,
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -164,6 +248,7 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
@@ -171,6 +256,49 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes the bloom filter of the leaf column `column` of the last written row group.
+    ///
+    /// The bitset is written, together with its header, right after the row group (as
+    /// parquet-mr does), so it must be written before the next row group.
+    ///
+    /// # Errors
+    /// Returns an error if no row group has been written or `column` is out of bounds.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filter(&mut self, column: usize, bitset: &[u8]) -> ParquetResult<()> {
+        let (ordinal, row_group) = match self.row_groups.iter_mut().enumerate().next_back() {
+            Some(row_group) if self.state == State::Started => row_group,
+            _ => {
+                return Err(ParquetError::InvalidParameter(
+                    "A bloom filter can only be written after writing a row group".to_string(),
+                ))
+            },
+        };
+        let Some(column_chunk) = row_group.columns.get_mut(column) else {
+            return Err(ParquetError::InvalidParameter(format!(
+                "Column {column} is out of bounds for a bloom filter"
+            )));
+        };
+        let offset = self.offset;
+        self.offset +=
+            match column_encryptor(self.encryptor.as_ref(), &self.schema, ordinal, column) {
+                // The header and the bitset are encrypted as separate modules.
+                Some(encryptor) => {
+                    let mut header = vec![];
+                    crate::parquet::bloom_filter::write_header(&mut header, bitset.len())?;
+                    let header = encryptor.encrypt(ModuleType::BloomFilterHeader, None, &header)?;
+                    let bitset = encryptor.encrypt(ModuleType::BloomFilterBitset, None, bitset)?;
+                    self.writer.write_all(&header)?;
+                    self.writer.write_all(&bitset)?;
+                    (header.len() + bitset.len()) as u64
+                },
+                None => crate::parquet::bloom_filter::write(&mut self.writer, bitset)?,
+            };
+        if let Some(metadata) = column_chunk.meta_data.as_mut() {
+            metadata.bloom_filter_offset = Some(offset as i64);
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
@@ -191,17 +319,31 @@ impl<W: Write> FileWriter<W> {
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                .enumerate()
+                .try_for_each(|(ordinal, (group, pages))| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .enumerate()
+                        .try_for_each(|(index, (column, pages))| {
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
+                            self.offset += write_index(
+                                &mut self.writer,
+                                column_encryptor(
+                                    self.encryptor.as_ref(),
+                                    &self.schema,
+                                    ordinal,
+                                    index,
+                                ),
+                                ModuleType::ColumnIndex,
+                                |writer| write_column_index(writer, pages),
+                            )?;
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             ParquetResult::Ok(())
-                        },
-                    )?;
+                        })?;
                     ParquetResult::Ok(())
                 })?;
         };
@@ -210,15 +352,22 @@ impl<W: Write> FileWriter<W> {
         self.row_groups
             .iter_mut()
             .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
+            .enumerate()
+            .try_for_each(|(ordinal, (group, pages))| {
                 group
                     .columns
                     .iter_mut()
                     .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .enumerate()
+                    .try_for_each(|(index, (column, pages))| {
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
+                        self.offset += write_index(
+                            &mut self.writer,
+                            column_encryptor(self.encryptor.as_ref(), &self.schema, ordinal, index),
+                            ModuleType::OffsetIndex,
+                            |writer| write_offset_index(writer, pages),
+                        )?;
                         column.offset_index_length = Some((self.offset - offset) as i32);
                         ParquetResult::Ok(())
                     })?;
@@ -237,12 +386,53 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => {
+                let metadata = self.encrypt_column_metadata(metadata.clone(), encryptor)?;
+                end_encrypted_file(&mut self.writer, &metadata, encryptor)?
+            },
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
     }
 
+    /// Encrypts the metadata of the column chunks that are encrypted with a column key, which
+    /// is then omitted from the footer.
+    fn encrypt_column_metadata(
+        &self,
+        mut metadata: ThriftFileMetaData,
+        encryptor: &FileEncryptor,
+    ) -> ParquetResult<ThriftFileMetaData> {
+        for (ordinal, group) in metadata.row_groups.iter_mut().enumerate() {
+            for (column, (column_chunk, descriptor)) in group
+                .columns
+                .iter_mut()
+                .zip(self.schema.columns())
+                .enumerate()
+            {
+                let Some(column_encryptor) =
+                    encryptor.column_encryptor(&descriptor.path_in_schema, ordinal, column)
+                else {
+                    continue;
+                };
+                if !column_encryptor.has_column_key() {
+                    continue;
+                }
+                let Some(column_metadata) = column_chunk.meta_data.take() else {
+                    continue;
+                };
+                let mut plaintext = vec![];
+                column_metadata
+                    .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut plaintext))?;
+                column_chunk.encrypted_column_metadata =
+                    Some(column_encryptor.encrypt(ModuleType::ColumnMetaData, None, &plaintext)?);
+            }
+        }
+        Ok(metadata)
+    }
+
     /// Returns the underlying writer.
     pub fn into_inner(self) -> W {
         self.writer
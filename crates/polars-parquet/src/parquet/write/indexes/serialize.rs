@@ -16,10 +16,7 @@ pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIn
             if let Some(stats) = &spec.statistics {
                 let stats = stats.serialize();
 
-                let null_count = stats
-                    .null_count
-                    .ok_or_else(|| ParquetError::oos("null count of a page is required"))?;
-                null_counts.push(null_count);
+                null_counts.push(stats.null_count);
 
                 if let Some(min_value) = stats.min_value {
                     min_values.push(min_value);
@@ -47,7 +44,8 @@ pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIn
         min_values,
         max_values,
         boundary_order: BoundaryOrder::UNORDERED,
-        null_counts: Some(null_counts),
+        // The null counts are optional, but must be given for either all pages or none.
+        null_counts: null_counts.into_iter().collect(),
     })
 }
 
@@ -8,6 +8,7 @@ use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 use parquet_format_safe::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -93,6 +94,63 @@ pub fn write_page<W: Write>(
     })
 }
 
+/// Writes a page of an encrypted column chunk, of which the header and the data are encrypted
+/// as separate modules. `page_ordinal` is the ordinal of the page among the data pages of the
+/// column chunk.
+pub(crate) fn write_encrypted_page<W: Write>(
+    writer: &mut W,
+    offset: u64,
+    compressed_page: &CompressedPage,
+    encryptor: &ColumnEncryptor,
+    page_ordinal: usize,
+) -> ParquetResult<PageWriteSpec> {
+    let num_values = compressed_page.num_values();
+    let selected_rows = compressed_page.selected_rows();
+
+    let (mut header, buffer, module_type, header_module_type, page) = match &compressed_page {
+        CompressedPage::Data(compressed_page) => (
+            assemble_data_page_header(compressed_page)?,
+            &compressed_page.buffer,
+            ModuleType::DataPage,
+            ModuleType::DataPageHeader,
+            Some(page_ordinal),
+        ),
+        CompressedPage::Dict(compressed_page) => (
+            assemble_dict_page_header(compressed_page)?,
+            &compressed_page.buffer,
+            ModuleType::DictionaryPage,
+            ModuleType::DictionaryPageHeader,
+            None,
+        ),
+    };
+
+    // SPEC: the compressed page size of an encrypted page is the size of its module
+    let data = encryptor.encrypt(module_type, page, buffer)?;
+    (_, header.compressed_page_size) = maybe_bytes(0, data.len())?;
+
+    let mut header_bytes = vec![];
+    write_page_header(&mut header_bytes, &header)?;
+    let header_module = encryptor.encrypt(header_module_type, page, &header_bytes)?;
+    writer.write_all(&header_module)?;
+    writer.write_all(&data)?;
+
+    let statistics = match &compressed_page {
+        CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
+        CompressedPage::Dict(_) => None,
+    };
+
+    Ok(PageWriteSpec {
+        header,
+        header_size: header_module.len() as u64,
+        offset,
+        bytes_written: (header_module.len() + data.len()) as u64,
+        compression: compressed_page.compression(),
+        statistics,
+        num_rows: selected_rows.map(|x| x.last().unwrap().length),
+        num_values,
+    })
+}
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
@@ -15,6 +15,8 @@ use super::*;
 use crate::executors::operators::PlaceHolder;
 use crate::executors::sinks::joins::generic_probe_inner_left::GenericJoinProbe;
 use crate::executors::sinks::joins::generic_probe_outer::GenericFullOuterJoinProbe;
+#[cfg(feature = "semi_anti_join")]
+use crate::executors::sinks::joins::generic_probe_semi_anti::GenericSemiAntiJoinProbe;
 use crate::executors::sinks::utils::{hash_rows, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
@@ -125,9 +127,15 @@ pub(super) fn compare_fn(
 
 impl<K: ExtraPayload> GenericBuild<K> {
     fn is_empty(&self) -> bool {
-        match self.chunks.len() {
-            0 => true,
-            1 => self.chunks[0].is_empty(),
+        // The chunks of semi and anti joins don't hold data, so we check the keys.
+        self.materialized_join_cols.is_empty()
+    }
+
+    /// Semi and anti joins only need the keys of the build table.
+    fn keys_only(&self) -> bool {
+        match self.join_args.how {
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => true,
             _ => false,
         }
     }
@@ -167,7 +175,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         // end up with empty chunks
         // But we always want one empty chunk if all is empty as we need
         // to finish the join
-        if self.chunks.len() == 1 && self.chunks[0].is_empty() {
+        if self.chunks.len() == 1 && self.is_empty() {
             self.chunks.pop().unwrap();
         }
         if chunk.is_empty() {
@@ -182,6 +190,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         self.hashes = hashes;
 
         let current_chunk_offset = self.chunks.len() as ChunkIdx;
+        let keys_only = self.keys_only();
 
         // row offset in the chunk belonging to the hash
         let mut current_df_idx = 0 as IdxSize;
@@ -197,7 +206,9 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     entry.insert(key, (unitvec![payload], Default::default()));
                 },
                 RawEntryMut::Occupied(mut entry) => {
-                    entry.get_mut().0.push(payload);
+                    if !keys_only {
+                        entry.get_mut().0.push(payload);
+                    }
                 },
             };
 
@@ -208,7 +219,11 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         self.hashes.clear();
         self.join_columns.clear();
 
-        self.chunks.push(chunk);
+        if keys_only {
+            self.chunks.push(chunk.with_data(DataFrame::empty()));
+        } else {
+            self.chunks.push(chunk);
+        }
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -226,6 +241,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         }
 
         let chunks_offset = self.chunks.len() as IdxSize;
+        let keys_only = self.keys_only();
         self.chunks.extend_from_slice(&other.chunks);
         self.materialized_join_cols
             .extend_from_slice(&other.materialized_join_cols);
@@ -264,6 +280,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                             }
                             entry.insert(key, (payload, Default::default()));
                         },
+                        RawEntryMut::Occupied(_) if keys_only => {},
                         RawEntryMut::Occupied(mut entry) => {
                             let iter = val.iter().map(|chunk_id| {
                                 let (chunk_idx, val_idx) = chunk_id.extract();
@@ -319,7 +336,9 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         hashes.clear();
 
         match self.join_args.how {
-            JoinType::Inner | JoinType::Left => {
+            JoinType::Inner | JoinType::Left | JoinType::Right => {
+                // A right join is a left join with the left table as build table.
+                let swapped_or_left = self.swapped || matches!(self.join_args.how, JoinType::Right);
                 let probe_operator = GenericJoinProbe::new(
                     left_df,
                     materialized_join_cols,
@@ -328,7 +347,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     hash_tables,
                     join_columns_left,
                     join_columns_right,
-                    self.swapped,
+                    swapped_or_left,
                     hashes,
                     context,
                     self.join_args.clone(),
@@ -356,7 +375,20 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
             },
-
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => {
+                let probe_operator = GenericSemiAntiJoinProbe::new(
+                    materialized_join_cols,
+                    hb,
+                    hash_tables,
+                    join_columns_right,
+                    hashes,
+                    matches!(self.join_args.how, JoinType::Anti),
+                    self.join_nulls,
+                );
+                self.placeholder.replace(Box::new(probe_operator));
+                Ok(FinalizedSink::Operator)
+            },
             _ => unimplemented!(),
         }
     }
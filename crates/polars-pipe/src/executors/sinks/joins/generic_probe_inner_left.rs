@@ -211,7 +211,12 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         };
         let right_df = unsafe { right_df._take_opt_chunked_unchecked_seq(&self.join_tuples_a) };
 
-        let out = self.finish_join(left_df, right_df)?;
+        // A right join streams the right table, whose columns come last.
+        let out = if matches!(self.args.how, JoinType::Right) {
+            self.finish_join(right_df, left_df)?
+        } else {
+            self.finish_join(left_df, right_df)?
+        };
 
         // Clear memory.
         self.row_values.clear();
@@ -312,7 +317,7 @@ impl<K: ExtraPayload> Operator for GenericJoinProbe<K> {
     ) -> PolarsResult<OperatorResult> {
         match self.args.how {
             JoinType::Inner => self.execute_inner(context, chunk),
-            JoinType::Left => self.execute_left(context, chunk),
+            JoinType::Left | JoinType::Right => self.execute_left(context, chunk),
             _ => unreachable!(),
         }
     }
@@ -0,0 +1,113 @@
+use arrow::array::{Array, BinaryArray};
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+
+use crate::executors::sinks::joins::generic_build::*;
+use crate::executors::sinks::joins::row_values::RowValues;
+use crate::executors::sinks::joins::{ExtraPayload, PartitionedMap};
+use crate::executors::sinks::utils::hash_rows;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Probe of semi and anti joins. The right table is the build table, only its
+/// keys are kept. The left table is streamed through and filtered on whether
+/// its keys are found, which maintains the order of the left table.
+#[derive(Clone)]
+pub struct GenericSemiAntiJoinProbe<K: ExtraPayload> {
+    /// The keys of the build table, one row encoded array per chunk.
+    materialized_join_cols: Arc<[BinaryArray<i64>]>,
+    hb: RandomState,
+    /// partitioned tables that will be used for probing
+    /// stores the key and the chunk_idx, df_idx of the right table
+    hash_tables: Arc<PartitionedMap<K>>,
+    /// Amortize allocations
+    hashes: Vec<u64>,
+    mask: Vec<bool>,
+    is_anti: bool,
+    join_nulls: bool,
+    row_values: RowValues,
+}
+
+impl<K: ExtraPayload> GenericSemiAntiJoinProbe<K> {
+    pub(super) fn new(
+        materialized_join_cols: Arc<[BinaryArray<i64>]>,
+        hb: RandomState,
+        hash_tables: Arc<PartitionedMap<K>>,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        // Re-use the hashes allocation of the build side.
+        amortized_hashes: Vec<u64>,
+        is_anti: bool,
+        join_nulls: bool,
+    ) -> Self {
+        GenericSemiAntiJoinProbe {
+            materialized_join_cols,
+            hb,
+            hash_tables,
+            hashes: amortized_hashes,
+            mask: vec![],
+            is_anti,
+            join_nulls,
+            // The probe table is the output, so the join columns are kept.
+            row_values: RowValues::new(join_columns_right, false),
+        }
+    }
+
+    fn contains(&self, h: u64, row: &[u8]) -> bool {
+        self.hash_tables
+            .raw_entry(h)
+            .from_hash(h, |key| {
+                compare_fn(key, h, &self.materialized_join_cols, row)
+            })
+            .is_some()
+    }
+}
+
+impl<K: ExtraPayload> Operator for GenericSemiAntiJoinProbe<K> {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let mut hashes = std::mem::take(&mut self.hashes);
+        let rows = self
+            .row_values
+            .get_values(context, chunk, self.join_nulls)?;
+        hash_rows(&rows, &mut hashes, &self.hb);
+
+        let mut mask = std::mem::take(&mut self.mask);
+        if self.join_nulls || rows.null_count() == 0 {
+            mask.extend(
+                hashes
+                    .iter()
+                    .zip(rows.values_iter())
+                    .map(|(h, row)| self.contains(*h, row) != self.is_anti),
+            );
+        } else {
+            // Null keys never match.
+            mask.extend(
+                hashes.iter().zip(rows.iter()).map(|(h, row)| {
+                    row.map_or(false, |row| self.contains(*h, row)) != self.is_anti
+                }),
+            );
+        }
+        self.hashes = hashes;
+
+        let out = chunk.data.filter(&BooleanChunked::from_slice("", &mask))?;
+
+        // Clear memory.
+        mask.clear();
+        self.mask = mask;
+        self.row_values.clear();
+        self.hashes.clear();
+
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        let new = self.clone();
+        Box::new(new)
+    }
+    fn fmt(&self) -> &str {
+        "generic_semi_anti_join_probe"
+    }
+}
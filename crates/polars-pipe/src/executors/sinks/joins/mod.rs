@@ -3,6 +3,8 @@ mod cross;
 mod generic_build;
 mod generic_probe_inner_left;
 mod generic_probe_outer;
+#[cfg(feature = "semi_anti_join")]
+mod generic_probe_semi_anti;
 mod row_values;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
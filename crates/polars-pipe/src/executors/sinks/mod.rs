@@ -17,7 +17,8 @@ pub(crate) use ordered::*;
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro"
 ))]
 pub(crate) use output::*;
 pub(crate) use reproject::*;
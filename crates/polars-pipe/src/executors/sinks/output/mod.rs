@@ -1,10 +1,13 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(any(
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro"
 ))]
 mod file_sink;
 #[cfg(feature = "ipc")]
@@ -14,6 +17,8 @@ mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
 
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
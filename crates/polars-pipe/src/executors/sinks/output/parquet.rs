@@ -5,14 +5,14 @@ use std::thread::JoinHandle;
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
 use polars_io::parquet::write::{
-    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
+    BatchedWriter, EncodedRowGroup, ParquetWriteOptions, ParquetWriter,
 };
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
+type RowGroups = Vec<EncodedRowGroup>;
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
@@ -66,6 +66,9 @@ impl ParquetSink {
             .with_data_page_size(options.data_page_size)
             .with_statistics(options.statistics)
             .with_row_group_size(options.row_group_size)
+            .with_bloom_filter_columns(options.bloom_filter_columns)
+            .with_column_options(options.column_options)
+            .with_encryption(options.encryption)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -157,6 +160,9 @@ impl ParquetCloudSink {
             .with_data_page_size(parquet_options.data_page_size)
             .with_statistics(parquet_options.statistics)
             .with_row_group_size(parquet_options.row_group_size)
+            .with_bloom_filter_columns(parquet_options.bloom_filter_columns)
+            .with_column_options(parquet_options.column_options)
+            .with_encryption(parquet_options.encryption)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
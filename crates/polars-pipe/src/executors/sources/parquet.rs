@@ -9,7 +9,9 @@ use polars_core::prelude::Series;
 use polars_core::POOL;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
-use polars_io::parquet::read::{BatchedParquetReader, ParquetOptions, ParquetReader};
+use polars_io::parquet::read::{
+    BatchedParquetReader, FileDecryptionProperties, ParquetOptions, ParquetReader,
+};
 use polars_io::path_utils::is_cloud_url;
 use polars_io::pl_async::get_runtime;
 use polars_io::predicates::PhysicalIoExpr;
@@ -40,6 +42,7 @@ pub struct ParquetSource {
     #[allow(dead_code)]
     cloud_options: Option<CloudOptions>,
     metadata: Option<FileMetaDataRef>,
+    decryption: Option<FileDecryptionProperties>,
     file_info: FileInfo,
     hive_parts: Option<Arc<[HivePartitions]>>,
     verbose: bool,
@@ -126,6 +129,7 @@ impl ParquetSource {
         let batched_reader = {
             let file = std::fs::File::open(path).unwrap();
             let mut reader = ParquetReader::new(file)
+                .with_decryption(self.decryption.clone())
                 .with_projection(projection)
                 .check_schema(
                     self.file_info
@@ -188,6 +192,7 @@ impl ParquetSource {
             let uri = path.to_string_lossy();
             ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref(), metadata)
                 .await?
+                .with_decryption(self.decryption.clone())
                 .with_row_index(file_options.row_index)
                 .with_projection(projection)
                 .check_schema(
@@ -221,6 +226,7 @@ impl ParquetSource {
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
         metadata: Option<FileMetaDataRef>,
+        decryption: Option<FileDecryptionProperties>,
         file_options: FileScanOptions,
         file_info: FileInfo,
         hive_parts: Option<Arc<[HivePartitions]>>,
@@ -248,6 +254,7 @@ impl ParquetSource {
             paths,
             cloud_options,
             metadata,
+            decryption,
             file_info,
             hive_parts,
             verbose,
@@ -44,7 +44,12 @@ pub(crate) fn chunks_to_df_unchecked(chunks: Vec<DataChunk>) -> DataFrame {
 ///
 /// Changing the `DataFrame` into contiguous chunks is the caller's
 /// responsibility.
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
 #[derive(Clone)]
 pub(crate) struct StreamingVstacker {
     current_dataframe: Option<DataFrame>,
@@ -52,7 +57,12 @@ pub(crate) struct StreamingVstacker {
     output_chunk_size: usize,
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
 impl StreamingVstacker {
     /// Create a new instance.
     pub fn new(output_chunk_size: usize) -> Self {
@@ -103,7 +113,12 @@ impl StreamingVstacker {
     }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
 impl Default for StreamingVstacker {
     /// 4 MB was chosen based on some empirical experiments that showed it to
     /// be decently faster than lower or higher values, and it's small enough
@@ -114,7 +129,12 @@ impl Default for StreamingVstacker {
 }
 
 #[cfg(test)]
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
 mod test {
     use super::*;
 
@@ -115,6 +115,7 @@ where
                     options: parquet_options,
                     cloud_options,
                     metadata,
+                    decryption,
                 } => {
                     let predicate = predicate
                         .as_ref()
@@ -143,6 +144,7 @@ where
                         parquet_options,
                         cloud_options,
                         metadata,
+                        decryption,
                         file_options,
                         file_info,
                         hive_parts,
@@ -184,7 +186,11 @@ where
                     match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(ParquetSink::new(
+                                path,
+                                options.clone(),
+                                input_schema.as_ref(),
+                            )?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "ipc")]
@@ -202,6 +208,11 @@ where
                             Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
+                        #[cfg(feature = "avro")]
+                        FileType::Avro(options) => {
+                            Box::new(AvroSink::new(path, options.clone(), input_schema.as_ref())?)
+                                as Box<dyn SinkTrait>
+                        },
                         #[allow(unreachable_patterns)]
                         _ => unreachable!(),
                     }
@@ -220,7 +231,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -284,7 +295,7 @@ where
                     };
 
                     match jt {
-                        JoinType::Inner | JoinType::Left => {
+                        JoinType::Inner | JoinType::Left | JoinType::Right => {
                             let (join_columns_left, join_columns_right) = swap_eval();
 
                             Box::new(GenericBuild::<()>::new(
@@ -327,6 +338,23 @@ where
                                 placeholder,
                             )) as Box<dyn SinkTrait>
                         },
+                        #[cfg(feature = "semi_anti_join")]
+                        JoinType::Semi | JoinType::Anti => {
+                            let (join_columns_left, join_columns_right) = swap_eval();
+
+                            Box::new(GenericBuild::<()>::new(
+                                Arc::from(options.args.suffix()),
+                                options.args.clone(),
+                                swapped,
+                                join_columns_left,
+                                join_columns_right,
+                                options.args.join_nulls,
+                                node,
+                                vec![].into(),
+                                vec![].into(),
+                                placeholder,
+                            )) as Box<dyn SinkTrait>
+                        },
                         _ => unimplemented!(),
                     }
                 },
@@ -746,10 +774,19 @@ where
     ))
 }
 
+/// Whether the right table is used as build table.
+///
+/// Left, semi and anti joins always build the right table and right joins the
+/// left table, so that the order of the streamed table is maintained.
 pub fn swap_join_order(options: &JoinOptions) -> bool {
-    matches!(options.args.how, JoinType::Left)
-        || match (options.rows_left, options.rows_right) {
+    match options.args.how {
+        JoinType::Left => true,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => true,
+        JoinType::Right => false,
+        _ => match (options.rows_left, options.rows_right) {
             ((Some(left), _), (Some(right), _)) => left > right,
             ((_, left), (_, right)) => left > right,
-        }
+        },
+    }
 }
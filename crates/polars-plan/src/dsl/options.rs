@@ -44,6 +44,10 @@ pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
     pub args: JoinArgs,
+    /// Whether the cross join was created by `join_where`. Only then the filters
+    /// on top of it may be turned into an equi join or an inequality join, which
+    /// do not keep the row order of the cross join.
+    pub join_where: bool,
     /// Proxy of the number of rows in both sides of the joins
     /// Holds `(Option<known_size>, estimated_size)`
     pub rows_left: (Option<usize>, usize),
@@ -56,6 +60,7 @@ impl Default for JoinOptions {
             allow_parallel: true,
             force_parallel: false,
             args: JoinArgs::new(JoinType::Left),
+            join_where: false,
             rows_left: (None, usize::MAX),
             rows_right: (None, usize::MAX),
         }
@@ -1,18 +1,33 @@
-#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "parquet",
+    feature = "avro"
+))]
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex, RwLock};
 
 use polars_core::prelude::*;
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{FileDecryptionProperties, ParquetOptions};
 use polars_io::HiveOptions;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "avro"
+))]
 use polars_io::RowIndex;
 
 use crate::constants::UNLIMITED_CACHE;
@@ -91,6 +106,7 @@ impl DslBuilder {
         hive_options: HiveOptions,
         glob: bool,
         include_file_paths: Option<Arc<str>>,
+        decryption: Option<FileDecryptionProperties>,
     ) -> PolarsResult<Self> {
         let paths = init_paths(paths);
 
@@ -119,6 +135,7 @@ impl DslBuilder {
                 },
                 cloud_options,
                 metadata: None,
+                decryption,
             },
         }
         .into())
@@ -164,6 +181,41 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn scan_avro<P: Into<Arc<[std::path::PathBuf]>>>(
+        paths: P,
+        n_rows: Option<usize>,
+        cache: bool,
+        row_index: Option<RowIndex>,
+        rechunk: bool,
+        cloud_options: Option<CloudOptions>,
+        hive_options: HiveOptions,
+        include_file_paths: Option<Arc<str>>,
+    ) -> PolarsResult<Self> {
+        let paths = init_paths(paths);
+
+        Ok(DslPlan::Scan {
+            paths,
+            file_info: Arc::new(RwLock::new(None)),
+            hive_parts: None,
+            file_options: FileScanOptions {
+                with_columns: None,
+                cache,
+                slice: n_rows.map(|x| (0, x)),
+                rechunk,
+                row_index,
+                file_counter: Default::default(),
+                hive_options,
+                glob: true,
+                include_file_paths,
+            },
+            predicate: None,
+            scan_type: FileScan::Avro { cloud_options },
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv<P: Into<Arc<[std::path::PathBuf]>>>(
@@ -292,12 +344,14 @@ impl DslBuilder {
         .into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn group_by<E: AsRef<[Expr]>>(
         self,
         keys: Vec<Expr>,
         aggs: E,
         apply: Option<(Arc<dyn DataFrameUdf>, SchemaRef)>,
         maintain_order: bool,
+        grouping_sets: Option<GroupingSets>,
         #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
         #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
     ) -> Self {
@@ -307,6 +361,7 @@ impl DslBuilder {
             dynamic: dynamic_options,
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
+            grouping_sets,
             slice: None,
         };
 
@@ -464,7 +519,12 @@ impl DslBuilder {
 }
 
 /// Initialize paths as non-expanded.
-#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "parquet",
+    feature = "avro"
+))]
 fn init_paths<P>(paths: P) -> Arc<Mutex<(Arc<[PathBuf]>, bool)>>
 where
     P: Into<Arc<[std::path::PathBuf]>>,
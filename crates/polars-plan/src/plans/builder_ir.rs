@@ -245,6 +245,10 @@ impl<'a> IRBuilder<'a> {
         );
         schema.merge(agg_schema);
 
+        if let Some(grouping_sets) = options.grouping_sets.as_ref() {
+            schema.with_column(grouping_sets.id_name.as_str().into(), DataType::UInt32);
+        }
+
         let lp = IR::GroupBy {
             input: self.root,
             keys,
@@ -4,11 +4,11 @@ use arrow::datatypes::ArrowSchemaRef;
 use either::Either;
 use expr_expansion::{is_regex_projection, rewrite_projections};
 use hive::{hive_partitions_from_paths, HivePartitions};
-#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "avro"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(any(feature = "csv", feature = "json"))]
 use polars_io::path_utils::expand_paths;
-#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "avro"))]
 use polars_io::path_utils::{expand_paths_hive, expanded_from_single_directory};
 
 use super::stack_opt::ConversionOptimizer;
@@ -123,11 +123,16 @@ pub fn to_alp_impl(
                     FileScan::Parquet {
                         cloud_options,
                         metadata,
+                        decryption,
                         ..
                     } => {
-                        let (file_info, md) =
-                            scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                        let (file_info, md) = scans::parquet_file_info(
+                            &paths,
+                            &file_options,
+                            cloud_options.as_ref(),
+                            decryption.as_ref(),
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -162,6 +167,11 @@ pub fn to_alp_impl(
                         cloud_options.as_ref(),
                     )
                     .map_err(|e| e.context(failed_here!(ndjson scan)))?,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { cloud_options } => {
+                        scans::avro_file_info(&paths, &file_options, cloud_options.as_ref())
+                            .map_err(|e| e.context(failed_here!(avro scan)))?
+                    },
                     // FileInfo should be set.
                     FileScan::Anonymous { .. } => unreachable!(),
                 }
@@ -202,6 +212,8 @@ pub fn to_alp_impl(
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "json")]
                     FileScan::NDJson { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => true,
                     FileScan::Anonymous { .. } => false,
                 });
 
@@ -474,6 +486,10 @@ pub fn to_alp_impl(
         } => {
             let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(group_by)))?;
+            polars_ensure!(
+                apply.is_none() || options.grouping_sets.is_none(),
+                InvalidOperation: "cannot apply a function over the groups of grouping sets"
+            );
 
             let (keys, aggs, schema) =
                 resolve_group_by(input, keys, aggs, &options, lp_arena, expr_arena)
@@ -540,6 +556,13 @@ pub fn to_alp_impl(
                             right_on.len()
                         )
                 );
+                #[cfg(feature = "interval_join")]
+                if let JoinType::Interval(_) = options.args.how {
+                    polars_ensure!(
+                        left_on.len() >= 2,
+                        InvalidOperation: "an 'interval' join expects the start and the end of the intervals as its first two join keys"
+                    );
+                }
             }
 
             let input_left = to_alp_impl(owned(input_left), expr_arena, lp_arena, convert)
@@ -820,6 +843,10 @@ fn expand_scan_paths(
             FileScan::NDJson { cloud_options, .. } => {
                 expand_paths(&lock.0, file_options.glob, cloud_options.as_ref())?
             },
+            #[cfg(feature = "avro")]
+            FileScan::Avro { cloud_options } => {
+                expand_scan_paths_with_hive_update(&lock.0, file_options, cloud_options)?
+            },
             FileScan::Anonymous { .. } => unreachable!(), // Invariant: Anonymous scans are already expanded.
         };
 
@@ -833,7 +860,7 @@ fn expand_scan_paths(
 }
 
 /// Expand scan paths and update the Hive partition information of `file_options`.
-#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "avro"))]
 fn expand_scan_paths_with_hive_update(
     paths: &[PathBuf],
     file_options: &mut FileScanOptions,
@@ -1003,6 +1030,34 @@ fn resolve_group_by(
             polars_ensure!(names.insert(name.clone()), duplicate = name)
         }
     }
+
+    // Add the grouping id column
+    if let Some(grouping_sets) = _options.grouping_sets.as_ref() {
+        polars_ensure!(
+            keys.len() <= GroupingSets::MAX_KEYS,
+            InvalidOperation: "grouping sets support at most {} keys, got {}",
+            GroupingSets::MAX_KEYS, keys.len()
+        );
+        polars_ensure!(
+            !grouping_sets.sets.is_empty(),
+            InvalidOperation: "expected at least one grouping set"
+        );
+        polars_ensure!(
+            grouping_sets.sets.len() <= GroupingSets::MAX_SETS,
+            InvalidOperation: "at most {} grouping sets are supported, got {}",
+            GroupingSets::MAX_SETS, grouping_sets.sets.len()
+        );
+        for set in &grouping_sets.sets {
+            polars_ensure!(
+                set.iter().all(|i| *i < keys.len()),
+                InvalidOperation: "grouping set {:?} refers to a key that doesn't exist, there are {} keys",
+                set, keys.len()
+            );
+        }
+        let name = grouping_sets.id_name.as_str();
+        polars_ensure!(!schema.contains(name), duplicate = name);
+        schema.with_column(name.into(), DataType::UInt32);
+    }
     let aggs = to_expr_irs(aggs, expr_arena);
     let keys = keys.convert(|e| to_expr_ir(e.clone(), expr_arena));
 
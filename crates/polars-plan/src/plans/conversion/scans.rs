@@ -16,7 +16,7 @@ fn get_first_path(paths: &[PathBuf]) -> PolarsResult<&PathBuf> {
         .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 path"))
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.as_ref().into(), IDX_DTYPE);
@@ -41,6 +41,7 @@ pub(super) fn parquet_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<(FileInfo, Option<FileMetaDataRef>)> {
     let path = get_first_path(paths)?;
 
@@ -52,7 +53,9 @@ pub(super) fn parquet_file_info(
         {
             let uri = path.to_string_lossy();
             get_runtime().block_on(async {
-                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None).await?;
+                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None)
+                    .await?
+                    .with_decryption(decryption.cloned());
                 let reader_schema = reader.schema().await?;
                 let num_rows = reader.num_rows().await?;
                 let metadata = reader.get_metadata().await?.clone();
@@ -64,7 +67,7 @@ pub(super) fn parquet_file_info(
         }
     } else {
         let file = polars_utils::open_file(path)?;
-        let mut reader = ParquetReader::new(file);
+        let mut reader = ParquetReader::new(file).with_decryption(decryption.cloned());
         let reader_schema = reader.schema()?;
         let schema =
             prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref());
@@ -125,6 +128,53 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    paths: &[PathBuf],
+    file_options: &FileScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+
+    let path = get_first_path(paths)?;
+    let run_async = is_cloud_url(path) || config::force_async();
+
+    let file = if run_async {
+        #[cfg(feature = "cloud")]
+        {
+            polars_io::file_cache::init_entries_from_uri_list(
+                paths
+                    .iter()
+                    .map(|path| Arc::from(path.to_str().unwrap()))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                cloud_options,
+            )?[0]
+                .try_open_check_latest()?
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            panic!("required feature `cloud` is not enabled")
+        }
+    } else {
+        polars_utils::open_file(path)?
+    };
+
+    let reader_schema = polars_io::avro::AvroReader::new(std::io::BufReader::new(file))
+        .arrow_schema()
+        .map(Arc::new)?;
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            reader_schema.as_ref().into(),
+            file_options.row_index.as_ref(),
+        ),
+        Some(Either::Left(reader_schema)),
+        (None, 0),
+    );
+
+    Ok(file_info)
+}
+
 #[cfg(feature = "csv")]
 pub(super) fn csv_file_info(
     paths: &[PathBuf],
@@ -7,7 +7,7 @@ use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetaDataRef;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{FileDecryptionProperties, ParquetOptions};
 
 use super::*;
 
@@ -25,6 +25,8 @@ pub enum FileScan {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<FileMetaDataRef>,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        decryption: Option<FileDecryptionProperties>,
     },
     #[cfg(feature = "ipc")]
     Ipc {
@@ -38,6 +40,10 @@ pub enum FileScan {
         options: NDJsonReadOptions,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    #[cfg(feature = "avro")]
+    Avro {
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    },
     #[cfg_attr(feature = "serde", serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
@@ -64,14 +70,16 @@ impl PartialEq for FileScan {
                 FileScan::Parquet {
                     options: opt_l,
                     cloud_options: c_l,
+                    decryption: d_l,
                     ..
                 },
                 FileScan::Parquet {
                     options: opt_r,
                     cloud_options: c_r,
+                    decryption: d_r,
                     ..
                 },
-            ) => opt_l == opt_r && c_l == c_r,
+            ) => opt_l == opt_r && c_l == c_r && d_l == d_r,
             #[cfg(feature = "ipc")]
             (
                 FileScan::Ipc {
@@ -96,6 +104,8 @@ impl PartialEq for FileScan {
                     cloud_options: c_r,
                 },
             ) => l == r && c_l == c_r,
+            #[cfg(feature = "avro")]
+            (FileScan::Avro { cloud_options: l }, FileScan::Avro { cloud_options: r }) => l == r,
             _ => false,
         }
     }
@@ -120,9 +130,11 @@ impl Hash for FileScan {
                 options,
                 cloud_options,
                 metadata: _,
+                decryption,
             } => {
                 options.hash(state);
                 cloud_options.hash(state);
+                decryption.hash(state);
             },
             #[cfg(feature = "ipc")]
             FileScan::Ipc {
@@ -141,6 +153,8 @@ impl Hash for FileScan {
                 options.hash(state);
                 cloud_options.hash(state)
             },
+            #[cfg(feature = "avro")]
+            FileScan::Avro { cloud_options } => cloud_options.hash(state),
             FileScan::Anonymous { options, .. } => options.hash(state),
         }
     }
@@ -169,6 +183,8 @@ impl FileScan {
             Self::Ipc { .. } => _file_options.row_index.is_some(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => _file_options.row_index.is_some(),
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => true,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -184,6 +200,8 @@ impl FileScan {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -7,11 +7,13 @@ use polars_io::csv::read::count_rows as count_rows_csv;
 #[cfg(all(feature = "parquet", feature = "cloud"))]
 use polars_io::parquet::read::ParquetAsyncReader;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetReader;
+use polars_io::parquet::read::{FileDecryptionProperties, ParquetReader};
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
+use polars_io::path_utils::is_cloud_url;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
 #[cfg(any(feature = "parquet", feature = "ipc"))]
-use polars_io::{path_utils::is_cloud_url, SerReader};
+use polars_io::SerReader;
 
 use super::*;
 
@@ -21,7 +23,8 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     )))]
     {
         unreachable!()
@@ -31,7 +34,8 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -57,9 +61,11 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                 n_rows
             },
             #[cfg(feature = "parquet")]
-            FileScan::Parquet { cloud_options, .. } => {
-                count_rows_parquet(paths, cloud_options.as_ref())
-            },
+            FileScan::Parquet {
+                cloud_options,
+                decryption,
+                ..
+            } => count_rows_parquet(paths, cloud_options.as_ref(), decryption.as_ref()),
             #[cfg(feature = "ipc")]
             FileScan::Ipc {
                 options,
@@ -76,6 +82,8 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                 options,
                 cloud_options,
             } => count_rows_ndjson(paths, cloud_options.as_ref()),
+            #[cfg(feature = "avro")]
+            FileScan::Avro { cloud_options } => count_rows_avro(paths, cloud_options.as_ref()),
             FileScan::Anonymous { .. } => {
                 unreachable!()
             },
@@ -91,6 +99,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
 pub(super) fn count_rows_parquet(
     paths: &Arc<[PathBuf]>,
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<usize> {
     if paths.is_empty() {
         return Ok(0);
@@ -103,14 +112,14 @@ pub(super) fn count_rows_parquet(
 
         #[cfg(feature = "cloud")]
         {
-            get_runtime().block_on(count_rows_cloud_parquet(paths, cloud_options))
+            get_runtime().block_on(count_rows_cloud_parquet(paths, cloud_options, decryption))
         }
     } else {
         paths
             .iter()
             .map(|path| {
                 let file = polars_utils::open_file(path)?;
-                let mut reader = ParquetReader::new(file);
+                let mut reader = ParquetReader::new(file).with_decryption(decryption.cloned());
                 reader.num_rows()
             })
             .sum::<PolarsResult<usize>>()
@@ -121,11 +130,14 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     paths: &Arc<[PathBuf]>,
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<usize> {
     let collection = paths.iter().map(|path| {
         with_concurrency_budget(1, || async {
             let mut reader =
-                ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, None).await?;
+                ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, None)
+                    .await?
+                    .with_decryption(decryption.cloned());
             reader.num_rows().await
         })
     });
@@ -238,3 +250,56 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    paths: &Arc<[PathBuf]>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    let run_async = !paths.is_empty() && is_cloud_url(&paths[0]) || config::force_async();
+
+    let cache_entries = {
+        #[cfg(feature = "cloud")]
+        {
+            if run_async {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    paths
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            } else {
+                None
+            }
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            if run_async {
+                panic!("required feature `cloud` is not enabled")
+            }
+        }
+    };
+
+    (0..paths.len())
+        .map(|i| {
+            let mut f = if run_async {
+                #[cfg(feature = "cloud")]
+                {
+                    cache_entries.as_ref().unwrap()[i].try_open_check_latest()?
+                }
+                #[cfg(not(feature = "cloud"))]
+                {
+                    panic!("required feature `cloud` is not enabled")
+                }
+            } else {
+                polars_utils::open_file(&paths[i])?
+            };
+
+            polars_io::avro::count_rows(&mut f)
+        })
+        .sum()
+}
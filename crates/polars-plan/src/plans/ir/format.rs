@@ -302,13 +302,21 @@ impl<'a> IRDisplay<'a> {
                 self.with_root(*input)._format(f, sub_indent)
             },
             GroupBy {
-                input, keys, aggs, ..
+                input,
+                keys,
+                aggs,
+                options,
+                ..
             } => {
                 let aggs = self.display_expr_slice(aggs);
                 let keys = self.display_expr_slice(keys);
 
                 write!(f, "{:indent$}AGGREGATE", "")?;
-                write!(f, "\n{:indent$}\t{aggs} BY {keys} FROM", "")?;
+                write!(f, "\n{:indent$}\t{aggs} BY {keys}", "")?;
+                if let Some(grouping_sets) = &options.grouping_sets {
+                    write!(f, " GROUPING SETS {:?}", grouping_sets.sets)?;
+                }
+                write!(f, " FROM")?;
                 self.with_root(*input)._format(f, sub_indent)
             },
             Join {
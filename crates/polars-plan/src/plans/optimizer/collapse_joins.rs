@@ -0,0 +1,266 @@
+//! Turn a cross join of `join_where` that is followed by a filter on predicates
+//! comparing the left and the right table into an equi join or an inequality
+//! join, so that the cartesian product is never materialized. Plain cross joins
+//! are left alone, as these joins neither keep the row order of the cross join
+//! nor are supported by every engine.
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+use super::join_utils::split_suffix;
+use crate::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// The table a column of the cross join output comes from.
+fn column_side(
+    name: &str,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<Side> {
+    if schema_left.contains(name) {
+        Some(Side::Left)
+    } else if schema_right.contains(name)
+        || name.ends_with(suffix) && schema_right.contains(split_suffix(name, suffix))
+    {
+        Some(Side::Right)
+    } else {
+        None
+    }
+}
+
+/// The table all columns of an expression come from, `None` if it uses both or none.
+fn expr_side(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<Side> {
+    let mut side = None;
+    for name in aexpr_to_leaf_names_iter(node, expr_arena) {
+        let column_side = column_side(&name, schema_left, schema_right, suffix)?;
+        if side
+            .replace(column_side)
+            .is_some_and(|side| side != column_side)
+        {
+            return None;
+        }
+    }
+    side
+}
+
+fn split_conjunction(node: Node, expr_arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(*left, expr_arena, out);
+            split_conjunction(*right, expr_arena, out);
+        },
+        _ => out.push(node),
+    }
+}
+
+/// A predicate of the form `left <op> right`, where `left` is evaluated on the
+/// left table and `right` on the right table.
+struct JoinPredicate {
+    /// The conjunct of the filter this predicate was created from.
+    node: Node,
+    left: Node,
+    right: Node,
+    op: Operator,
+}
+
+/// Rename the suffixed columns of an expression on the right table to their
+/// names in the right table.
+fn to_right_input(
+    mut node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Node {
+    let suffixed = aexpr_to_leaf_names_iter(node, expr_arena)
+        .filter(|name| !schema_right.contains(name))
+        .collect::<Vec<_>>();
+    for name in suffixed {
+        node =
+            rename_matching_aexpr_leaf_names(node, expr_arena, &name, split_suffix(&name, suffix));
+    }
+    node
+}
+
+fn to_join_predicate(
+    node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<JoinPredicate> {
+    let AExpr::BinaryExpr { left, op, right } = *expr_arena.get(node) else {
+        return None;
+    };
+    let swapped_op = match op {
+        Operator::Eq => Operator::Eq,
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        _ => return None,
+    };
+    // The join keys are evaluated on the input tables instead of the cartesian
+    // product, which is only the same for elementwise expressions.
+    if !is_streamable(left, expr_arena, Context::Default)
+        || !is_streamable(right, expr_arena, Context::Default)
+    {
+        return None;
+    }
+    let left_side = expr_side(left, expr_arena, schema_left, schema_right, suffix)?;
+    let right_side = expr_side(right, expr_arena, schema_left, schema_right, suffix)?;
+    let (left, right, op) = match (left_side, right_side) {
+        (Side::Left, Side::Right) => (left, right, op),
+        (Side::Right, Side::Left) => (right, left, swapped_op),
+        _ => return None,
+    };
+    let right = to_right_input(right, expr_arena, schema_right, suffix);
+
+    let dtype_left = expr_arena
+        .get(left)
+        .to_field(schema_left, Context::Default, expr_arena)
+        .ok()?
+        .dtype;
+    let dtype_right = expr_arena
+        .get(right)
+        .to_field(schema_right, Context::Default, expr_arena)
+        .ok()?
+        .dtype;
+    let supported = if op == Operator::Eq {
+        dtype_left == dtype_right
+    } else {
+        try_get_supertype(&dtype_left, &dtype_right).is_ok()
+    };
+    supported.then_some(JoinPredicate {
+        node,
+        left,
+        right,
+        op,
+    })
+}
+
+fn to_inequality_operator(op: Operator) -> InequalityOperator {
+    match op {
+        Operator::Lt => InequalityOperator::Lt,
+        Operator::LtEq => InequalityOperator::LtEq,
+        Operator::Gt => InequalityOperator::Gt,
+        Operator::GtEq => InequalityOperator::GtEq,
+        _ => unreachable!(),
+    }
+}
+
+pub(super) fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        let current_ir = lp_arena.get(current);
+        current_ir.copy_inputs(&mut ir_stack);
+        let IR::Filter { input, predicate } = current_ir else {
+            continue;
+        };
+        let (input, predicate) = (*input, predicate.node());
+        let IR::Join {
+            input_left,
+            input_right,
+            schema,
+            options,
+            ..
+        } = lp_arena.get(input)
+        else {
+            continue;
+        };
+        // A slice is applied before the filter.
+        if !matches!(options.args.how, JoinType::Cross)
+            || !options.join_where
+            || options.args.slice.is_some()
+        {
+            continue;
+        }
+        let (input_left, input_right) = (*input_left, *input_right);
+        let schema = schema.clone();
+        let mut options = options.as_ref().clone();
+        let suffix = options.args.suffix().to_string();
+        let schema_left = lp_arena.get(input_left).schema(lp_arena).into_owned();
+        let schema_right = lp_arena.get(input_right).schema(lp_arena).into_owned();
+
+        let mut conjuncts = vec![];
+        split_conjunction(predicate, expr_arena, &mut conjuncts);
+        let mut equalities = vec![];
+        let mut inequalities = vec![];
+        let mut remaining = vec![];
+        for node in conjuncts {
+            match to_join_predicate(node, expr_arena, &schema_left, &schema_right, &suffix) {
+                Some(p) if p.op == Operator::Eq => equalities.push(p),
+                Some(p) => inequalities.push(p),
+                None => remaining.push(node),
+            }
+        }
+
+        // Prefer a hash join on the equalities and filter on the inequalities
+        // afterwards, an inequality join handles at most two inequalities.
+        let join_predicates = if !equalities.is_empty() {
+            remaining.extend(inequalities.iter().map(|p| p.node));
+            options.args.how = JoinType::Inner;
+            // Keep the right columns like the cross join does.
+            options.args.coalesce = JoinCoalesce::KeepColumns;
+            equalities
+        } else if !inequalities.is_empty() {
+            remaining.extend(inequalities.iter().skip(2).map(|p| p.node));
+            inequalities.truncate(2);
+            options.args.how = JoinType::IEJoin(IEJoinOptions {
+                operator1: to_inequality_operator(inequalities[0].op),
+                operator2: inequalities.get(1).map(|p| to_inequality_operator(p.op)),
+            });
+            inequalities
+        } else {
+            continue;
+        };
+
+        let join = IR::Join {
+            input_left,
+            input_right,
+            schema,
+            left_on: join_predicates
+                .iter()
+                .map(|p| ExprIR::from_node(p.left, expr_arena))
+                .collect(),
+            right_on: join_predicates
+                .iter()
+                .map(|p| ExprIR::from_node(p.right, expr_arena))
+                .collect(),
+            options: Arc::new(options),
+        };
+        if remaining.is_empty() {
+            lp_arena.replace(current, join);
+        } else {
+            lp_arena.replace(input, join);
+            let predicate = remaining
+                .into_iter()
+                .reduce(|left, right| {
+                    expr_arena.add(AExpr::BinaryExpr {
+                        left,
+                        op: Operator::And,
+                        right,
+                    })
+                })
+                .unwrap();
+            let predicate = ExprIR::from_node(predicate, expr_arena);
+            lp_arena.replace(current, IR::Filter { input, predicate });
+        }
+    }
+}
@@ -7,6 +7,8 @@ mod delay_rechunk;
 
 mod cluster_with_columns;
 mod collapse_and_project;
+#[cfg(feature = "iejoin")]
+mod collapse_joins;
 mod collect_members;
 mod count_star;
 #[cfg(feature = "cse")]
@@ -156,6 +158,11 @@ pub fn optimize(
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
+
+        // Needs the predicates that combine both tables of a cross join to be
+        // right above the join, which predicate pushdown takes care of.
+        #[cfg(feature = "iejoin")]
+        collapse_joins::optimize(lp_top, lp_arena, expr_arena);
     }
 
     if cluster_with_columns {
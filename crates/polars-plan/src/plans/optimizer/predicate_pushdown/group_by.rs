@@ -22,8 +22,9 @@ pub(super) fn process_group_by(
     #[cfg(not(feature = "dynamic_group_by"))]
     let no_push = false;
 
-    // Don't pushdown predicates on these cases.
-    if apply.is_some() || no_push || options.slice.is_some() {
+    // Don't pushdown predicates on these cases. The keys of grouping sets are
+    // nulled out per set, so also a predicate on the keys can't be pushed down.
+    if apply.is_some() || no_push || options.slice.is_some() || options.grouping_sets.is_some() {
         let lp = GroupBy {
             input,
             keys,
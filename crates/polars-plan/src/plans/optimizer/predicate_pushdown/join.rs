@@ -71,7 +71,12 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     {
         match how {
             JoinType::Left => LeftRight(false, true),
-            JoinType::Full { .. } | JoinType::Cross | JoinType::AsOf(_) => LeftRight(true, true),
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(IntervalJoinOptions {
+                how: IntervalJoinType::Left,
+                ..
+            }) => LeftRight(false, true),
+            JoinType::Full { .. } | JoinType::AsOf(_) => LeftRight(true, true),
             _ => LeftRight(false, false),
         }
     }
@@ -79,7 +84,12 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     {
         match how {
             JoinType::Left => LeftRight(false, true),
-            JoinType::Full { .. } | JoinType::Cross => LeftRight(true, true),
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(IntervalJoinOptions {
+                how: IntervalJoinType::Left,
+                ..
+            }) => LeftRight(false, true),
+            JoinType::Full { .. } => LeftRight(true, true),
             _ => LeftRight(false, false),
         }
     }
@@ -235,6 +245,18 @@ pub(super) fn process_join(
                 local_predicates.push(predicate);
                 continue;
             },
+            #[cfg(feature = "interval_join")]
+            (
+                false,
+                true,
+                JoinType::Interval(IntervalJoinOptions {
+                    how: IntervalJoinType::Left,
+                    ..
+                }),
+            ) => {
+                local_predicates.push(predicate);
+                continue;
+            },
             // business as usual
             _ => {}
         }
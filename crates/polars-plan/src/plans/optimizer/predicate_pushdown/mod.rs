@@ -344,6 +344,8 @@ impl<'a> PredicatePushDown<'a> {
                     FileScan::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => {},
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
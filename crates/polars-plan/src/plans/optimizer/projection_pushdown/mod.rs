@@ -416,6 +416,8 @@ impl ProjectionPushDown {
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => true,
                 };
 
                 if do_optimization {
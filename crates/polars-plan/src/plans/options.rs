@@ -5,6 +5,8 @@ use std::path::PathBuf;
 use bitflags::bitflags;
 use polars_core::prelude::*;
 use polars_core::utils::SuperTypeOptions;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -19,7 +21,8 @@ use polars_time::{DynamicGroupOptions, RollingGroupOptions};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::plans::ExprIR;
+use crate::dsl::{col, Expr};
+use crate::plans::{typed_lit, ExprIR};
 #[cfg(feature = "python")]
 use crate::prelude::python_udf::PythonFunction;
 
@@ -65,10 +68,87 @@ pub struct GroupbyOptions {
     pub dynamic: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     pub rolling: Option<RollingGroupOptions>,
+    /// Group by every grouping set instead of by all keys at once.
+    pub grouping_sets: Option<GroupingSets>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
 
+/// The grouping sets of a group by, as in `GROUP BY GROUPING SETS (...)`.
+///
+/// The result holds the groups of every set after each other, the keys that
+/// are not part of a set are null. An extra column with the grouping id tells
+/// the sets apart: bit `i` of it is set if the `i`-th key is not part of the
+/// set of the row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupingSets {
+    /// Every set holds the indices of the keys it groups by.
+    pub sets: Vec<Vec<usize>>,
+    /// Name of the grouping id column.
+    pub id_name: String,
+}
+
+impl GroupingSets {
+    pub const MAX_KEYS: usize = 32;
+    /// The maximum number of grouping sets, as in PostgreSQL.
+    pub const MAX_SETS: usize = 4096;
+
+    pub fn new(sets: Vec<Vec<usize>>) -> Self {
+        Self {
+            sets,
+            id_name: "grouping_id".to_string(),
+        }
+    }
+
+    /// The sets of `ROLLUP`: all prefixes of the keys, from longest to shortest.
+    pub fn rollup(n_keys: usize) -> Self {
+        Self::new((0..=n_keys).rev().map(|n| (0..n).collect()).collect())
+    }
+
+    /// The sets of `CUBE`: all subsets of the keys.
+    pub fn cube(n_keys: usize) -> PolarsResult<Self> {
+        let max_keys = Self::MAX_SETS.ilog2() as usize;
+        polars_ensure!(
+            n_keys <= max_keys,
+            InvalidOperation: "CUBE supports at most {} keys, got {}", max_keys, n_keys
+        );
+        let sets = (0..1usize << n_keys)
+            .map(|excluded| (0..n_keys).filter(|i| excluded & (1 << i) == 0).collect())
+            .collect();
+        Ok(Self::new(sets))
+    }
+
+    pub fn with_id_name(mut self, name: &str) -> Self {
+        self.id_name = name.to_string();
+        self
+    }
+
+    /// The grouping id of a set over `n_keys` keys.
+    pub fn grouping_id(set: &[usize], n_keys: usize) -> u32 {
+        (0..n_keys)
+            .filter(|i| !set.contains(i))
+            .fold(0, |id, i| id | (1 << i))
+    }
+
+    /// The `GROUPING(...)` indicator of the given keys: a bit per key, with
+    /// the first key as most significant bit, that is set if the key is not
+    /// part of the grouping set of the row.
+    pub fn grouping(&self, keys: &[usize]) -> Expr {
+        let n = keys.len();
+        keys.iter()
+            .enumerate()
+            .map(|(j, &i)| {
+                let is_excluded = col(&self.id_name)
+                    .and(typed_lit(1u32 << i))
+                    .neq(typed_lit(0u32));
+                is_excluded.cast(DataType::UInt32) * typed_lit(1u32 << (n - 1 - j))
+            })
+            .reduce(|acc, e| acc + e)
+            .unwrap_or_else(|| typed_lit(0u32))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DistinctOptions {
@@ -305,6 +385,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
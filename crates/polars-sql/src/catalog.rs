@@ -0,0 +1,138 @@
+//! This module defines the catalog, which resolves schema-qualified table names.
+#[cfg(feature = "parquet")]
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// Name of the schema that holds the tables registered on the SQLContext.
+pub const DEFAULT_SCHEMA: &str = "public";
+/// Name of the schema that describes the tables and columns of the SQLContext.
+pub const INFORMATION_SCHEMA: &str = "information_schema";
+
+/// A catalog that lazily resolves the tables of one or more schemas,
+/// referenced in SQL as `schema.table`.
+pub trait Catalog: Send + Sync {
+    /// Get the names of the schemas in the catalog.
+    fn schema_names(&self) -> PolarsResult<Vec<String>>;
+    /// Get the names of the tables in a schema.
+    fn table_names(&self, schema: &str) -> PolarsResult<Vec<String>>;
+    /// Get a table, or `None` if the schema does not contain it.
+    fn get_table(&self, schema: &str, name: &str) -> PolarsResult<Option<LazyFrame>>;
+}
+
+/// A catalog over a local directory, in which every subdirectory is a schema.
+///
+/// The tables of a schema are its Parquet files (named by their file stem)
+/// and its subdirectories, which are scanned as hive-partitioned datasets.
+#[cfg(feature = "parquet")]
+pub struct DirectoryCatalog {
+    root: PathBuf,
+}
+
+#[cfg(feature = "parquet")]
+impl DirectoryCatalog {
+    /// Create a catalog over the given root directory.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Get the path of a schema or table; names must not traverse the directory tree.
+    fn path(&self, parts: &[&str]) -> Option<PathBuf> {
+        let mut path = self.root.clone();
+        for part in parts {
+            if part.is_empty() || *part == "." || *part == ".." || part.contains(['/', '\\']) {
+                return None;
+            }
+            path.push(part);
+        }
+        Some(path)
+    }
+
+    fn entry_names(path: &std::path::Path) -> PolarsResult<Vec<(String, bool)>> {
+        let mut names = vec![];
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            let is_dir = path.is_dir();
+            let name = if is_dir {
+                path.file_name()
+            } else if path.extension().is_some_and(|ext| ext == "parquet") {
+                path.file_stem()
+            } else {
+                None
+            };
+            if let Some(name) = name.and_then(|name| name.to_str()) {
+                names.push((name.to_string(), is_dir));
+            }
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Catalog for DirectoryCatalog {
+    fn schema_names(&self) -> PolarsResult<Vec<String>> {
+        Ok(Self::entry_names(&self.root)?
+            .into_iter()
+            .filter_map(|(name, is_dir)| is_dir.then_some(name))
+            .collect())
+    }
+
+    fn table_names(&self, schema: &str) -> PolarsResult<Vec<String>> {
+        match self.path(&[schema]) {
+            Some(path) if path.is_dir() => Ok(Self::entry_names(&path)?
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()),
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn get_table(&self, schema: &str, name: &str) -> PolarsResult<Option<LazyFrame>> {
+        let Some(path) = self.path(&[schema, name]) else {
+            return Ok(None);
+        };
+        let mut args = ScanArgsParquet::default();
+        if path.is_dir() {
+            args.hive_options.enabled = Some(true);
+            return LazyFrame::scan_parquet(path, args).map(Some);
+        }
+        let path = path.with_extension("parquet");
+        if path.is_file() {
+            return LazyFrame::scan_parquet(path, args).map(Some);
+        }
+        Ok(None)
+    }
+}
+
+/// Get the SQL name of a data type, as reported by `information_schema` and `DESCRIBE`.
+pub(crate) fn sql_type_name(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".to_string(),
+        DataType::Int8 => "TINYINT".to_string(),
+        DataType::Int16 => "SMALLINT".to_string(),
+        DataType::Int32 => "INTEGER".to_string(),
+        DataType::Int64 => "BIGINT".to_string(),
+        DataType::UInt8 => "UTINYINT".to_string(),
+        DataType::UInt16 => "USMALLINT".to_string(),
+        DataType::UInt32 => "UINTEGER".to_string(),
+        DataType::UInt64 => "UBIGINT".to_string(),
+        DataType::Float32 => "REAL".to_string(),
+        DataType::Float64 => "DOUBLE".to_string(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(precision), Some(scale)) => {
+            format!("DECIMAL({precision}, {scale})")
+        },
+        DataType::String => "VARCHAR".to_string(),
+        DataType::Binary => "BLOB".to_string(),
+        DataType::Date => "DATE".to_string(),
+        DataType::Time => "TIME".to_string(),
+        DataType::Datetime(_, None) => "TIMESTAMP".to_string(),
+        DataType::Datetime(_, Some(_)) => "TIMESTAMP WITH TIME ZONE".to_string(),
+        DataType::Duration(_) => "INTERVAL".to_string(),
+        DataType::List(inner) => format!("{}[]", sql_type_name(inner)),
+        DataType::Null => "NULL".to_string(),
+        dtype => dtype.to_string().to_uppercase(),
+    }
+}
@@ -1,29 +1,57 @@
 use std::cell::RefCell;
 use std::ops::Deref;
+use std::sync::Mutex;
 
 use polars_core::export::regex;
 use polars_core::frame::row::Row;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg,
-    GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy,
-    Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
-    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, ExprWithAlias, FromTable, FunctionArg, GroupByExpr,
+    GroupByWithModifier, Ident, Insert, JoinConstraint, JoinOperator, MergeAction, MergeClause,
+    MergeClauseKind, MergeInsertKind, NamedWindowDefinition, NamedWindowExpr, ObjectName,
+    ObjectType, Offset, OrderBy, PivotValueSource, Query, RenameSelectItem, Select, SelectItem,
+    SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins,
+    UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
-use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
+use crate::catalog::{sql_type_name, Catalog, DEFAULT_SCHEMA, INFORMATION_SCHEMA};
+use crate::dialect::PolarsDialect;
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{
-    parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
+    filter_aggregation, join_conjunctions, parse_sql_array, parse_sql_expr, parse_sql_semi_join,
+    resolve_compound_identifier, split_conjunctions, to_sql_interface_err, visit_identifiers,
 };
 use crate::table_functions::PolarsTableFunctions;
 
+/// Name of the grouping id column of a 'GROUP BY' with grouping sets.
+const GROUPING_ID_NAME: &str = "__POLARS_GROUPING_ID";
+/// Prefix of the columns that hold the results of the 'GROUPING' calls.
+const GROUPING_CALL_PREFIX: &str = "__POLARS_GROUPING_CALL_";
+/// Names of the columns that mark the target and source rows of a 'MERGE',
+/// and the column that holds the index of the clause that applies to a row.
+const MERGE_TARGET_NAME: &str = "__POLARS_MERGE_TARGET";
+const MERGE_SOURCE_NAME: &str = "__POLARS_MERGE_SOURCE";
+const MERGE_ACTION_NAME: &str = "__POLARS_MERGE_ACTION";
+/// Default maximum number of iterations of the recursive term of a recursive CTE.
+const RECURSIVE_CTE_MAX_ITERATIONS: usize = 1000;
+
+/// A join that brings the result of a (decorrelated) subquery into scope.
+#[derive(Clone)]
+pub(crate) struct SubqueryJoin {
+    pub(crate) frame: LazyFrame,
+    pub(crate) left_on: Vec<Expr>,
+    pub(crate) right_on: Vec<Expr>,
+    pub(crate) how: JoinType,
+    pub(crate) validation: JoinValidation,
+}
+
 #[derive(Clone)]
 pub struct TableInfo {
     pub(crate) frame: LazyFrame,
@@ -57,22 +85,34 @@ impl SelectModifiers {
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) catalog: Option<Arc<dyn Catalog>>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    catalog_tables: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    named_windows: RefCell<PlHashMap<String, WindowSpec>>,
+    grouping_calls: RefCell<Vec<Vec<Expr>>>,
+    subquery_joins: RefCell<Vec<SubqueryJoin>>,
+    recursive_cte_max_iterations: usize,
 }
 
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            catalog: None,
             table_map: Default::default(),
             cte_map: Default::default(),
+            catalog_tables: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
+            grouping_calls: Default::default(),
+            subquery_joins: Default::default(),
+            recursive_cte_max_iterations: RECURSIVE_CTE_MAX_ITERATIONS,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -141,7 +181,7 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
+        let mut parser = Parser::new(&PolarsDialect);
         parser = parser.with_options(ParserOptions {
             trailing_commas: true,
             ..Default::default()
@@ -164,6 +204,7 @@ impl SQLContext {
 
         // Every execution should clear the statement-level maps.
         self.cte_map.borrow_mut().clear();
+        self.catalog_tables.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
 
@@ -186,6 +227,25 @@ impl SQLContext {
     pub fn registry_mut(&mut self) -> &mut dyn FunctionRegistry {
         Arc::get_mut(&mut self.function_registry).unwrap()
     }
+
+    /// add a catalog to the SQLContext
+    /// the catalog resolves the schema-qualified `schema.table` names of a query
+    pub fn with_catalog(mut self, catalog: Arc<dyn Catalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Get the catalog of the SQLContext, if any
+    pub fn catalog(&self) -> Option<&Arc<dyn Catalog>> {
+        self.catalog.as_ref()
+    }
+
+    /// Set the maximum number of iterations of a recursive CTE's recursive term.
+    /// Queries that do not terminate within this limit fail. Defaults to 1000.
+    pub fn with_recursive_cte_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.recursive_cte_max_iterations = max_iterations;
+        self
+    }
 }
 
 impl SQLContext {
@@ -194,6 +254,7 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
@@ -201,6 +262,10 @@ impl SQLContext {
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update(stmt)?,
+            stmt @ Statement::Delete(_) => self.execute_delete(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type {:?} is not supported", ast,
             ),
@@ -222,17 +287,116 @@ impl SQLContext {
     }
 
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
-        let table = self.table_map.get(name).cloned();
+        // The schema-qualified names of catalog tables take precedence.
+        let table = self.catalog_tables.borrow().get(name).cloned();
         table
+            .or_else(|| self.table_map.get(name).cloned())
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    let table = self.catalog_tables.borrow().get(alias).cloned();
+                    table.or_else(|| self.table_map.get(alias).cloned())
+                })
             })
     }
 
+    /// Resolve a (possibly schema-qualified) table name, returning the name under
+    /// which the table is in scope. Tables of the catalog and of `information_schema`
+    /// are brought into scope for the current statement, under their qualified name.
+    fn resolve_table_name(&mut self, name: &ObjectName) -> PolarsResult<String> {
+        match name.0.as_slice() {
+            [tbl] => Ok(tbl.value.clone()),
+            [schema, tbl] => {
+                let lf = self.get_schema_table(&schema.value, &tbl.value)?;
+                let qualified_name = format!("{}.{}", schema.value, tbl.value);
+                self.catalog_tables
+                    .borrow_mut()
+                    .insert(qualified_name.clone(), lf);
+                Ok(qualified_name)
+            },
+            _ => {
+                polars_bail!(SQLInterface: "invalid table name '{}'; expected [schema.]table", name)
+            },
+        }
+    }
+
+    /// Get a table of the given schema.
+    fn get_schema_table(&mut self, schema: &str, name: &str) -> PolarsResult<LazyFrame> {
+        let lf = if schema.eq_ignore_ascii_case(INFORMATION_SCHEMA) {
+            Some(self.get_information_schema_table(name)?)
+        } else if schema == DEFAULT_SCHEMA {
+            self.table_map.get(name).cloned()
+        } else if let Some(catalog) = &self.catalog {
+            catalog.get_table(schema, name)?
+        } else {
+            None
+        };
+        lf.ok_or_else(|| polars_err!(SQLInterface: "relation '{}.{}' was not found", schema, name))
+    }
+
+    /// Get the (schema, table) names of all registered and catalog tables.
+    fn get_schema_tables(&self) -> PolarsResult<Vec<(String, String)>> {
+        let mut tables = self
+            .get_tables()
+            .into_iter()
+            .map(|name| (DEFAULT_SCHEMA.to_string(), name))
+            .collect::<Vec<_>>();
+        if let Some(catalog) = &self.catalog {
+            for schema in catalog.schema_names()? {
+                for name in catalog.table_names(&schema)? {
+                    tables.push((schema.clone(), name));
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    /// Get the `information_schema.tables` or `information_schema.columns` table.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_schema_tables()?;
+        let df = match name.to_lowercase().as_str() {
+            "tables" => {
+                let table_types = vec!["BASE TABLE"; tables.len()];
+                let (schemas, names): (Vec<_>, Vec<_>) = tables.into_iter().unzip();
+                df! {
+                    "table_schema" => schemas,
+                    "table_name" => names,
+                    "table_type" => table_types,
+                }?
+            },
+            "columns" => {
+                let mut schemas = vec![];
+                let mut names = vec![];
+                let mut column_names = vec![];
+                let mut positions = vec![];
+                let mut data_types = vec![];
+                for (schema, name) in tables {
+                    let mut lf = self.get_schema_table(&schema, &name)?;
+                    for (idx, (column_name, dtype)) in
+                        self.get_frame_schema(&mut lf)?.iter().enumerate()
+                    {
+                        schemas.push(schema.clone());
+                        names.push(name.clone());
+                        column_names.push(column_name.to_string());
+                        positions.push(idx as i64 + 1);
+                        data_types.push(sql_type_name(dtype));
+                    }
+                }
+                df! {
+                    "table_schema" => schemas,
+                    "table_name" => names,
+                    "column_name" => column_names,
+                    "ordinal_position" => positions,
+                    "data_type" => data_types,
+                }?
+            },
+            _ => {
+                polars_bail!(SQLInterface: "relation '{}.{}' was not found", INFORMATION_SCHEMA, name)
+            },
+        };
+        Ok(df.lazy())
+    }
+
     fn expr_or_ordinal(
         &mut self,
         e: &SQLExpr,
@@ -309,7 +473,20 @@ impl SQLContext {
 
     fn process_query(&mut self, expr: &SetExpr, query: &Query) -> PolarsResult<LazyFrame> {
         match expr {
-            SetExpr::Select(select_stmt) => self.execute_select(select_stmt, query),
+            SetExpr::Select(select_stmt) => {
+                // The windows of a 'WINDOW' clause, the 'GROUPING' calls and
+                // the subquery joins are only visible in their own SELECT.
+                let outer_windows = self.named_windows.take();
+                let outer_grouping_calls = self.grouping_calls.take();
+                let outer_subquery_joins = self.subquery_joins.take();
+                let lf = self
+                    .register_named_windows(&select_stmt.named_window)
+                    .and_then(|_| self.execute_select(select_stmt, query));
+                self.named_windows.replace(outer_windows);
+                self.grouping_calls.replace(outer_grouping_calls);
+                self.subquery_joins.replace(outer_subquery_joins);
+                lf
+            },
             SetExpr::Query(query) => self.execute_query_no_ctes(query),
             SetExpr::SetOperation {
                 op: SetOperator::Union,
@@ -478,13 +655,44 @@ impl SQLContext {
         }
     }
 
-    // SHOW TABLES
-    fn execute_show_tables(&mut self, _: &Statement) -> PolarsResult<LazyFrame> {
-        let tables = Series::new("name", self.get_tables());
+    // SHOW TABLES [FROM schema]
+    fn execute_show_tables(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ShowTables { db_name, .. } = stmt else {
+            unreachable!()
+        };
+        let names = match db_name {
+            None => self.get_tables(),
+            Some(schema) if schema.value == DEFAULT_SCHEMA => self.get_tables(),
+            Some(schema) if schema.value.eq_ignore_ascii_case(INFORMATION_SCHEMA) => {
+                vec!["columns".to_string(), "tables".to_string()]
+            },
+            Some(schema) => match &self.catalog {
+                Some(catalog) => catalog.table_names(&schema.value)?,
+                None => polars_bail!(SQLInterface: "schema '{}' does not exist", schema),
+            },
+        };
+        let tables = Series::new("name", names);
         let df = DataFrame::new(vec![tables])?;
         Ok(df.lazy())
     }
 
+    // DESCRIBE tbl
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ExplainTable { table_name, .. } = stmt else {
+            unreachable!()
+        };
+        let tbl_name = self.resolve_table_name(table_name)?;
+        let Some(mut lf) = self.get_table_from_current_scope(&tbl_name) else {
+            polars_bail!(SQLInterface: "relation '{}' was not found", table_name);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let df = df! {
+            "column_name" => schema.iter_names().map(|name| name.as_str()).collect::<Vec<_>>(),
+            "column_type" => schema.iter_dtypes().map(sql_type_name).collect::<Vec<_>>(),
+        }?;
+        Ok(df.lazy())
+    }
+
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
             Statement::Drop { names, .. } => {
@@ -528,27 +736,602 @@ impl SQLContext {
         }
     }
 
+    /// Get a registered table (and its schema) to be modified by a DML statement.
+    fn get_dml_table(
+        &mut self,
+        name: &ObjectName,
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame, SchemaRef)> {
+        let tbl_name = name.0.first().unwrap().value.clone();
+        let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+        };
+        if let Some(alias) = alias {
+            self.table_aliases
+                .borrow_mut()
+                .insert(alias.name.value.clone(), tbl_name.clone());
+        }
+        let schema = self.get_frame_schema(&mut lf)?;
+        Ok((tbl_name, lf, schema))
+    }
+
+    /// Replace a table modified by a DML statement, and respond with the statement.
+    fn replace_dml_table(
+        &mut self,
+        tbl_name: &str,
+        lf: LazyFrame,
+        schema: &Schema,
+        statement: &str,
+    ) -> LazyFrame {
+        self.register(tbl_name, lf.select(column_exprs(schema)));
+        df! {
+            "Response" => [statement]
+        }
+        .unwrap()
+        .lazy()
+    }
+
+    /// Get the name of the column targeted by an assignment.
+    fn get_assignment_target<'a>(
+        &self,
+        assignment: &'a Assignment,
+        schema: &Schema,
+    ) -> PolarsResult<&'a str> {
+        match &assignment.target {
+            AssignmentTarget::ColumnName(name) => {
+                let name = name.0.last().unwrap().value.as_str();
+                polars_ensure!(
+                    schema.contains(name),
+                    ColumnNotFound: "cannot assign to '{}'; column not found", name
+                );
+                Ok(name)
+            },
+            target => {
+                polars_bail!(SQLInterface: "assignment to a tuple of columns is not supported; found {}", target)
+            },
+        }
+    }
+
+    // INSERT INTO tbl [(cols)] VALUES ... / SELECT ...
+    fn execute_insert(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Insert(Insert {
+            or,
+            ignore,
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            replace_into,
+            ..
+        }) = stmt
+        else {
+            unreachable!()
+        };
+        polars_ensure!(
+            or.is_none() && !ignore && partitioned.is_none() && on.is_none() && returning.is_none() && !replace_into,
+            SQLInterface: "INSERT only supports an optional column list and OVERWRITE; found {}", stmt
+        );
+        let Some(source) = source else {
+            polars_bail!(SQLInterface: "INSERT requires a VALUES or SELECT source");
+        };
+        let (tbl_name, lf, schema) = self.get_dml_table(table_name, None)?;
+        let mut values = self.execute_query(source)?;
+        let values_schema = self.get_frame_schema(&mut values)?;
+
+        let targets = if columns.is_empty() {
+            schema.iter_names().map(|name| name.as_str()).collect()
+        } else {
+            columns
+                .iter()
+                .map(|ident| {
+                    polars_ensure!(
+                        schema.contains(&ident.value),
+                        ColumnNotFound: "column '{}' not found in table '{}'", ident.value, tbl_name
+                    );
+                    Ok(ident.value.as_str())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+        polars_ensure!(
+            targets.len() == values_schema.len(),
+            SQLSyntax: "INSERT has {} target columns but {} values", targets.len(), values_schema.len()
+        );
+
+        // Columns that are not inserted into are NULL.
+        let values = values.select(
+            schema
+                .iter()
+                .map(|(name, dtype)| {
+                    match targets.iter().position(|target| target == name) {
+                        Some(idx) => col(values_schema.get_at_index(idx).unwrap().0),
+                        None => lit(LiteralValue::Null),
+                    }
+                    .strict_cast(dtype.clone())
+                    .alias(name)
+                })
+                .collect::<Vec<_>>(),
+        );
+        let lf = if *overwrite {
+            values
+        } else {
+            concat([lf, values], UnionArgs::default())?
+        };
+        Ok(self.replace_dml_table(&tbl_name, lf, &schema, "INSERT"))
+    }
+
+    // UPDATE tbl SET col = value, ... [WHERE ...]
+    fn execute_update(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let TableWithJoins {
+            relation:
+                TableFactor::Table {
+                    name,
+                    alias,
+                    args: None,
+                    ..
+                },
+            joins,
+        } = table
+        else {
+            polars_bail!(SQLInterface: "UPDATE requires a table name; found {}", table);
+        };
+        polars_ensure!(
+            joins.is_empty() && from.is_none() && returning.is_none(),
+            SQLInterface: "UPDATE does not support joins, FROM or RETURNING"
+        );
+        let (tbl_name, mut lf, schema) = self.get_dml_table(name, alias.as_ref())?;
+
+        let ((mut predicate, mut values), _) = self.parse_with_subqueries(&mut lf, |ctx| {
+            let predicate = selection
+                .as_ref()
+                .map(|expr| parse_sql_expr(expr, ctx, Some(&schema)))
+                .transpose()?;
+            let values = assignments
+                .iter()
+                .map(|assignment| {
+                    let name = ctx.get_assignment_target(assignment, &schema)?;
+                    let value = parse_sql_expr(&assignment.value, ctx, Some(&schema))?;
+                    Ok(value
+                        .strict_cast(schema.get(name).unwrap().clone())
+                        .alias(name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok((predicate, values))
+        })?;
+        lf = self.process_subqueries(lf, predicate.iter_mut().chain(&mut values).collect());
+
+        // All values are computed from the rows before the update.
+        if let Some(predicate) = predicate {
+            values = values
+                .into_iter()
+                .map(|value| {
+                    let name = value.clone().meta().output_name()?;
+                    Ok(when(predicate.clone())
+                        .then(value)
+                        .otherwise(col(name.as_ref()))
+                        .alias(name.as_ref()))
+                })
+                .collect::<PolarsResult<_>>()?;
+        }
+        Ok(self.replace_dml_table(&tbl_name, lf.with_columns(values), &schema, "UPDATE"))
+    }
+
+    // DELETE FROM tbl [WHERE ...]
+    fn execute_delete(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Delete(Delete {
+            tables,
+            from: FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from),
+            using,
+            selection,
+            returning,
+            order_by,
+            limit,
+        }) = stmt
+        else {
+            unreachable!()
+        };
+        polars_ensure!(
+            tables.is_empty() && using.is_none() && returning.is_none() && order_by.is_empty() && limit.is_none(),
+            SQLInterface: "DELETE only supports an optional WHERE clause; found {}", stmt
+        );
+        let [TableWithJoins {
+            relation:
+                TableFactor::Table {
+                    name,
+                    alias,
+                    args: None,
+                    ..
+                },
+            joins,
+        }] = from.as_slice()
+        else {
+            polars_bail!(SQLInterface: "DELETE requires a single table name");
+        };
+        polars_ensure!(joins.is_empty(), SQLInterface: "DELETE does not support joins");
+        let (tbl_name, mut lf, schema) = self.get_dml_table(name, alias.as_ref())?;
+
+        lf = match selection {
+            // Keep the rows for which the predicate is not true.
+            Some(expr) => {
+                let (mut predicate, _) = self.parse_with_subqueries(&mut lf, |ctx| {
+                    parse_sql_expr(expr, ctx, Some(&schema))
+                })?;
+                lf = self.process_subqueries(lf, vec![&mut predicate]);
+                lf.filter(predicate.fill_null(lit(false)).not())
+            },
+            None => DataFrame::empty_with_schema(&schema).lazy(),
+        };
+        Ok(self.replace_dml_table(&tbl_name, lf, &schema, "DELETE"))
+    }
+
+    // MERGE INTO tbl USING source ON ... WHEN [NOT] MATCHED [AND ...] THEN ...
+    fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let TableFactor::Table {
+            name,
+            alias,
+            args: None,
+            ..
+        } = table
+        else {
+            polars_bail!(SQLInterface: "MERGE requires a target table name; found {}", table);
+        };
+        let (tbl_name, target, schema) = self.get_dml_table(name, alias.as_ref())?;
+        let t_name = alias
+            .as_ref()
+            .map_or(tbl_name.clone(), |alias| alias.name.value.clone());
+        let (s_name, mut source) = self.get_table(source)?;
+        let source_schema = self.get_frame_schema(&mut source)?;
+
+        // Columns that exist in both tables must be qualified.
+        let ensure_unambiguous = |expr: &SQLExpr| {
+            let mut ambiguous = None;
+            visit_identifiers(expr, &mut |idents| {
+                if let [ident] = idents {
+                    if schema.contains(&ident.value) && source_schema.contains(&ident.value) {
+                        ambiguous.get_or_insert(ident.value.clone());
+                    }
+                }
+            });
+            match ambiguous {
+                Some(name) => {
+                    polars_bail!(SQLSyntax: "column reference '{}' is ambiguous in MERGE; qualify it with '{}' or '{}'", name, t_name, s_name)
+                },
+                None => Ok(()),
+            }
+        };
+
+        // The ON condition joins the target and source rows with equal keys.
+        let is_target = |expr: &SQLExpr| {
+            let (mut target, mut source) = (false, false);
+            visit_identifiers(expr, &mut |idents| match idents {
+                [ident] => {
+                    target |= schema.contains(&ident.value);
+                    source |= source_schema.contains(&ident.value);
+                },
+                [root, ..] => {
+                    target |= root.value == t_name;
+                    source |= root.value == s_name;
+                },
+                [] => {},
+            });
+            match (target, source) {
+                (true, false) => Some(true),
+                (false, true) => Some(false),
+                _ => None,
+            }
+        };
+        let (mut target_keys, mut source_keys) = (vec![], vec![]);
+        for expr in split_conjunctions(on) {
+            let (t_key, s_key) = match expr {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } => match (is_target(left), is_target(right)) {
+                    (Some(true), Some(false)) => (left, right),
+                    (Some(false), Some(true)) => (right, left),
+                    _ => {
+                        polars_bail!(SQLInterface: "MERGE ON condition must compare target and source columns for equality; found {}", expr)
+                    },
+                },
+                _ => {
+                    polars_bail!(SQLInterface: "MERGE ON condition must be a conjunction of equalities; found {}", expr)
+                },
+            };
+            let t_key = parse_sql_expr(t_key, self, Some(&schema))?;
+            let s_key = parse_sql_expr(s_key, self, Some(&source_schema))?;
+            let dtype = t_key.to_field(&schema, Context::Default)?.dtype;
+            target_keys.push(t_key);
+            source_keys.push(s_key.cast(dtype));
+        }
+
+        // The target rows are numbered, to check that they match at most one
+        // source row.
+        let mut joined = target
+            .with_row_index(MERGE_TARGET_NAME, None)
+            .join_builder()
+            .with(source.with_column(lit(true).alias(MERGE_SOURCE_NAME)))
+            .left_on(target_keys)
+            .right_on(source_keys)
+            .how(JoinType::Full)
+            .suffix(format!(":{}", s_name))
+            .coalesce(JoinCoalesce::KeepColumns)
+            .finish();
+        self.register_joined_aliases(&mut joined, &s_name, &schema, &source_schema)?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+
+        // Find the first clause that applies to every row, and the values
+        // that the clause assigns to the columns of the row.
+        let has_target = col(MERGE_TARGET_NAME).is_not_null();
+        let has_source = col(MERGE_SOURCE_NAME).is_not_null();
+        let action = col(MERGE_ACTION_NAME);
+        let mut conditions = Vec::with_capacity(clauses.len());
+        let mut values: Vec<Vec<(usize, Expr)>> = vec![vec![]; schema.len()];
+        let mut keep = has_target.clone();
+        for (
+            idx,
+            MergeClause {
+                clause_kind,
+                predicate,
+                action: merge_action,
+            },
+        ) in clauses.iter().enumerate()
+        {
+            let this_action = action
+                .clone()
+                .eq(typed_lit(idx as u32))
+                .fill_null(lit(false));
+            let mut condition = match clause_kind {
+                MergeClauseKind::Matched => has_target.clone().and(has_source.clone()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    has_target.clone().not()
+                },
+                MergeClauseKind::NotMatchedBySource => has_source.clone().not(),
+            };
+            if let Some(predicate) = predicate {
+                ensure_unambiguous(predicate)?;
+                let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+                condition = condition.and(predicate.fill_null(lit(false)));
+            }
+            conditions.push(condition);
+
+            let is_insert = matches!(
+                clause_kind,
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+            );
+            match merge_action {
+                MergeAction::Insert(insert) if is_insert => {
+                    let MergeInsertKind::Values(Values { rows, .. }) = &insert.kind else {
+                        polars_bail!(SQLInterface: "MERGE INSERT requires a VALUES list; found {}", insert);
+                    };
+                    let [row] = rows.as_slice() else {
+                        polars_bail!(SQLSyntax: "MERGE INSERT requires a single row of values");
+                    };
+                    let targets = if insert.columns.is_empty() {
+                        schema.iter_names().map(|name| name.as_str()).collect()
+                    } else {
+                        insert
+                            .columns
+                            .iter()
+                            .map(|ident| ident.value.as_str())
+                            .collect::<Vec<_>>()
+                    };
+                    polars_ensure!(
+                        targets.len() == row.len(),
+                        SQLSyntax: "MERGE INSERT has {} target columns but {} values", targets.len(), row.len()
+                    );
+                    for (name, expr) in targets.into_iter().zip(row) {
+                        let Some(col_idx) = schema.index_of(name) else {
+                            polars_bail!(ColumnNotFound: "column '{}' not found in table '{}'", name, tbl_name);
+                        };
+                        ensure_unambiguous(expr)?;
+                        let value = parse_sql_expr(expr, self, Some(&joined_schema))?;
+                        values[col_idx].push((idx, value));
+                    }
+                    // Columns that are not inserted into keep the (NULL) values of
+                    // the unmatched target row.
+                    keep = keep.or(this_action);
+                },
+                MergeAction::Update { assignments } if !is_insert => {
+                    for assignment in assignments {
+                        let name = self.get_assignment_target(assignment, &schema)?;
+                        ensure_unambiguous(&assignment.value)?;
+                        let value = parse_sql_expr(&assignment.value, self, Some(&joined_schema))?;
+                        values[schema.index_of(name).unwrap()].push((idx, value));
+                    }
+                },
+                MergeAction::Delete if !is_insert => keep = keep.and(this_action.not()),
+                _ => {
+                    polars_bail!(SQLSyntax: "cannot {} when {}", merge_action, clause_kind)
+                },
+            }
+        }
+
+        let action_expr = conditions.into_iter().enumerate().rev().fold(
+            lit(LiteralValue::Null).cast(DataType::UInt32),
+            |acc, (idx, condition)| when(condition).then(typed_lit(idx as u32)).otherwise(acc),
+        );
+        let value_exprs = schema
+            .iter()
+            .zip(values)
+            .map(|((name, dtype), values)| {
+                values
+                    .into_iter()
+                    .rev()
+                    .fold(col(name), |acc, (idx, value)| {
+                        when(
+                            action
+                                .clone()
+                                .eq(typed_lit(idx as u32))
+                                .fill_null(lit(false)),
+                        )
+                        .then(value.strict_cast(dtype.clone()))
+                        .otherwise(acc)
+                    })
+                    .alias(name)
+            })
+            .collect::<Vec<_>>();
+        // Source rows with equal keys are only an error if they match a target
+        // row, not if they are inserted.
+        let validate_matches = |s: &mut [Series]| {
+            let matched = s[0].filter(s[1].bool()?)?.drop_nulls();
+            polars_ensure!(
+                matched.n_unique()? == matched.len(),
+                SQLInterface: "MERGE matched a target row with more than one source row"
+            );
+            Ok(Some(s[0].clone()))
+        };
+        let lf = joined
+            .with_column(col(MERGE_TARGET_NAME).apply_many(
+                validate_matches,
+                &[has_source],
+                GetOutput::same_type(),
+            ))
+            .with_column(action_expr.alias(MERGE_ACTION_NAME))
+            .with_columns(value_exprs)
+            .filter(keep);
+        Ok(self.replace_dml_table(&tbl_name, lf, &schema, "MERGE"))
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match recursive_cte_terms(cte, with.recursive) {
+                    Some((anchor, recursive, quantifier)) => {
+                        self.execute_recursive_cte(cte, anchor, recursive, quantifier)?
+                    },
+                    None => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE of the form `anchor UNION [ALL] recursive` to a fixpoint.
+    ///
+    /// Each iteration evaluates the recursive term against the rows produced by the
+    /// previous one (starting with the anchor), until no new rows are produced. With
+    /// `UNION`, rows that were already produced are discarded. The recursive term is
+    /// planned once against a working table; the iterations run when the query is collected.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let query = cte.query.as_ref();
+        polars_ensure!(
+            query.order_by.is_none() && query.limit.is_none() && query.offset.is_none(),
+            SQLInterface: "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE '{}'", cte_name
+        );
+        polars_ensure!(
+            !references_table(anchor, cte_name),
+            SQLInterface: "the non-recursive term of recursive CTE '{}' cannot reference it", cte_name
+        );
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", quantifier, cte_name
+            ),
+        };
+
+        let lf = self.process_query(anchor, query)?;
+        let anchor_lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        let schema = self.get_frame_schema(&mut anchor_lf.clone())?;
+
+        let working = Arc::new(WorkingTable {
+            schema: schema.clone(),
+            df: Mutex::new(DataFrame::empty_with_schema(&schema)),
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            name: "RECURSIVE CTE WORKING TABLE",
+            ..Default::default()
+        };
+        self.register_cte(cte_name, LazyFrame::anonymous_scan(working.clone(), args)?);
+        let mut rf = self.process_query(recursive, query)?;
+        let rf_schema = self.get_frame_schema(&mut rf.clone())?;
+        polars_ensure!(
+            rf_schema.len() == schema.len(),
+            SQLInterface: "the terms of recursive CTE '{}' return a different number of columns ({} != {})",
+            cte_name, schema.len(), rf_schema.len()
+        );
+        let exprs = rf_schema
+            .iter_names()
+            .zip(schema.iter())
+            .map(|(rf_name, (name, dtype))| col(rf_name).strict_cast(dtype.clone()).alias(name))
+            .collect::<Vec<_>>();
+        rf = rf.select(exprs);
+
+        let cte_name = cte_name.to_string();
+        let max_iterations = self.recursive_cte_max_iterations;
+        let function = move |anchor: DataFrame| {
+            let mut seen = distinct.then(PlHashSet::new);
+            let mut result = remove_seen_rows(anchor, seen.as_mut())?;
+            let mut new_rows = result.clone();
+            let mut iterations = 0;
+            while new_rows.height() > 0 {
+                polars_ensure!(
+                    iterations < max_iterations,
+                    SQLInterface: "recursive CTE '{}' did not terminate after {} iterations",
+                    cte_name, max_iterations
+                );
+                iterations += 1;
+
+                *working.df.lock().unwrap() = new_rows;
+                new_rows = remove_seen_rows(rf.clone().collect()?, seen.as_mut())?;
+                result.vstack_mut(&new_rows)?;
+            }
+            result.as_single_chunk_par();
+            Ok(result)
+        };
+        Ok(anchor_lf.map(
+            function,
+            AllowedOptimizations::empty(),
+            None,
+            Some("RECURSIVE CTE"),
+        ))
+    }
+
     /// execute the 'FROM' part of the query
-    fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
+    pub(crate) fn execute_from_statement(
+        &mut self,
+        tbl_expr: &TableWithJoins,
+    ) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
@@ -599,30 +1382,101 @@ impl SQLContext {
                 };
 
                 // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.to_string(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &mut self,
+        joined: &mut LazyFrame,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> PolarsResult<()> {
+        let joined_schema = self.get_frame_schema(joined)?;
+
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(())
+    }
+
+    /// Register the windows of a 'WINDOW' clause, which can refer to the
+    /// windows defined before them.
+    fn register_named_windows(&self, windows: &[NamedWindowDefinition]) -> PolarsResult<()> {
+        for NamedWindowDefinition(name, window) in windows {
+            let spec = match window {
+                NamedWindowExpr::NamedWindow(base) => self.get_named_window(base)?,
+                NamedWindowExpr::WindowSpec(spec) => self.resolve_window_spec(spec)?,
+            };
+            self.named_windows
+                .borrow_mut()
+                .insert(name.value.clone(), spec);
+        }
+        Ok(())
+    }
+
+    fn get_named_window(&self, name: &Ident) -> PolarsResult<WindowSpec> {
+        self.named_windows
+            .borrow()
+            .get(&name.value)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLInterface: "no window named '{}' found", name))
+    }
+
+    /// Resolve the window of an 'OVER' clause, which is either a named window
+    /// or a window specification that may extend a named window.
+    pub(crate) fn resolve_window(&self, window: &WindowType) -> PolarsResult<WindowSpec> {
+        match window {
+            WindowType::NamedWindow(name) => self.get_named_window(name),
+            WindowType::WindowSpec(spec) => self.resolve_window_spec(spec),
+        }
+    }
+
+    fn resolve_window_spec(&self, spec: &WindowSpec) -> PolarsResult<WindowSpec> {
+        let Some(name) = &spec.window_name else {
+            return Ok(spec.clone());
+        };
+        // A window can only add an ORDER BY and a frame clause to the named window.
+        let base = self.get_named_window(name)?;
+        polars_ensure!(
+            spec.partition_by.is_empty(),
+            SQLSyntax: "cannot override the PARTITION BY clause of window '{}'", name
+        );
+        polars_ensure!(
+            spec.order_by.is_empty() || base.order_by.is_empty(),
+            SQLSyntax: "cannot override the ORDER BY clause of window '{}'", name
+        );
+        polars_ensure!(
+            base.window_frame.is_none(),
+            SQLSyntax: "cannot copy window '{}' as it has a frame clause", name
+        );
+        Ok(WindowSpec {
+            window_name: None,
+            partition_by: base.partition_by,
+            order_by: if spec.order_by.is_empty() {
+                base.order_by
+            } else {
+                spec.order_by.clone()
+            },
+            window_frame: spec.window_frame.clone(),
+        })
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
@@ -651,33 +1505,30 @@ impl SQLContext {
 
         let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
 
+        // Bring the results of the subqueries in the projections into scope.
+        let subquery_joins = self.subquery_joins.take();
+        let schema = if subquery_joins.is_empty() {
+            schema
+        } else {
+            lf = self.apply_subquery_joins(lf, subquery_joins);
+            self.get_frame_schema(&mut lf)?
+        };
+
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets = None;
         match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values),
+            // optionally with "GROUPING SETS", "ROLLUP" and "CUBE" elements
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                (group_by_keys, grouping_sets) =
+                    self.process_grouping_sets(group_by_exprs, modifiers, &projections, &schema)?;
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
                 if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                    polars_bail!(SQLInterface: "GROUP BY ALL does not support CUBE, ROLLUP, or TOTALS modifiers")
                 }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
@@ -704,15 +1555,20 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        let grouping_calls = self.grouping_calls.take();
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
+            polars_ensure!(
+                grouping_calls.is_empty(),
+                SQLSyntax: "GROUPING can only be used in a query with a GROUP BY clause"
+            );
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
-            let have_order_by = query.order_by.is_some();
+            let project_all = query.order_by.is_some() || select_stmt.qualify.is_some();
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' or 'qualify' then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. Otherwise we
+            // can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -721,7 +1577,7 @@ impl SQLContext {
                 if select_modifiers.matches_ilike(&name)
                     && !select_modifiers.exclude.contains(&name)
                 {
-                    retained_cols.push(if have_order_by {
+                    retained_cols.push(if project_all {
                         col(name.as_str())
                     } else {
                         p.clone()
@@ -730,9 +1586,10 @@ impl SQLContext {
             }
 
             // Apply the remaining modifiers and establish the final projection
-            if have_order_by {
+            if project_all {
                 lf = lf.with_columns(projections);
             }
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
             if !select_modifiers.replace.is_empty() {
                 lf = lf.with_columns(&select_modifiers.replace);
             }
@@ -751,16 +1608,31 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf = self.process_group_by(
+                lf,
+                &group_by_keys,
+                &projections,
+                grouping_sets,
+                &grouping_calls,
+            )?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
+            };
+            self.process_qualify(lf, &select_stmt.qualify)?
         };
+        polars_ensure!(
+            self.grouping_calls.borrow().is_empty(),
+            SQLInterface: "GROUPING is only supported in the SELECT list"
+        );
+        polars_ensure!(
+            self.subquery_joins.borrow().is_empty(),
+            SQLInterface: "subqueries are only supported in the SELECT list and the WHERE clause"
+        );
 
         // Apply optional DISTINCT clause.
         lf = match &select_stmt.distinct {
@@ -789,6 +1661,21 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Filter on the result of window functions (QUALIFY clause); the columns and
+    /// aliases of the projection are in scope.
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        if let Some(expr) = expr {
+            let schema = self.get_frame_schema(&mut lf)?;
+            let predicate = parse_sql_expr(expr, self, Some(&schema))?;
+            lf = lf.filter(predicate);
+        }
+        Ok(lf)
+    }
+
     fn column_projections(
         &mut self,
         select_stmt: &Select,
@@ -838,23 +1725,61 @@ impl SQLContext {
         Ok(flattened_exprs)
     }
 
-    fn process_where(
+    pub(crate) fn process_where(
         &mut self,
         mut lf: LazyFrame,
         expr: &Option<SQLExpr>,
     ) -> PolarsResult<LazyFrame> {
         if let Some(expr) = expr {
-            let schema = Some(self.get_frame_schema(&mut lf)?);
-            let mut filter_expression = parse_sql_expr(expr, self, schema.as_deref())?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            // Correlated '[NOT] EXISTS' and '[NOT] IN' conjuncts filter the
+            // frame with a semi/anti join, the others with a predicate.
+            let mut predicates = vec![];
+            for conjunct in split_conjunctions(expr) {
+                match parse_sql_semi_join(conjunct, self, &schema)? {
+                    Some(join) => lf = self.apply_subquery_joins(lf, vec![join]),
+                    None => predicates.push(conjunct),
+                }
+            }
+            let Some(predicate) = join_conjunctions(predicates) else {
+                return Ok(lf);
+            };
+
+            let (mut filter_expression, joined) = self.parse_with_subqueries(&mut lf, |ctx| {
+                parse_sql_expr(&predicate, ctx, Some(&schema))
+            })?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
             }
             lf = self.process_subqueries(lf, vec![&mut filter_expression]);
             lf = lf.filter(filter_expression);
+            if joined {
+                // Drop the joined subquery results again.
+                lf = lf.select(column_exprs(&schema));
+            }
         }
         Ok(lf)
     }
 
+    /// Parse expressions with `parse`, joining the results of their (correlated
+    /// or scalar) subqueries to the frame. Returns whether any were joined.
+    fn parse_with_subqueries<T>(
+        &mut self,
+        lf: &mut LazyFrame,
+        parse: impl FnOnce(&mut Self) -> PolarsResult<T>,
+    ) -> PolarsResult<(T, bool)> {
+        let outer_subquery_joins = self.subquery_joins.take();
+        let parsed = parse(self);
+        let subquery_joins = self.subquery_joins.replace(outer_subquery_joins);
+        let parsed = parsed?;
+        let joined = !subquery_joins.is_empty();
+        if joined {
+            *lf = self.apply_subquery_joins(lf.clone(), subquery_joins);
+        }
+        Ok((parsed, joined))
+    }
+
     pub(super) fn process_join(
         &mut self,
         tbl_left: &TableInfo,
@@ -879,6 +1804,34 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Register a join that brings the result of a subquery into scope.
+    pub(crate) fn register_subquery_join(&self, join: SubqueryJoin) {
+        self.subquery_joins.borrow_mut().push(join);
+    }
+
+    /// Get the index of the next subquery join of the current SELECT.
+    pub(crate) fn next_subquery_index(&self) -> usize {
+        self.subquery_joins.borrow().len()
+    }
+
+    fn apply_subquery_joins(&self, mut lf: LazyFrame, joins: Vec<SubqueryJoin>) -> LazyFrame {
+        for join in joins {
+            lf = if join.left_on.is_empty() {
+                lf.cross_join(join.frame, None)
+            } else {
+                lf.join_builder()
+                    .with(join.frame)
+                    .left_on(join.left_on)
+                    .right_on(join.right_on)
+                    .how(join.how)
+                    .validate(join.validation)
+                    .coalesce(JoinCoalesce::KeepColumns)
+                    .finish()
+            }
+        }
+        lf
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -941,16 +1894,25 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, args);
                 }
-                let tbl_name = name.0.first().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                let tbl_name = self.resolve_table_name(name)?;
+                if let Some(lf) = self.get_table_from_current_scope(&tbl_name) {
                     match alias {
                         Some(alias) => {
                             self.table_aliases
                                 .borrow_mut()
-                                .insert(alias.name.value.clone(), tbl_name.to_string());
+                                .insert(alias.name.value.clone(), tbl_name);
                             Ok((alias.to_string(), lf))
                         },
-                        None => Ok((tbl_name.to_string(), lf)),
+                        None => {
+                            // A schema-qualified table can be referred to by its bare name.
+                            let bare_name = &name.0.last().unwrap().value;
+                            if *bare_name != tbl_name {
+                                self.table_aliases
+                                    .borrow_mut()
+                                    .insert(bare_name.clone(), tbl_name);
+                            }
+                            Ok((bare_name.clone(), lf))
+                        },
                     }
                 } else {
                     polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
@@ -1042,11 +2004,191 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_table_operator(lf, alias.as_ref())
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.execute_unpivot(lf, value, name, columns)?;
+                self.register_table_operator(lf, alias.as_ref())
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Apply the (optional) alias of a PIVOT/UNPIVOT table operator to its result.
+    fn register_table_operator(
+        &mut self,
+        lf: LazyFrame,
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok(("".to_string(), lf)),
+        }
+    }
+
+    /// Pivot the values of a column into new columns, one for each value of the `IN` list
+    /// (and each aggregate). The remaining columns are used as the group keys.
+    ///
+    /// The pivot in `polars_lazy::frame::pivot` works on a materialized `DataFrame`, as it
+    /// discovers the output columns from the data. Here the `IN` list fixes the output
+    /// schema, so the pivot stays lazy with one filtered aggregation per output column;
+    /// combinations without any rows are null, as in that pivot.
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let values = match value_source {
+            PivotValueSource::List(values) => values,
+            _ => {
+                polars_bail!(SQLInterface: "PIVOT requires an explicit list of values, found {}", value_source)
+            },
+        };
+        polars_ensure!(
+            !values.is_empty(),
+            SQLSyntax: "PIVOT requires at least one value"
+        );
+        polars_ensure!(
+            aggregate_functions.len() == 1 || aggregate_functions.iter().all(|a| a.alias.is_some()),
+            SQLSyntax: "PIVOT with multiple aggregates requires an alias for each of them"
+        );
+        let schema = self.get_frame_schema(&mut lf)?;
+        let pivot_col = match value_column.last() {
+            Some(ident) if schema.contains(&ident.value) => ident.value.as_str(),
+            _ => polars_bail!(
+                SQLInterface: "PIVOT column '{}' not found",
+                SQLExpr::CompoundIdentifier(value_column.to_vec())
+            ),
+        };
+
+        let aggs = aggregate_functions
+            .iter()
+            .map(|agg| {
+                let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+                Ok((expr, agg.alias.as_ref()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let default = default_on_null
+            .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+            .transpose()?;
+
+        // Every column that is not pivoted or aggregated is a group key.
+        let mut used = PlHashSet::new();
+        used.insert(pivot_col);
+        for (expr, _) in &aggs {
+            for name in expr_to_leaf_column_names_iter(expr) {
+                if let Some((_, name, _)) = schema.get_full(&name) {
+                    used.insert(name.as_str());
+                }
+            }
+        }
+        let keys = schema
+            .iter_names()
+            .filter(|name| !used.contains(name.as_str()))
+            .map(|name| col(name))
+            .collect::<Vec<_>>();
+
+        let mut pivoted = Vec::with_capacity(values.len() * aggs.len());
+        for value in values {
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                (None, expr) => expr.to_string(),
+            };
+            let cond = col(pivot_col).eq(parse_sql_expr(&value.expr, self, Some(&schema))?);
+            for (expr, alias) in &aggs {
+                // Values without any rows are null (even for counts), as in the frame pivot.
+                let agg = filter_aggregation(expr.clone(), &cond, false);
+                let name = match alias {
+                    Some(alias) => format!("{}_{}", value_name, alias.value),
+                    None => value_name.clone(),
+                };
+                pivoted.push(agg.alias(&name));
+            }
+        }
+        let n_keys = keys.len();
+        let mut lf = if keys.is_empty() {
+            lf.select(pivoted)
+        } else {
+            lf.group_by_stable(keys).agg(pivoted)
+        };
+        if let Some(default) = default {
+            let schema = self.get_frame_schema(&mut lf)?;
+            let filled = schema
+                .iter()
+                .skip(n_keys)
+                .map(|(name, dtype)| {
+                    col(name).fill_null(default.clone().strict_cast(dtype.clone()))
+                })
+                .collect::<Vec<_>>();
+            lf = lf.with_columns(filled);
+        }
+        Ok(lf)
+    }
+
+    /// Unpivot the given columns into a name and a value column, dropping null values.
+    fn execute_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let mut on = Vec::with_capacity(columns.len());
+        for c in columns {
+            polars_ensure!(
+                schema.contains(&c.value),
+                SQLInterface: "UNPIVOT column '{}' not found", c.value
+            );
+            on.push(SmartString::from(c.value.as_str()));
+        }
+        let index = schema
+            .iter_names()
+            .filter(|n| !on.contains(n))
+            .cloned()
+            .collect();
+        let args = UnpivotArgs {
+            on,
+            index,
+            variable_name: Some(name.value.as_str().into()),
+            value_name: Some(value.value.as_str().into()),
+            streamable: false,
+        };
+        Ok(lf.unpivot(args).filter(col(&value.value).is_not_null()))
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1055,12 +2197,15 @@ impl SQLContext {
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
         let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
-        let (tbl_name, lf) = read_fn.execute(args)?;
+        let (tbl_name, mut lf) = read_fn.execute(self, args)?;
         #[allow(clippy::useless_asref)]
         let tbl_name = alias
             .as_ref()
             .map(|a| a.name.value.clone())
             .unwrap_or_else(|| tbl_name);
+        if let Some(alias) = alias {
+            lf = self.rename_columns_from_table_alias(lf, alias)?;
+        }
 
         self.table_map.insert(tbl_name.clone(), lf.clone());
         Ok((tbl_name, lf))
@@ -1122,16 +2267,184 @@ impl SQLContext {
         ))
     }
 
+    /// Translate the 'GROUP BY' expressions to the group keys and, if there is a
+    /// 'GROUPING SETS', 'ROLLUP' or 'CUBE' element, to the grouping sets over them.
+    ///
+    /// Every element of the clause is a list of grouping sets, a plain expression
+    /// being a single set. The grouping sets of the clause are the cross product
+    /// of those lists, e.g. 'GROUP BY a, ROLLUP (b, c)' groups by (a, b, c), (a, b)
+    /// and (a).
+    fn process_grouping_sets(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        modifiers: &[GroupByWithModifier],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Option<GroupingSets>)> {
+        let mut keys = vec![];
+        let mut has_grouping_sets = false;
+        let mut element_sets = Vec::with_capacity(group_by_exprs.len());
+        for e in group_by_exprs {
+            let sets = match e {
+                SQLExpr::GroupingSets(sets) => sets
+                    .iter()
+                    .map(|set| self.group_by_key_indices(set, &mut keys, projections, schema))
+                    .collect::<PolarsResult<Vec<_>>>()?,
+                SQLExpr::Rollup(elements) | SQLExpr::Cube(elements) => {
+                    let elements = elements
+                        .iter()
+                        .map(|el| self.group_by_key_indices(el, &mut keys, projections, schema))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let sets = if matches!(e, SQLExpr::Rollup(_)) {
+                        GroupingSets::rollup(elements.len())
+                    } else {
+                        GroupingSets::cube(elements.len())?
+                    };
+                    expand_grouping_sets(&sets, &elements)
+                },
+                SQLExpr::Tuple(exprs) => {
+                    vec![self.group_by_key_indices(exprs, &mut keys, projections, schema)?]
+                },
+                e => vec![self.group_by_key_indices(
+                    std::slice::from_ref(e),
+                    &mut keys,
+                    projections,
+                    schema,
+                )?],
+            };
+            has_grouping_sets |= matches!(
+                e,
+                SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+            );
+            element_sets.push(sets);
+        }
+
+        // "GROUP BY x, y WITH ROLLUP" is the same as "GROUP BY ROLLUP (x, y)".
+        match modifiers {
+            [] => {},
+            [modifier @ (GroupByWithModifier::Rollup | GroupByWithModifier::Cube)]
+                if !has_grouping_sets =>
+            {
+                let elements = element_sets
+                    .into_iter()
+                    .map(|mut sets| sets.pop().unwrap())
+                    .collect::<Vec<_>>();
+                let sets = if matches!(modifier, GroupByWithModifier::Rollup) {
+                    GroupingSets::rollup(elements.len())
+                } else {
+                    GroupingSets::cube(elements.len())?
+                };
+                element_sets = vec![expand_grouping_sets(&sets, &elements)];
+                has_grouping_sets = true;
+            },
+            _ => {
+                let modifiers = modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+                polars_bail!(SQLInterface: "GROUP BY does not support '{}' here", modifiers.join(" "))
+            },
+        }
+        if !has_grouping_sets {
+            return Ok((keys, None));
+        }
+
+        // Check the size of the cross product before building it.
+        let n_sets = element_sets
+            .iter()
+            .try_fold(1usize, |n, sets| n.checked_mul(sets.len()))
+            .filter(|n| *n <= GroupingSets::MAX_SETS);
+        polars_ensure!(
+            n_sets.is_some(),
+            SQLInterface: "GROUP BY supports at most {} grouping sets", GroupingSets::MAX_SETS
+        );
+        let sets = element_sets
+            .into_iter()
+            .fold(vec![vec![]], |acc: Vec<Vec<usize>>, sets| {
+                acc.iter()
+                    .flat_map(|a| {
+                        sets.iter()
+                            .map(move |b| a.iter().chain(b).copied().collect::<Vec<_>>())
+                    })
+                    .collect()
+            });
+        let grouping_sets = GroupingSets::new(sets).with_id_name(GROUPING_ID_NAME);
+        Ok((keys, Some(grouping_sets)))
+    }
+
+    /// Translate group keys, allowing ordinal values, and return their indices in `keys`.
+    fn group_by_key_indices(
+        &mut self,
+        exprs: &[SQLExpr],
+        keys: &mut Vec<Expr>,
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<Vec<usize>> {
+        exprs
+            .iter()
+            .map(|e| {
+                let key = self.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+                Ok(match keys.iter().position(|k| *k == key) {
+                    Some(idx) => idx,
+                    None => {
+                        keys.push(key);
+                        keys.len() - 1
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Register a 'GROUPING' call with the given keys, which is resolved once the
+    /// grouping sets are known.
+    pub(crate) fn register_grouping(&self, keys: Vec<Expr>) -> Expr {
+        let mut calls = self.grouping_calls.borrow_mut();
+        let name = format!("{GROUPING_CALL_PREFIX}{}", calls.len());
+        calls.push(keys);
+        col(&name).alias("grouping")
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
         projections: &[Expr],
+        grouping_sets: Option<GroupingSets>,
+        grouping_calls: &[Vec<Expr>],
     ) -> PolarsResult<LazyFrame> {
         let schema_before = self.get_frame_schema(&mut lf)?;
         let group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
+        // Resolve the 'GROUPING' calls to the indices of their keys.
+        let mut grouping_exprs = Vec::with_capacity(grouping_calls.len());
+        for (n, args) in grouping_calls.iter().enumerate() {
+            let indices = args
+                .iter()
+                .map(|arg| {
+                    let name = arg.to_field(&schema_before, Context::Default)?.name;
+                    group_by_keys_schema.index_of(&name).ok_or_else(
+                        || polars_err!(SQLSyntax: "GROUPING argument '{}' is not a GROUP BY key", name),
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let expr = match &grouping_sets {
+                Some(grouping_sets) => grouping_sets.grouping(&indices),
+                // Without grouping sets all keys are part of every group.
+                None => typed_lit(0u32),
+            };
+            grouping_exprs.push(expr.alias(&format!("{GROUPING_CALL_PREFIX}{n}")));
+        }
+        let schema_before = if grouping_exprs.is_empty() {
+            schema_before
+        } else {
+            let mut schema = schema_before.as_ref().clone();
+            for n in 0..grouping_exprs.len() {
+                schema.with_column(
+                    format!("{GROUPING_CALL_PREFIX}{n}").into(),
+                    DataType::UInt32,
+                );
+            }
+            Arc::new(schema)
+        };
+
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
         let mut projection_overrides = PlHashMap::with_capacity(projections.len());
@@ -1177,12 +2490,22 @@ impl SQLContext {
             } = e
             {
                 // Non-aggregated columns must be part of the GROUP BY clause
-                if !group_by_keys_schema.contains(&field.name) {
+                if !group_by_keys_schema.contains(&field.name)
+                    && !field.name.starts_with(GROUPING_CALL_PREFIX)
+                {
                     polars_bail!(SQLSyntax: "'{}' should participate in the GROUP BY clause or an aggregate function", &field.name);
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let mut aggregated = match grouping_sets {
+            Some(grouping_sets) => lf
+                .group_by_grouping_sets(group_by_keys, grouping_sets)
+                .agg(&aggregation_projection),
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
+        if !grouping_exprs.is_empty() {
+            aggregated = aggregated.with_columns(grouping_exprs);
+        }
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1501,3 +2824,107 @@ fn process_join_constraint(
         polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint);
     }
 }
+
+/// Expand grouping sets over the elements of a 'ROLLUP' or 'CUBE' to grouping
+/// sets over the keys of those elements.
+fn expand_grouping_sets(sets: &GroupingSets, elements: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    sets.sets
+        .iter()
+        .map(|set| set.iter().flat_map(|i| elements[*i].clone()).collect())
+        .collect()
+}
+
+/// Select the columns of a schema, in order.
+fn column_exprs(schema: &Schema) -> Vec<Expr> {
+    schema.iter_names().map(|name| col(name)).collect()
+}
+
+/// Split the body of a recursive CTE into its non-recursive (anchor) and recursive terms.
+///
+/// Returns `None` if the CTE does not reference itself, in which case it is evaluated
+/// like any other CTE.
+fn recursive_cte_terms(cte: &Cte, recursive: bool) -> Option<(&SetExpr, &SetExpr, &SetQuantifier)> {
+    match cte.query.body.as_ref() {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } if recursive && references_table(right, &cte.alias.name.value) => {
+            Some((left, right, set_quantifier))
+        },
+        _ => None,
+    }
+}
+
+/// The rows a recursive CTE produced in its previous iteration, which its recursive term
+/// reads under the name of the CTE.
+struct WorkingTable {
+    schema: SchemaRef,
+    df: Mutex<DataFrame>,
+}
+
+impl AnonymousScan for WorkingTable {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        Ok(self.df.lock().unwrap().clone())
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+/// Remove the rows that are in `seen` (or repeated in `df`) and add the others to it.
+fn remove_seen_rows(
+    df: DataFrame,
+    seen: Option<&mut PlHashSet<Vec<u8>>>,
+) -> PolarsResult<DataFrame> {
+    let Some(seen) = seen else {
+        return Ok(df);
+    };
+    let n = df.width();
+    let rows = _get_rows_encoded_ca("", df.get_columns(), &vec![false; n], &vec![false; n])?;
+    let mask: BooleanChunked = rows
+        .into_no_null_iter()
+        .map(|row| seen.insert(row.to_vec()))
+        .collect();
+    df.filter(&mask)
+}
+
+/// Whether a table with the given name is referenced in the FROM clauses of a query.
+fn references_table(expr: &SetExpr, name: &str) -> bool {
+    fn factor_references(factor: &TableFactor, name: &str) -> bool {
+        match factor {
+            TableFactor::Table { name: tbl, .. } => {
+                tbl.0.first().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => references_table(&subquery.body, name),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => from_references(table_with_joins, name),
+            TableFactor::Pivot { table, .. } | TableFactor::Unpivot { table, .. } => {
+                factor_references(table, name)
+            },
+            _ => false,
+        }
+    }
+    fn from_references(tbl: &TableWithJoins, name: &str) -> bool {
+        factor_references(&tbl.relation, name)
+            || tbl
+                .joins
+                .iter()
+                .any(|join| factor_references(&join.relation, name))
+    }
+    match expr {
+        SetExpr::Select(select) => select.from.iter().any(|tbl| from_references(tbl, name)),
+        SetExpr::Query(query) => references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_table(left, name) || references_table(right, name)
+        },
+        _ => false,
+    }
+}
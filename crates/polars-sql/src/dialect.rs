@@ -0,0 +1,73 @@
+use std::any::TypeId;
+
+use sqlparser::dialect::{Dialect, GenericDialect};
+
+/// The SQL dialect of the Polars SQL interface.
+///
+/// This is the [`GenericDialect`], extended with the syntax it lacks but that
+/// Polars supports, such as `agg(...) FILTER (WHERE ...)`.
+#[derive(Debug, Default)]
+pub(crate) struct PolarsDialect;
+
+impl Dialect for PolarsDialect {
+    // Parse like the generic dialect wherever the parser checks for it.
+    fn dialect(&self) -> TypeId {
+        GenericDialect.dialect()
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        GenericDialect.is_delimited_identifier_start(ch)
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        GenericDialect.is_identifier_start(ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        GenericDialect.is_identifier_part(ch)
+    }
+
+    fn supports_filter_during_aggregation(&self) -> bool {
+        true
+    }
+
+    fn supports_group_by_expr(&self) -> bool {
+        GenericDialect.supports_group_by_expr()
+    }
+
+    fn supports_connect_by(&self) -> bool {
+        GenericDialect.supports_connect_by()
+    }
+
+    fn supports_match_recognize(&self) -> bool {
+        GenericDialect.supports_match_recognize()
+    }
+
+    fn supports_start_transaction_modifier(&self) -> bool {
+        GenericDialect.supports_start_transaction_modifier()
+    }
+
+    fn supports_window_function_null_treatment_arg(&self) -> bool {
+        GenericDialect.supports_window_function_null_treatment_arg()
+    }
+
+    fn supports_dictionary_syntax(&self) -> bool {
+        GenericDialect.supports_dictionary_syntax()
+    }
+
+    fn supports_window_clause_named_window_reference(&self) -> bool {
+        GenericDialect.supports_window_clause_named_window_reference()
+    }
+
+    fn supports_parenthesized_set_variables(&self) -> bool {
+        GenericDialect.supports_parenthesized_set_variables()
+    }
+
+    fn supports_select_wildcard_except(&self) -> bool {
+        GenericDialect.supports_select_wildcard_except()
+    }
+
+    fn support_map_literal_syntax(&self) -> bool {
+        GenericDialect.support_map_literal_syntax()
+    }
+}
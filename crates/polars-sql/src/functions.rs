@@ -2,21 +2,32 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::export::regex;
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult, Schema, TimeUnit};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, IdxSize, PolarsResult,
+    RollingOptionsFixedWindow, Schema, TimeUnit, IDX_DTYPE,
+};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    arg_sort_by, coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+    WindowMapping,
+};
 use polars_plan::plans::{typed_lit, LiteralValue};
 use polars_plan::prelude::LiteralValue::Null;
 use polars_plan::prelude::{col, cols, lit, StrptimeOptions};
+use polars_time::prelude::{ClosedWindow, RollingOptionsDynamicWindow};
+use polars_time::Duration;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, filter_aggregation, has_aggregation, parse_extract_date_part,
+    parse_sql_expr,
+};
 use crate::SQLContext;
 
 pub(crate) struct SQLFunctionVisitor<'a> {
@@ -485,6 +496,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bit per argument that is set if the argument is not part of the
+    /// grouping set of the row, with the first argument as most significant bit.
+    /// ```sql
+    /// SELECT GROUPING(column_1, column_2) FROM df GROUP BY ROLLUP (column_1, column_2);
+    /// ```
+    Grouping,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -528,6 +546,73 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// Returns the number of the row within its partition, counting from 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// Returns the rank of the row within its partition, with gaps after ties.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the row within its partition, without gaps after ties.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'percent_rank' function
+    /// Returns the relative rank of the row within its partition, from 0 to 1.
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    PercentRank,
+    /// SQL 'ntile' function
+    /// Divides the rows of the partition into the given number of buckets of
+    /// (nearly) equal size, and returns the bucket of the row counting from 1.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    NTile,
+    /// SQL 'lag' function
+    /// Returns the value of the row that is `offset` rows (default 1) before the
+    /// row within its partition, or the default value (default NULL) if there is none.
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the row that is `offset` rows (default 1) after the
+    /// row within its partition, or the default value (default NULL) if there is none.
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function
+    /// Returns the value of the first row of the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function
+    /// Returns the value of the last row of the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'nth_value' function
+    /// Returns the value of the n-th row of the window frame, counting from 1.
+    /// ```sql
+    /// SELECT NTH_VALUE(column_1, 2) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    NthValue,
+
     // ----
     // Array functions
     // ----
@@ -657,15 +742,21 @@ impl PolarsSQLFunctions {
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -680,17 +771,22 @@ impl PolarsSQLFunctions {
             "median",
             "min",
             "mod",
+            "nth_value",
+            "ntile",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -814,6 +910,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -822,6 +919,20 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "percent_rank" => Self::PercentRank,
+            "ntile" => Self::NTile,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+            "nth_value" => Self::NthValue,
+
             // ----
             // Array functions
             // ----
@@ -864,8 +975,8 @@ impl SQLFunctionVisitor<'_> {
         if !function.within_group.is_empty() {
             polars_bail!(SQLInterface: "'WITHIN GROUP' is not currently supported")
         }
-        if function.filter.is_some() {
-            polars_bail!(SQLInterface: "'FILTER' is not currently supported")
+        if let Some(filter) = &function.filter {
+            return self.visit_filtered_aggregate(filter, matches!(function_name, Count));
         }
         if function.null_treatment.is_some() {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
@@ -1236,16 +1347,81 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_aggregate(FrameAggregate::Mean),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => {
+                let args = extract_args(function)?;
+                polars_ensure!(!args.is_empty(), SQLSyntax: "GROUPING expects at least 1 argument");
+                let mut keys = Vec::with_capacity(args.len());
+                for arg in args {
+                    if let FunctionArgExpr::Expr(sql_expr) = arg {
+                        keys.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?);
+                    } else {
+                        return self.not_supported_error();
+                    };
+                }
+                Ok(self.ctx.register_grouping(keys))
+            },
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
-            Median => self.visit_unary(Expr::median),
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
-            StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
-            Variance => self.visit_unary(|e| e.var(1)),
+            Max => self.visit_aggregate(FrameAggregate::Max),
+            Median => self.visit_aggregate(FrameAggregate::Median),
+            Min => self.visit_aggregate(FrameAggregate::Min),
+            StdDev => self.visit_aggregate(FrameAggregate::Std),
+            Sum => self.visit_aggregate(FrameAggregate::Sum),
+            Variance => self.visit_aggregate(FrameAggregate::Var),
+
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_window_function(0, 0, |w, _| Ok(w.row_number())),
+            Rank => self.visit_window_function(0, 0, |w, _| Ok(w.rank())),
+            DenseRank => self.visit_window_function(0, 0, |w, _| {
+                Ok(w.is_peer_start().cast(IDX_DTYPE).cum_sum(false))
+            }),
+            PercentRank => self.visit_window_function(0, 0, |w, _| {
+                let rank = (w.rank() - typed_lit(1 as IdxSize)).cast(DataType::Float64);
+                Ok(when(len().gt(typed_lit(1 as IdxSize)))
+                    .then(rank / (len() - typed_lit(1 as IdxSize)).cast(DataType::Float64))
+                    .otherwise(lit(0.0)))
+            }),
+            NTile => self.visit_window_function(1, 1, |_, args| {
+                let n = window_int_arg("NTILE", &args[0], 1)?;
+                // The first `len % n` buckets have one row more than the others.
+                let i = WindowPartition::row_index().cast(DataType::Int64);
+                let size = len().cast(DataType::Int64).floor_div(lit(n));
+                let n_larger = len().cast(DataType::Int64) % lit(n);
+                let larger_rows = n_larger.clone() * (size.clone() + lit(1));
+                Ok(when(i.clone().lt(larger_rows.clone()))
+                    .then(i.clone().floor_div(size.clone() + lit(1)))
+                    .otherwise((i - larger_rows).floor_div(size.clip_min(lit(1))) + n_larger)
+                    + lit(1))
+            }),
+            Lag => self.visit_window_function(1, 3, |w, args| w.offset_value("LAG", args, false)),
+            Lead => self.visit_window_function(1, 3, |w, args| w.offset_value("LEAD", args, true)),
+            FirstValue => self.visit_window_function(1, 1, |w, args| {
+                w.frame_is_partition("FIRST_VALUE")?;
+                Ok(args[0].clone().first())
+            }),
+            LastValue => self.visit_window_function(1, 1, |w, args| {
+                let e = args[0].clone();
+                Ok(if w.frame_is_partition("LAST_VALUE")? {
+                    e.last()
+                } else {
+                    e.gather(w.last_peer_index())
+                })
+            }),
+            NthValue => self.visit_window_function(2, 2, |w, args| {
+                let n = window_int_arg("NTH_VALUE", &args[1], 1)?;
+                let nth = args[0].clone().slice(lit(n - 1), lit(1)).first();
+                Ok(if w.frame_is_partition("NTH_VALUE")? {
+                    nth
+                } else {
+                    when(w.last_peer_index().gt_eq(lit(n - 1)))
+                        .then(nth)
+                        .otherwise(lit(Null))
+                })
+            }),
 
             // ----
             // Array functions
@@ -1390,7 +1566,7 @@ impl SQLFunctionVisitor<'_> {
             )?),
             _ => self.not_supported_error(),
         }
-        .and_then(|e| self.apply_window_spec(e, &self.func.over))
+        .and_then(|e| self.apply_window_spec(e))
     }
 
     /// Some functions have cumulative equivalents that can be applied to window specs
@@ -1403,15 +1579,27 @@ impl SQLFunctionVisitor<'_> {
         f: impl Fn(Expr) -> Expr,
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+        match self.window_spec()? {
+            Some(spec) => self.apply_cumulative_window(f, cumulative_f, &spec),
+            None => self.visit_unary(f),
+        }
+    }
+
+    /// Aggregates are evaluated over the window frame of every row if the
+    /// `OVER` clause has a frame clause, e.g. `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`.
+    fn visit_aggregate(&mut self, agg: FrameAggregate) -> PolarsResult<Expr> {
+        let has_frame = matches!(self.window_spec()?, Some(spec) if spec.window_frame.is_some());
+        match agg {
+            _ if has_frame => {
+                let function_name = self.func.name.to_string().to_uppercase();
+                self.visit_window_function(1, 1, |w, args| {
+                    w.frame_aggregate(&function_name, agg, args[0].clone())
+                })
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
+            FrameAggregate::Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            FrameAggregate::Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            FrameAggregate::Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            _ => self.visit_unary(|e| agg.aggregate(e)),
         }
     }
 
@@ -1562,25 +1750,137 @@ impl SQLFunctionVisitor<'_> {
 
     fn visit_count(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
+        if matches!(self.window_spec()?, Some(spec) if spec.window_frame.is_some()) {
+            return match (is_distinct, args.as_slice()) {
+                // count(*) over a frame counts the rows of the frame.
+                (false, [FunctionArgExpr::Wildcard] | []) => {
+                    let function_name = self.func.name.to_string().to_uppercase();
+                    let no_args = SQLFunction {
+                        args: FunctionArguments::None,
+                        ..self.func.clone()
+                    };
+                    SQLFunctionVisitor {
+                        func: &no_args,
+                        ctx: self.ctx,
+                        active_schema: self.active_schema,
+                    }
+                    .visit_window_function(0, 0, |w, _| {
+                        w.frame_aggregate(
+                            &function_name,
+                            FrameAggregate::Count,
+                            WindowPartition::row_index(),
+                        )
+                    })
+                },
+                (false, [FunctionArgExpr::Expr(_)]) => self.visit_aggregate(FrameAggregate::Count),
+                (true, _) => {
+                    polars_bail!(SQLInterface: "COUNT(DISTINCT) does not support a window frame")
+                },
+                _ => self.not_supported_error(),
+            };
+        }
         match (is_distinct, args.as_slice()) {
             // count(*), count()
             (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
+                let expr = self.apply_window_spec(expr)?;
                 Ok(expr.count())
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
+                let expr = self.apply_window_spec(expr)?;
                 Ok(expr.clone().n_unique().sub(expr.null_count().gt(lit(0))))
             },
             _ => self.not_supported_error(),
         }
     }
 
+    /// Window functions are evaluated on the rows of every partition of the
+    /// `OVER` clause, in the order of its `ORDER BY`.
+    fn visit_window_function(
+        &mut self,
+        min_args: usize,
+        max_args: usize,
+        f: impl Fn(&WindowPartition, &[Expr]) -> PolarsResult<Expr>,
+    ) -> PolarsResult<Expr> {
+        let function_name = self.func.name.to_string().to_uppercase();
+        let Some(spec) = self.window_spec()? else {
+            polars_bail!(SQLSyntax: "{} requires an OVER clause", function_name)
+        };
+        let args = extract_args(self.func)?
+            .into_iter()
+            .map(|arg| match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    parse_sql_expr(sql_expr, self.ctx, self.active_schema)
+                },
+                _ => self.not_supported_error(),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if args.len() < min_args || args.len() > max_args {
+            let expected = if min_args == max_args {
+                format!("{}", min_args)
+            } else {
+                format!("{}-{}", min_args, max_args)
+            };
+            polars_bail!(SQLSyntax: "{} expects {} arguments (found {})", function_name, expected, args.len())
+        }
+
+        let mut partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if partition_by.is_empty() {
+            // A single partition with all rows.
+            partition_by.push(lit(0));
+        }
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut descending = Vec::with_capacity(spec.order_by.len());
+        let mut nulls_last = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+            let desc_order = !ob.asc.unwrap_or(true);
+            order_by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+            nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+            descending.push(desc_order);
+        }
+        // A window is ordered by a single expression, so multiple sort keys are
+        // replaced by the position of the row in the sorted frame.
+        let window_order = match order_by.as_slice() {
+            [] => None,
+            [by] => Some((
+                vec![by.clone()],
+                SortOptions::default()
+                    .with_order_descending(descending[0])
+                    .with_nulls_last(nulls_last[0]),
+            )),
+            _ => Some((
+                vec![arg_sort_by(
+                    &order_by,
+                    SortMultipleOptions::default()
+                        .with_order_descending_multi(descending.clone())
+                        .with_nulls_last_multi(nulls_last)
+                        .with_maintain_order(true),
+                )
+                .arg_sort(SortOptions::default())],
+                SortOptions::default(),
+            )),
+        };
+
+        let expr = f(
+            &WindowPartition {
+                order_by,
+                descending,
+                frame: spec.window_frame,
+            },
+            &args,
+        )?;
+        Ok(expr.over_with_options(partition_by, window_order, WindowMapping::GroupsToRows))
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
@@ -1603,13 +1903,51 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
-    fn apply_window_spec(
-        &mut self,
-        expr: Expr,
-        window_type: &Option<WindowType>,
-    ) -> PolarsResult<Expr> {
-        Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
+    /// Aggregate only the rows for which the `FILTER (WHERE ...)` clause is true.
+    fn visit_filtered_aggregate(&mut self, filter: &SQLExpr, is_count: bool) -> PolarsResult<Expr> {
+        if let Some(spec) = self.window_spec()? {
+            polars_ensure!(
+                spec.order_by.is_empty() && spec.window_frame.is_none(),
+                SQLInterface: "'FILTER' is not supported for window functions with ORDER BY or a frame"
+            );
+        }
+        let predicate = parse_sql_expr(filter, self.ctx, self.active_schema)?;
+        let unfiltered = SQLFunction {
+            filter: None,
+            ..self.func.clone()
+        };
+        let expr = SQLFunctionVisitor {
+            func: &unfiltered,
+            ctx: self.ctx,
+            active_schema: self.active_schema,
+        }
+        .visit_function()?;
+        polars_ensure!(
+            has_aggregation(&expr),
+            SQLInterface: "'FILTER' is only supported for aggregate functions, found {}", self.func
+        );
+        Ok(filter_aggregation(expr, &predicate, is_count))
+    }
+
+    /// The window of the `OVER` clause, with a reference to a named window resolved.
+    fn window_spec(&self) -> PolarsResult<Option<WindowSpec>> {
+        self.func
+            .over
+            .as_ref()
+            .map(|window| self.ctx.resolve_window(window))
+            .transpose()
+    }
+
+    fn apply_window_spec(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        Ok(match self.window_spec()? {
+            Some(window_spec) => {
+                // Only the aggregates of `visit_aggregate` honour a frame.
+                if let Some(frame) = &window_spec.window_frame {
+                    polars_bail!(
+                        SQLInterface: "{} does not support the window frame '{}'",
+                        self.func.name.to_string().to_uppercase(), display_frame(frame)
+                    )
+                }
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1632,10 +1970,6 @@ impl SQLFunctionVisitor<'_> {
                     expr.over(partition_by)
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             None => expr,
         })
     }
@@ -1649,6 +1983,344 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The rows of a window partition, in the order of the `ORDER BY` of the window.
+/// Rows with equal `ORDER BY` values are peers, they have the same rank.
+struct WindowPartition {
+    order_by: Vec<Expr>,
+    descending: Vec<bool>,
+    frame: Option<WindowFrame>,
+}
+
+impl WindowPartition {
+    /// The position of the row in the partition, counting from 0.
+    fn row_index() -> Expr {
+        int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE)
+    }
+
+    fn row_number(&self) -> Expr {
+        Self::row_index() + typed_lit(1 as IdxSize)
+    }
+
+    /// Whether the row is the first of its peers.
+    fn is_peer_start(&self) -> Expr {
+        self.order_by.iter().fold(
+            Self::row_index().eq(typed_lit(0 as IdxSize)),
+            |is_start, e| is_start.or(e.clone().neq_missing(e.clone().shift(lit(1)))),
+        )
+    }
+
+    /// The row number of the first peer of the row.
+    fn rank(&self) -> Expr {
+        when(self.is_peer_start())
+            .then(self.row_number())
+            .otherwise(lit(Null))
+            .forward_fill(None)
+    }
+
+    /// The position of the first peer of the row.
+    fn first_peer_index(&self) -> Expr {
+        self.rank() - typed_lit(1 as IdxSize)
+    }
+
+    /// The position of the last peer of the row, where the default window frame ends.
+    fn last_peer_index(&self) -> Expr {
+        let is_peer_end = self.is_peer_start().shift(lit(-1)).fill_null(lit(true));
+        when(is_peer_end)
+            .then(Self::row_index())
+            .otherwise(lit(Null))
+            .backward_fill(None)
+    }
+
+    /// Whether the window frame of every row is the whole partition, rather
+    /// than the default frame that ends at the last peer of the row. Other
+    /// frames are not supported by the functions that return a value of the frame.
+    fn frame_is_partition(&self, function_name: &str) -> PolarsResult<bool> {
+        let Some(frame) = &self.frame else {
+            return Ok(self.order_by.is_empty());
+        };
+        let end_bound = frame.end_bound.as_ref();
+        match (&frame.units, &frame.start_bound, end_bound) {
+            (_, WindowFrameBound::Preceding(None), Some(WindowFrameBound::Following(None))) => {
+                Ok(true)
+            },
+            (
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(None),
+                None | Some(WindowFrameBound::CurrentRow),
+            ) => Ok(self.order_by.is_empty()),
+            _ => polars_bail!(
+                SQLInterface: "{} does not support the window frame '{}'", function_name, display_frame(frame)
+            ),
+        }
+    }
+
+    /// The aggregate of `e` over the window frame of every row.
+    fn frame_aggregate(
+        &self,
+        function_name: &str,
+        agg: FrameAggregate,
+        e: Expr,
+    ) -> PolarsResult<Expr> {
+        use WindowFrameBound::*;
+        let frame = self.frame.as_ref().unwrap();
+        let end_bound = frame.end_bound.clone().unwrap_or(CurrentRow);
+        let unsupported = || {
+            polars_err!(
+                SQLInterface: "{} does not support the window frame '{}'", function_name, display_frame(frame)
+            )
+        };
+        match frame.units {
+            WindowFrameUnits::Rows => {
+                if matches!(frame.start_bound, Following(None))
+                    || matches!(end_bound, Preceding(None))
+                {
+                    polars_bail!(SQLSyntax: "invalid window frame '{}'", display_frame(frame))
+                }
+                let start = rows_frame_offset(&frame.start_bound)?;
+                let end = rows_frame_offset(&end_bound)?;
+                if let (Some(start), Some(end)) = (start, end) {
+                    polars_ensure!(start <= end, SQLSyntax: "invalid window frame '{}'", display_frame(frame));
+                }
+                let out = Self::rows_frame_aggregate(agg, e, start, end).ok_or_else(unsupported)?;
+                // A frame without rows counts 0 rows, the other aggregates of it are null.
+                Ok(match agg {
+                    FrameAggregate::Count => out.fill_null(typed_lit(0 as IdxSize)),
+                    _ => out,
+                })
+            },
+            WindowFrameUnits::Range => match (&frame.start_bound, &end_bound) {
+                (Preceding(None), Following(None)) => Ok(agg.aggregate(e)),
+                (Preceding(None), CurrentRow) => Ok(agg
+                    .cumulative(e, false)
+                    .ok_or_else(unsupported)?
+                    .gather(self.last_peer_index())),
+                (CurrentRow, Following(None)) => Ok(agg
+                    .cumulative(e, true)
+                    .ok_or_else(unsupported)?
+                    .gather(self.first_peer_index())),
+                (Preceding(Some(offset)), CurrentRow) => {
+                    let ([by], [false]) = (self.order_by.as_slice(), self.descending.as_slice())
+                    else {
+                        polars_bail!(
+                            SQLSyntax: "a RANGE frame with an offset requires a single ascending ORDER BY expression"
+                        )
+                    };
+                    let options = RollingOptionsDynamicWindow {
+                        window_size: range_frame_offset(offset)?,
+                        min_periods: 1,
+                        closed_window: ClosedWindow::Both,
+                        fn_params: None,
+                    };
+                    // A row's frame also includes the peers that follow it.
+                    Ok(agg
+                        .rolling_by(e, by.clone(), options)
+                        .gather(self.last_peer_index()))
+                },
+                _ => Err(unsupported()),
+            },
+            WindowFrameUnits::Groups => Err(unsupported()),
+        }
+    }
+
+    /// The aggregate over the `ROWS` frame from `start` to `end` rows after the
+    /// row (before it if negative), `None` being the start or the end of the partition.
+    fn rows_frame_aggregate(
+        agg: FrameAggregate,
+        e: Expr,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Option<Expr> {
+        let row_index = Self::row_index().cast(DataType::Int64);
+        let n_rows = len().cast(DataType::Int64);
+        Some(match (start, end) {
+            (None, None) => agg.aggregate(e),
+            (None, Some(end)) if end > 0 => {
+                let cumulative = agg.cumulative(e, false)?;
+                when((row_index + lit(end)).lt(n_rows))
+                    .then(cumulative.clone().shift(lit(-end)))
+                    .otherwise(cumulative.last())
+            },
+            (None, Some(end)) => agg.cumulative(e, false)?.shift(lit(-end)),
+            // Reverse the partition, so that the frame starts at its start.
+            (Some(start), None) => {
+                Self::rows_frame_aggregate(agg, e.reverse(), None, Some(-start))?.reverse()
+            },
+            (Some(start), Some(end)) => {
+                let options = RollingOptionsFixedWindow {
+                    window_size: (end - start + 1) as usize,
+                    min_periods: 1,
+                    ..Default::default()
+                };
+                // The aggregate of the frame that ends at row `i + end`.
+                let rolling = agg.rolling(e.clone(), options.clone());
+                if end <= 0 {
+                    return Some(rolling.shift(lit(-end)));
+                }
+                // The frames that are truncated at the end of the partition are the
+                // frames that start at row `i + start` of the reversed partition,
+                // or the whole partition if they are truncated at both ends.
+                let rolling_reverse = agg.rolling(e.reverse(), options).reverse();
+                when((row_index.clone() + lit(end)).lt(n_rows))
+                    .then(rolling.clone().shift(lit(-end)))
+                    .when((row_index + lit(start)).gt_eq(lit(0)))
+                    .then(rolling_reverse.shift(lit(-start)))
+                    .otherwise(rolling.last())
+            },
+        })
+    }
+
+    /// The value `offset` rows after (or before) the row, `LEAD` and `LAG`.
+    fn offset_value(&self, function_name: &str, args: &[Expr], after: bool) -> PolarsResult<Expr> {
+        let offset = match args.get(1) {
+            Some(offset) => window_int_arg(function_name, offset, 0)?,
+            None => 1,
+        };
+        let value = args[0]
+            .clone()
+            .shift(lit(if after { -offset } else { offset }));
+        Ok(match args.get(2) {
+            Some(default) => {
+                let has_value = if after {
+                    (Self::row_index() + lit(offset)).lt(len())
+                } else {
+                    Self::row_index().gt_eq(lit(offset))
+                };
+                when(has_value).then(value).otherwise(default.clone())
+            },
+            None => value,
+        })
+    }
+}
+
+/// An aggregate function that can be evaluated over a window frame.
+#[derive(Clone, Copy)]
+enum FrameAggregate {
+    Count,
+    Max,
+    Mean,
+    Median,
+    Min,
+    Std,
+    Sum,
+    Var,
+}
+
+impl FrameAggregate {
+    fn aggregate(self, e: Expr) -> Expr {
+        match self {
+            Self::Count => e.count(),
+            Self::Max => e.max(),
+            Self::Mean => e.mean(),
+            Self::Median => e.median(),
+            Self::Min => e.min(),
+            Self::Std => e.std(1),
+            Self::Sum => e.sum(),
+            Self::Var => e.var(1),
+        }
+    }
+
+    /// The aggregate of the values up to every value (or from it, if `reverse`),
+    /// `None` if there is no cumulative equivalent of the aggregate.
+    fn cumulative(self, e: Expr, reverse: bool) -> Option<Expr> {
+        let out = match self {
+            Self::Count => e.cum_count(reverse),
+            Self::Max => e.cum_max(reverse),
+            Self::Mean => e.clone().cum_sum(reverse).cast(DataType::Float64) / e.cum_count(reverse),
+            Self::Min => e.cum_min(reverse),
+            Self::Sum => e.cum_sum(reverse),
+            Self::Median | Self::Std | Self::Var => return None,
+        };
+        // The cumulative aggregates are null where the values are null.
+        Some(if reverse {
+            out.backward_fill(None)
+        } else {
+            out.forward_fill(None)
+        })
+    }
+
+    fn rolling(self, e: Expr, options: RollingOptionsFixedWindow) -> Expr {
+        match self {
+            // The number of non-null values is the sum of their indicators.
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+            Self::Max => e.rolling_max(options),
+            Self::Mean => e.rolling_mean(options),
+            Self::Median => e.rolling_median(options),
+            Self::Min => e.rolling_min(options),
+            Self::Std => e.rolling_std(options),
+            Self::Sum => e.rolling_sum(options),
+            Self::Var => e.rolling_var(options),
+        }
+    }
+
+    fn rolling_by(self, e: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        match self {
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum_by(by, options),
+            Self::Max => e.rolling_max_by(by, options),
+            Self::Mean => e.rolling_mean_by(by, options),
+            Self::Median => e.rolling_median_by(by, options),
+            Self::Min => e.rolling_min_by(by, options),
+            Self::Std => e.rolling_std_by(by, options),
+            Self::Sum => e.rolling_sum_by(by, options),
+            Self::Var => e.rolling_var_by(by, options),
+        }
+    }
+}
+
+fn display_frame(frame: &WindowFrame) -> String {
+    match &frame.end_bound {
+        Some(end_bound) => format!(
+            "{} BETWEEN {} AND {}",
+            frame.units, frame.start_bound, end_bound
+        ),
+        None => format!("{} {}", frame.units, frame.start_bound),
+    }
+}
+
+/// The offset of a bound of a `ROWS` frame from the row, `None` if it is unbounded.
+fn rows_frame_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    let parse = |offset: &SQLExpr| {
+        match offset {
+            SQLExpr::Value(SQLValue::Number(n, _)) => n.parse::<i64>().map_err(|_| ()),
+            _ => Err(()),
+        }
+        .map_err(|_| polars_err!(SQLSyntax: "invalid ROWS frame offset ({})", offset))
+    };
+    Ok(match bound {
+        WindowFrameBound::CurrentRow => Some(0),
+        WindowFrameBound::Preceding(offset) => {
+            offset.as_deref().map(parse).transpose()?.map(|n| -n)
+        },
+        WindowFrameBound::Following(offset) => offset.as_deref().map(parse).transpose()?,
+    })
+}
+
+/// The offset of a bound of a `RANGE` frame from the row, e.g. `INTERVAL '7 days'`.
+fn range_frame_offset(offset: &SQLExpr) -> PolarsResult<Duration> {
+    match offset {
+        SQLExpr::Interval(interval)
+            if interval.leading_field.is_none() && interval.last_field.is_none() =>
+        {
+            match &*interval.value {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) if !s.contains('-') => {
+                    Ok(Duration::parse_interval(s))
+                },
+                _ => polars_bail!(SQLSyntax: "invalid RANGE frame offset ({})", offset),
+            }
+        },
+        _ => polars_bail!(
+            SQLInterface: "RANGE frame offsets are only supported as intervals; found {}", offset
+        ),
+    }
+}
+
+/// An integer literal argument of a window function of at least `min`.
+fn window_int_arg(function_name: &str, arg: &Expr, min: i128) -> PolarsResult<i64> {
+    match arg {
+        Expr::Literal(LiteralValue::Int(n)) if *n >= min => Ok(*n as i64),
+        _ => polars_bail!(SQLSyntax: "invalid argument for {} ({:?})", function_name, arg),
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -1,7 +1,9 @@
 //! Polars SQL
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
+pub mod catalog;
 mod context;
+mod dialect;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
@@ -4,8 +4,9 @@ use std::ops::Div;
 use polars_core::export::regex;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
-use polars_plan::prelude::typed_lit;
 use polars_plan::prelude::LiteralValue::Null;
+use polars_plan::prelude::{typed_lit, Context};
+use polars_plan::utils::has_expr;
 use polars_time::Duration;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
@@ -16,16 +17,21 @@ use serde::{Deserialize, Serialize};
 use sqlparser::ast::ExactNumberInfo;
 use sqlparser::ast::{
     ArrayElemTypeDef, BinaryOperator as SQLBinaryOperator, BinaryOperator, CastFormat, CastKind,
-    DataType as SQLDataType, DateTimeField, Expr as SQLExpr, Function as SQLFunction, Ident,
-    Interval, ObjectName, Query as Subquery, SelectItem, Subscript, TimezoneInfo, TrimWhereField,
-    UnaryOperator, Value as SQLValue,
+    DataType as SQLDataType, DateTimeField, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
+    FunctionArgExpr, FunctionArguments, GroupByExpr, Ident, Interval, ObjectName,
+    Query as Subquery, Select, SelectItem, SetExpr, Subscript, TableFactor, TableWithJoins,
+    TimezoneInfo, TrimWhereField, UnaryOperator, Value as SQLValue,
 };
-use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
+use crate::context::SubqueryJoin;
+use crate::dialect::PolarsDialect;
 use crate::functions::SQLFunctionVisitor;
 use crate::SQLContext;
 
+/// Prefix of the columns that hold the results of subqueries.
+const SUBQUERY_PREFIX: &str = "__POLARS_SUBQUERY_";
+
 static DATETIME_LITERAL_RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
 static DATE_LITERAL_RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
 static TIME_LITERAL_RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
@@ -222,6 +228,53 @@ pub enum SubqueryRestriction {
     // Any
 }
 
+/// A correlated subquery, split into the inner query without its correlated
+/// predicates and the equi-join keys that correlate it with the outer query.
+struct CorrelatedSubquery<'q> {
+    select: &'q Select,
+    frame: LazyFrame,
+    schema: SchemaRef,
+    outer_keys: Vec<Expr>,
+    inner_keys: Vec<Expr>,
+}
+
+impl<'q> CorrelatedSubquery<'q> {
+    /// Select the (uniquely named) join keys of the inner query, with `exprs`.
+    fn select_keys(&self, name: &str, exprs: Vec<Expr>) -> (LazyFrame, Vec<Expr>) {
+        let key_names = (0..self.inner_keys.len())
+            .map(|i| format!("{name}_KEY_{i}"))
+            .collect::<Vec<_>>();
+        let mut keys = self
+            .inner_keys
+            .iter()
+            .zip(&key_names)
+            .map(|(key, key_name)| key.clone().alias(key_name))
+            .collect::<Vec<_>>();
+        let right_on: Vec<_> = key_names.iter().map(|name| col(name)).collect();
+        let frame = if exprs.is_empty() || exprs.iter().any(has_aggregation) {
+            self.frame.clone().group_by(keys).agg(exprs)
+        } else {
+            keys.extend(exprs);
+            self.frame.clone().select(keys)
+        };
+        // Null keys never match, and would fail the validation of the join.
+        let frame = frame.drop_nulls(Some(right_on.clone()));
+        (frame, right_on)
+    }
+
+    /// The subquery without the value of an `IN` subquery, which is its last key.
+    fn without_in_value(&self) -> CorrelatedSubquery<'q> {
+        let n_keys = self.inner_keys.len() - 1;
+        CorrelatedSubquery {
+            select: self.select,
+            frame: self.frame.clone(),
+            schema: self.schema.clone(),
+            outer_keys: self.outer_keys[..n_keys].to_vec(),
+            inner_keys: self.inner_keys[..n_keys].to_vec(),
+        }
+    }
+}
+
 /// Recursively walks a SQL Expr to create a polars Expr
 pub(crate) struct SQLExprVisitor<'a> {
     ctx: &'a mut SQLContext,
@@ -279,6 +332,7 @@ impl SQLExprVisitor<'_> {
             } => self.visit_cast(expr, data_type, format, kind),
             SQLExpr::Ceil { expr, .. } => Ok(self.visit_expr(expr)?.ceil()),
             SQLExpr::CompoundIdentifier(idents) => self.visit_compound_identifier(idents),
+            SQLExpr::Exists { subquery, negated } => self.visit_exists(subquery, None, *negated),
             SQLExpr::Extract { field, expr } => {
                 parse_extract_date_part(self.visit_expr(expr)?, field)
             },
@@ -299,7 +353,7 @@ impl SQLExprVisitor<'_> {
                 expr,
                 subquery,
                 negated,
-            } => self.visit_in_subquery(expr, subquery, *negated),
+            } => self.visit_exists(subquery, Some(expr), *negated),
             SQLExpr::Interval(interval) => self.visit_interval(interval),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
@@ -349,7 +403,7 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => self.visit_scalar_subquery(subquery),
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -442,6 +496,300 @@ impl SQLExprVisitor<'_> {
         polars_bail!(SQLInterface: "subquery type not supported");
     }
 
+    /// Split a correlated subquery into the inner query and the join keys that
+    /// correlate it with the outer query.
+    ///
+    /// Returns `None` if the subquery does not reference the outer query. With
+    /// `in_expr`, the subquery is the right-hand side of an `IN` and its value is
+    /// matched with `in_expr` as an extra join key.
+    fn decorrelate_subquery<'q>(
+        &mut self,
+        subquery: &'q Subquery,
+        in_expr: Option<&SQLExpr>,
+    ) -> PolarsResult<Option<CorrelatedSubquery<'q>>> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let (Some(outer_schema), SetExpr::Select(select)) =
+            (self.active_schema, subquery.body.as_ref())
+        else {
+            return Ok(None);
+        };
+        let [tbl_expr] = select.from.as_slice() else {
+            return Ok(None);
+        };
+        let frame = self.ctx.execute_from_statement(tbl_expr)?;
+        // The schema is resolved on a copy, as caching the resolved plan in the
+        // frame would make the joins of an `IN` subquery share its plan nodes.
+        let schema = self.ctx.get_frame_schema(&mut frame.clone())?;
+
+        // Identifiers that cannot be resolved in the subquery refer to the outer query.
+        let relations = relation_names(tbl_expr);
+        let is_outer = |idents: &[Ident]| match idents {
+            [ident] => !schema.contains(&ident.value) && outer_schema.contains(&ident.value),
+            [root, ..] => !relations.contains(&root.value) && !schema.contains(&root.value),
+            [] => false,
+        };
+        let references_outer = |expr: &SQLExpr| {
+            let mut found = false;
+            visit_identifiers(expr, &mut |idents| found |= is_outer(idents));
+            found
+        };
+        let only_outer = |expr: &SQLExpr| {
+            let (mut outer, mut inner) = (false, false);
+            visit_identifiers(expr, &mut |idents| match is_outer(idents) {
+                true => outer = true,
+                false => inner = true,
+            });
+            outer && !inner
+        };
+
+        let projection_is_correlated = select.projection.iter().any(|item| match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                references_outer(expr)
+            },
+            _ => false,
+        });
+        polars_ensure!(
+            !projection_is_correlated,
+            SQLInterface: "outer query columns can only be referenced in the WHERE clause of a subquery"
+        );
+        let (correlated, uncorrelated): (Vec<_>, Vec<_>) = select
+            .selection
+            .iter()
+            .flat_map(split_conjunctions)
+            .partition(|expr| references_outer(expr));
+        if correlated.is_empty() {
+            return Ok(None);
+        }
+        polars_ensure!(
+            subquery.order_by.is_none()
+                && subquery.limit.is_none()
+                && subquery.offset.is_none()
+                && select.distinct.is_none()
+                && select.having.is_none()
+                && matches!(&select.group_by, GroupByExpr::Expressions(exprs, modifiers) if exprs.is_empty() && modifiers.is_empty()),
+            SQLInterface: "correlated subqueries with GROUP BY, HAVING, DISTINCT, ORDER BY, LIMIT or OFFSET are not supported"
+        );
+
+        let mut outer_keys = Vec::with_capacity(correlated.len() + 1);
+        let mut inner_keys = Vec::with_capacity(correlated.len() + 1);
+        for expr in correlated {
+            let (outer, inner) = match expr {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } if only_outer(left) && !references_outer(right) => (left, right),
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } if only_outer(right) && !references_outer(left) => (right, left),
+                _ => {
+                    polars_bail!(SQLInterface: "correlated subquery predicates must be equalities between outer and inner query expressions; found {}", expr)
+                },
+            };
+            outer_keys.push(self.visit_expr(outer)?);
+            inner_keys.push(parse_sql_expr(inner, self.ctx, Some(&schema))?);
+        }
+        let frame = self
+            .ctx
+            .process_where(frame, &join_conjunctions(uncorrelated))?;
+        let mut correlated = CorrelatedSubquery {
+            select,
+            frame,
+            schema,
+            outer_keys,
+            inner_keys,
+        };
+        if let Some(in_expr) = in_expr {
+            let value = self.visit_subquery_value(&correlated)?;
+            correlated.outer_keys.push(self.visit_expr(in_expr)?);
+            correlated.inner_keys.push(value);
+        }
+        // The inner keys are joined with the outer keys, so must have the same type.
+        for (outer_key, inner_key) in correlated.outer_keys.iter().zip(&mut correlated.inner_keys) {
+            let dtype = outer_key.to_field(outer_schema, Context::Default)?.dtype;
+            *inner_key = inner_key.clone().cast(dtype);
+        }
+        Ok(Some(correlated))
+    }
+
+    /// Visit the single projected value of a correlated subquery.
+    fn visit_subquery_value(&mut self, correlated: &CorrelatedSubquery) -> PolarsResult<Expr> {
+        match correlated.select.projection.as_slice() {
+            [SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. }] => {
+                parse_sql_expr(expr, self.ctx, Some(&correlated.schema))
+            },
+            _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one column"),
+        }
+    }
+
+    /// Visit an `EXISTS` subquery, or an `IN` subquery if `in_expr` is given.
+    ///
+    /// Correlated subqueries are left-joined to the outer query on their
+    /// correlated keys, and uncorrelated `EXISTS` subqueries are cross-joined
+    /// as a single boolean. Uncorrelated `IN` subqueries are evaluated as a
+    /// (single column) subplan.
+    fn visit_exists(
+        &mut self,
+        subquery: &Subquery,
+        in_expr: Option<&SQLExpr>,
+        negated: bool,
+    ) -> PolarsResult<Expr> {
+        let join = match self.decorrelate_subquery(subquery, in_expr)? {
+            Some(correlated) if in_expr.is_some() => {
+                let is_in = self.visit_correlated_in(correlated);
+                return Ok(if negated { is_in.not() } else { is_in });
+            },
+            Some(correlated) => {
+                let name = self.next_subquery_name();
+                let (frame, right_on) = correlated.select_keys(&name, vec![]);
+                (
+                    name.clone(),
+                    SubqueryJoin {
+                        frame: frame.with_column(lit(true).alias(&name)),
+                        left_on: correlated.outer_keys,
+                        right_on,
+                        how: JoinType::Left,
+                        validation: JoinValidation::ManyToOne,
+                    },
+                )
+            },
+            None => {
+                if let Some(in_expr) = in_expr {
+                    return self.visit_in_subquery(in_expr, subquery, negated);
+                }
+                // Selecting only literals (as in `EXISTS (SELECT 1 ...)`) would
+                // always produce a row, so count the rows of the relation instead.
+                let frame = match subquery.body.as_ref() {
+                    SetExpr::Select(select)
+                        if !select.from.is_empty()
+                            && select.having.is_none()
+                            && matches!(&select.group_by, GroupByExpr::Expressions(exprs, _) if exprs.is_empty())
+                            && select.projection.iter().all(|item| {
+                                matches!(item, SelectItem::UnnamedExpr(SQLExpr::Value(_)))
+                            }) =>
+                    {
+                        let mut select = select.clone();
+                        select.projection = vec![SelectItem::Wildcard(Default::default())];
+                        let subquery = Subquery {
+                            body: Box::new(SetExpr::Select(select)),
+                            ..subquery.clone()
+                        };
+                        self.ctx.execute_query_no_ctes(&subquery)?
+                    },
+                    _ => self.ctx.execute_query_no_ctes(subquery)?,
+                };
+                let name = self.next_subquery_name();
+                (
+                    name.clone(),
+                    SubqueryJoin {
+                        frame: frame.select([len().gt(lit(0)).alias(&name)]),
+                        left_on: vec![],
+                        right_on: vec![],
+                        how: JoinType::Cross,
+                        validation: JoinValidation::ManyToMany,
+                    },
+                )
+            },
+        };
+        let (name, join) = join;
+        self.ctx.register_subquery_join(join);
+        let exists = col(&name).fill_null(lit(false));
+        Ok(if negated { exists.not() } else { exists })
+    }
+
+    /// Visit a correlated `IN` subquery, with the null semantics of SQL: if the
+    /// value isn't found, the result is null (rather than false) if the value
+    /// or any value of the subquery is null. An empty subquery gives false.
+    fn visit_correlated_in(&mut self, correlated: CorrelatedSubquery) -> Expr {
+        let value = correlated.outer_keys.last().unwrap().clone();
+        let subquery_value = correlated.inner_keys.last().unwrap().clone();
+
+        let found_name = self.next_subquery_name();
+        let (frame, right_on) = correlated.select_keys(&found_name, vec![]);
+        self.ctx.register_subquery_join(SubqueryJoin {
+            frame: frame.with_column(lit(true).alias(&found_name)),
+            left_on: correlated.outer_keys.clone(),
+            right_on,
+            how: JoinType::Left,
+            validation: JoinValidation::ManyToOne,
+        });
+
+        // Whether the subquery of the row has a null value, null if it is empty.
+        // This must be an aggregation (unlike `any`) to group by the keys.
+        let has_null_name = self.next_subquery_name();
+        let groups = correlated.without_in_value();
+        let (frame, right_on) = groups.select_keys(
+            &has_null_name,
+            vec![subquery_value.is_null().max().alias(&has_null_name)],
+        );
+        self.ctx.register_subquery_join(SubqueryJoin {
+            frame,
+            left_on: groups.outer_keys,
+            right_on,
+            how: JoinType::Left,
+            validation: JoinValidation::ManyToOne,
+        });
+
+        let has_null = col(&has_null_name);
+        when(col(&found_name).fill_null(lit(false)))
+            .then(lit(true))
+            .when(has_null.clone().is_null())
+            .then(lit(false))
+            .when(value.is_null().or(has_null))
+            .then(lit(Null).cast(DataType::Boolean))
+            .otherwise(lit(false))
+    }
+
+    /// Visit a scalar subquery.
+    ///
+    /// Correlated subqueries are aggregated by their correlated keys (if they
+    /// aggregate) and left-joined to the outer query; uncorrelated subqueries
+    /// are cross-joined as a single value.
+    fn visit_scalar_subquery(&mut self, subquery: &Subquery) -> PolarsResult<Expr> {
+        let Some(correlated) = self.decorrelate_subquery(subquery, None)? else {
+            let mut frame = self.ctx.execute_query_no_ctes(subquery)?;
+            let schema = self.ctx.get_frame_schema(&mut frame)?;
+            let [value] = schema.iter_names().collect::<Vec<_>>()[..] else {
+                polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
+            };
+            let name = self.next_subquery_name();
+            self.ctx.register_subquery_join(SubqueryJoin {
+                frame: frame.select([col(value).first().alias(&name)]),
+                left_on: vec![],
+                right_on: vec![],
+                how: JoinType::Cross,
+                validation: JoinValidation::ManyToMany,
+            });
+            return Ok(col(&name));
+        };
+        let value = self.visit_subquery_value(&correlated)?;
+        let name = self.next_subquery_name();
+
+        // The outer rows without a match have no group, so they count zero rows.
+        let is_count = matches!(value, Expr::Len | Expr::Agg(AggExpr::Count(..)));
+        let (frame, right_on) = correlated.select_keys(&name, vec![value.alias(&name)]);
+        self.ctx.register_subquery_join(SubqueryJoin {
+            frame,
+            left_on: correlated.outer_keys,
+            right_on,
+            how: JoinType::Left,
+            validation: JoinValidation::ManyToOne,
+        });
+        Ok(if is_count {
+            col(&name).fill_null(typed_lit(0 as IdxSize))
+        } else {
+            col(&name)
+        })
+    }
+
+    fn next_subquery_name(&self) -> String {
+        format!("{SUBQUERY_PREFIX}{}", self.ctx.next_subquery_index())
+    }
+
     /// Visit a single SQL identifier.
     ///
     /// e.g. column
@@ -1164,7 +1512,7 @@ impl SQLExprVisitor<'_> {
 pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
     let mut ctx = SQLContext::new();
 
-    let mut parser = Parser::new(&GenericDialect);
+    let mut parser = Parser::new(&PolarsDialect);
     parser = parser.with_options(ParserOptions {
         trailing_commas: true,
         ..Default::default()
@@ -1194,6 +1542,220 @@ pub(crate) fn parse_sql_expr(
     visitor.visit_expr(expr)
 }
 
+/// Parse a correlated `[NOT] EXISTS` or `IN` subquery predicate as a semi (or
+/// anti) join with the outer query.
+///
+/// Returns `None` for other predicates, and for uncorrelated subqueries.
+pub(crate) fn parse_sql_semi_join(
+    expr: &SQLExpr,
+    ctx: &mut SQLContext,
+    active_schema: &Schema,
+) -> PolarsResult<Option<SubqueryJoin>> {
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let (subquery, in_expr, negated) = match expr {
+            SQLExpr::Exists { subquery, negated } => (subquery, None, *negated),
+            // `NOT IN` isn't an anti join if there are nulls, so is evaluated
+            // as a predicate.
+            SQLExpr::InSubquery {
+                expr,
+                subquery,
+                negated: false,
+            } => (subquery, Some(expr.as_ref()), false),
+            _ => return Ok(None),
+        };
+        let mut visitor = SQLExprVisitor {
+            ctx,
+            active_schema: Some(active_schema),
+        };
+        let Some(correlated) = visitor.decorrelate_subquery(subquery, in_expr)? else {
+            return Ok(None);
+        };
+        let name = visitor.next_subquery_name();
+        let (frame, right_on) = correlated.select_keys(&name, vec![]);
+        Ok(Some(SubqueryJoin {
+            frame,
+            left_on: correlated.outer_keys,
+            right_on,
+            how: if negated {
+                JoinType::Anti
+            } else {
+                JoinType::Semi
+            },
+            validation: JoinValidation::ManyToMany,
+        }))
+    }
+    #[cfg(not(feature = "semi_anti_join"))]
+    {
+        let _ = (expr, ctx, active_schema);
+        Ok(None)
+    }
+}
+
+/// Split a SQL predicate into its `AND`-ed conjuncts.
+pub(crate) fn split_conjunctions(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjunctions(left);
+            conjuncts.extend(split_conjunctions(right));
+            conjuncts
+        },
+        SQLExpr::Nested(expr) => split_conjunctions(expr),
+        expr => vec![expr],
+    }
+}
+
+/// Join SQL predicates with `AND` (the inverse of [`split_conjunctions`]).
+pub(crate) fn join_conjunctions(exprs: Vec<&SQLExpr>) -> Option<SQLExpr> {
+    exprs
+        .into_iter()
+        .cloned()
+        .reduce(|left, right| SQLExpr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::And,
+            right: Box::new(right),
+        })
+}
+
+/// Call `f` with the (compound) identifiers of a SQL expression, without
+/// descending into subqueries.
+pub(crate) fn visit_identifiers<'e>(expr: &'e SQLExpr, f: &mut dyn FnMut(&'e [Ident])) {
+    let mut visit = |expr: &'e SQLExpr| visit_identifiers(expr, f);
+    match expr {
+        SQLExpr::Identifier(ident) => f(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(idents) => f(idents),
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right) => {
+            visit(left);
+            visit(right);
+        },
+        SQLExpr::Like { expr, pattern, .. }
+        | SQLExpr::ILike { expr, pattern, .. }
+        | SQLExpr::RLike { expr, pattern, .. } => {
+            visit(expr);
+            visit(pattern);
+        },
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            visit(expr);
+            visit(low);
+            visit(high);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            visit(expr);
+            list.iter().for_each(visit);
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            operand.iter().for_each(|e| visit(e));
+            conditions.iter().chain(results).for_each(&mut visit);
+            else_result.iter().for_each(|e| visit(e));
+        },
+        SQLExpr::Function(function) => {
+            if let FunctionArguments::List(list) = &function.args {
+                for arg in &list.args {
+                    if let FunctionArg::Named {
+                        arg: FunctionArgExpr::Expr(expr),
+                        ..
+                    }
+                    | FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) = arg
+                    {
+                        visit(expr)
+                    }
+                }
+            }
+        },
+        SQLExpr::Tuple(exprs) => exprs.iter().for_each(visit),
+        SQLExpr::Cast { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::InSubquery { expr, .. }
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::Nested(expr)
+        | SQLExpr::UnaryOp { expr, .. } => visit(expr),
+        _ => {},
+    }
+}
+
+/// Get the names (or aliases) of the relations of a FROM clause.
+fn relation_names(tbl_expr: &TableWithJoins) -> Vec<String> {
+    std::iter::once(&tbl_expr.relation)
+        .chain(tbl_expr.joins.iter().map(|join| &join.relation))
+        .filter_map(|relation| match relation {
+            TableFactor::Table {
+                alias: None, name, ..
+            } => name.0.last().map(|ident| ident.value.clone()),
+            TableFactor::Table { alias, .. }
+            | TableFactor::Derived { alias, .. }
+            | TableFactor::UNNEST { alias, .. }
+            | TableFactor::Function { alias, .. }
+            | TableFactor::NestedJoin { alias, .. } => {
+                alias.as_ref().map(|alias| alias.name.value.clone())
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check if an expression contains an aggregation.
+pub(crate) fn has_aggregation(expr: &Expr) -> bool {
+    has_expr(expr, |e| matches!(e, Expr::Agg(_) | Expr::Len))
+}
+
+/// Restrict the input rows of an aggregation to those where `predicate` is true,
+/// as in `agg(...) FILTER (WHERE predicate)`. The partitions of a window are unaffected.
+/// Without any such rows the result is null, except for counts (as `COUNT(DISTINCT ...)`
+/// is not a plain aggregation, the caller tells whether `expr` is a count).
+pub(crate) fn filter_aggregation(expr: Expr, predicate: &Expr, is_count: bool) -> Expr {
+    match expr {
+        Expr::Window {
+            function,
+            partition_by,
+            order_by,
+            options,
+        } => Expr::Window {
+            function: Arc::new(filter_aggregation(
+                Arc::unwrap_or_clone(function),
+                predicate,
+                is_count,
+            )),
+            partition_by,
+            order_by,
+            options,
+        },
+        expr => {
+            let agg = expr.map_expr(|e| match e {
+                Expr::Column(name) => Expr::Column(name).filter(predicate.clone()),
+                Expr::Len => predicate.clone().filter(predicate.clone()).len(),
+                e => e,
+            });
+            if is_count {
+                agg
+            } else {
+                when(predicate.clone().any(true))
+                    .then(agg)
+                    .otherwise(lit(Null))
+            }
+        },
+    }
+}
+
 pub(crate) fn parse_sql_array(expr: &SQLExpr, ctx: &mut SQLContext) -> PolarsResult<Series> {
     match expr {
         SQLExpr::Array(arr) => {
@@ -1,17 +1,38 @@
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+use std::path::PathBuf;
 use std::str::FromStr;
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+use std::sync::Arc;
 
+use polars_core::prelude::*;
 #[cfg(any(
     feature = "csv",
     feature = "parquet",
     feature = "ipc",
-    feature = "json"
+    feature = "json",
+    feature = "avro"
 ))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
-#[cfg(feature = "csv")]
-use polars_lazy::prelude::LazyCsvReader;
-use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use polars_io::cloud::CloudOptions;
+use polars_lazy::prelude::*;
+use polars_time::{ClosedWindow, Duration};
+use sqlparser::ast::{
+    Expr as SQLExpr, FunctionArg, FunctionArgExpr, UnaryOperator, Value as SQLValue,
+};
+
+use crate::sql_expr::parse_sql_expr;
+use crate::SQLContext;
 
 /// Table functions that are supported by Polars
 #[allow(clippy::enum_variant_names)]
@@ -19,12 +40,14 @@ pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', separator => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('path/to/dir/**/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -40,6 +63,24 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "json")]
     ReadJson,
+    /// SQL 'read_ndjson' function
+    /// ```sql
+    /// SELECT * FROM read_ndjson('path/to/file.ndjson')
+    /// ```
+    #[cfg(feature = "json")]
+    ReadNdJson,
+    /// SQL 'read_avro' function
+    /// ```sql
+    /// SELECT * FROM read_avro('path/to/file.avro')
+    /// ```
+    #[cfg(feature = "avro")]
+    ReadAvro,
+    /// SQL 'generate_series' function; the series includes the stop value.
+    /// ```sql
+    /// SELECT * FROM generate_series(1, 10, 2)
+    /// SELECT * FROM generate_series(DATE '2024-01-01', DATE '2024-01-31', INTERVAL '1 week')
+    /// ```
+    GenerateSeries,
 }
 
 impl FromStr for PolarsTableFunctions {
@@ -56,81 +97,237 @@ impl FromStr for PolarsTableFunctions {
             "read_ipc" => PolarsTableFunctions::ReadIpc,
             #[cfg(feature = "json")]
             "read_json" => PolarsTableFunctions::ReadJson,
+            #[cfg(feature = "json")]
+            "read_ndjson" => PolarsTableFunctions::ReadNdJson,
+            #[cfg(feature = "avro")]
+            "read_avro" => PolarsTableFunctions::ReadAvro,
+            "generate_series" => PolarsTableFunctions::GenerateSeries,
             _ => polars_bail!(SQLInterface: "'{}' is not a supported table function", s),
         })
     }
 }
 
 impl PolarsTableFunctions {
-    #[allow(unused_variables, unreachable_patterns)]
-    pub(crate) fn execute(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+    fn name(&self) -> &'static str {
         match self {
             #[cfg(feature = "csv")]
-            PolarsTableFunctions::ReadCsv => self.read_csv(args),
+            PolarsTableFunctions::ReadCsv => "read_csv",
             #[cfg(feature = "parquet")]
-            PolarsTableFunctions::ReadParquet => self.read_parquet(args),
+            PolarsTableFunctions::ReadParquet => "read_parquet",
             #[cfg(feature = "ipc")]
-            PolarsTableFunctions::ReadIpc => self.read_ipc(args),
+            PolarsTableFunctions::ReadIpc => "read_ipc",
             #[cfg(feature = "json")]
-            PolarsTableFunctions::ReadJson => self.read_ndjson(args),
-            _ => unreachable!(),
+            PolarsTableFunctions::ReadJson => "read_json",
+            #[cfg(feature = "json")]
+            PolarsTableFunctions::ReadNdJson => "read_ndjson",
+            #[cfg(feature = "avro")]
+            PolarsTableFunctions::ReadAvro => "read_avro",
+            PolarsTableFunctions::GenerateSeries => "generate_series",
         }
     }
 
-    #[cfg(feature = "csv")]
-    fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
+    pub(crate) fn execute(
+        &self,
+        ctx: &mut SQLContext,
+        args: &[FunctionArg],
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let mut args = TableFunctionArgs::new(self.name(), args)?;
+        match self {
+            #[cfg(feature = "csv")]
+            PolarsTableFunctions::ReadCsv => self.read_csv(&mut args),
+            #[cfg(feature = "parquet")]
+            PolarsTableFunctions::ReadParquet => self.read_parquet(&mut args),
+            #[cfg(feature = "ipc")]
+            PolarsTableFunctions::ReadIpc => self.read_ipc(&mut args),
+            #[cfg(feature = "json")]
+            PolarsTableFunctions::ReadJson | PolarsTableFunctions::ReadNdJson => {
+                self.read_ndjson(&mut args)
+            },
+            #[cfg(feature = "avro")]
+            PolarsTableFunctions::ReadAvro => self.read_avro(&mut args),
+            PolarsTableFunctions::GenerateSeries => self.generate_series(ctx, &mut args),
+        }
+    }
 
+    #[cfg(feature = "csv")]
+    fn read_csv(&self, args: &mut TableFunctionArgs) -> PolarsResult<(String, LazyFrame)> {
+        use polars_io::csv::read::NullValues;
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path)
-            .with_try_parse_dates(true)
+
+        let (name, paths) = args.paths()?;
+        let mut reader = LazyCsvReader::new_paths(paths)
+            .with_try_parse_dates(args.take_bool("try_parse_dates")?.unwrap_or(true))
             .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+            .with_n_rows(args.take_usize("n_rows")?)
+            .with_cloud_options(args.take_cloud_options(&name)?);
+        if let Some(separator) = args.take_char("separator")? {
+            reader = reader.with_separator(separator);
+        }
+        if let Some(has_header) = args.take_bool("has_header")? {
+            reader = reader.with_has_header(has_header);
+        }
+        if let Some(skip_rows) = args.take_usize("skip_rows")? {
+            reader = reader.with_skip_rows(skip_rows);
+        }
+        if let Some(n) = args.take_usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(Some(n));
+        }
+        if let Some(quote_char) = args.take_char("quote_char")? {
+            reader = reader.with_quote_char(Some(quote_char));
+        }
+        if let Some(null_value) = args.take_string("null_values")? {
+            reader = reader.with_null_values(Some(NullValues::AllColumnsSingle(null_value)));
+        }
+        if let Some(ignore_errors) = args.take_bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        if let Some(glob) = args.take_bool("glob")? {
+            reader = reader.with_glob(glob);
+        }
+        let columns = args.take_strings("columns")?;
+        args.finish()?;
+
+        let lf = reader.finish()?;
+        Ok((name, select_columns(lf, columns)))
     }
 
     #[cfg(feature = "parquet")]
-    fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+    fn read_parquet(&self, args: &mut TableFunctionArgs) -> PolarsResult<(String, LazyFrame)> {
+        let (name, paths) = args.paths()?;
+        let mut scan_args = ScanArgsParquet {
+            n_rows: args.take_usize("n_rows")?,
+            cloud_options: args.take_cloud_options(&name)?,
+            ..Default::default()
+        };
+        if let Some(hive_partitioning) = args.take_bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = Some(hive_partitioning);
+        }
+        if let Some(glob) = args.take_bool("glob")? {
+            scan_args.glob = glob;
+        }
+        let columns = args.take_strings("columns")?;
+        args.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
-        Ok((path, lf))
+        let lf = LazyFrame::scan_parquet_files(paths, scan_args)?;
+        Ok((name, select_columns(lf, columns)))
     }
 
     #[cfg(feature = "ipc")]
-    fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+    fn read_ipc(&self, args: &mut TableFunctionArgs) -> PolarsResult<(String, LazyFrame)> {
+        let (name, paths) = args.paths()?;
+        let mut scan_args = ScanArgsIpc {
+            n_rows: args.take_usize("n_rows")?,
+            cloud_options: args.take_cloud_options(&name)?,
+            ..Default::default()
+        };
+        if let Some(hive_partitioning) = args.take_bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = Some(hive_partitioning);
+        }
+        let columns = args.take_strings("columns")?;
+        args.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
-        Ok((path, lf))
+        let lf = LazyFrame::scan_ipc_files(paths, scan_args)?;
+        Ok((name, select_columns(lf, columns)))
     }
+
     #[cfg(feature = "json")]
-    fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+    fn read_ndjson(&self, args: &mut TableFunctionArgs) -> PolarsResult<(String, LazyFrame)> {
+        use std::num::NonZeroUsize;
 
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+        let (name, paths) = args.paths()?;
+        let mut reader = LazyJsonLineReader::new_paths(paths)
+            .with_n_rows(args.take_usize("n_rows")?)
+            .with_cloud_options(args.take_cloud_options(&name)?);
+        if let Some(n) = args.take_usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(NonZeroUsize::new(n));
+        }
+        if let Some(ignore_errors) = args.take_bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        let columns = args.take_strings("columns")?;
+        args.finish()?;
+
+        let lf = reader.finish()?;
+        Ok((name, select_columns(lf, columns)))
     }
 
-    #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
-        match arg {
-            FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
-                SQLValue::SingleQuotedString(s),
-            ))) => Ok(s.to_string()),
-            _ => polars_bail!(
-                SQLSyntax:
-                "expected a valid file path as a single-quoted string; found: {}", arg,
-            ),
+    #[cfg(feature = "avro")]
+    fn read_avro(&self, args: &mut TableFunctionArgs) -> PolarsResult<(String, LazyFrame)> {
+        let (name, paths) = args.paths()?;
+        let mut scan_args = ScanArgsAvro {
+            n_rows: args.take_usize("n_rows")?,
+            cloud_options: args.take_cloud_options(&name)?,
+            ..Default::default()
+        };
+        if let Some(hive_partitioning) = args.take_bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = Some(hive_partitioning);
         }
+        let columns = args.take_strings("columns")?;
+        args.finish()?;
+
+        let lf = LazyFrame::scan_avro_files(paths, scan_args)?;
+        Ok((name, select_columns(lf, columns)))
+    }
+
+    fn generate_series(
+        &self,
+        ctx: &mut SQLContext,
+        args: &mut TableFunctionArgs,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let name = self.name();
+        polars_ensure!(
+            matches!(args.positional.len(), 2 | 3),
+            SQLSyntax: "`{}` expects 2 or 3 arguments; found {}", name, args.positional.len()
+        );
+        args.finish()?;
+
+        let start = parse_sql_expr(args.positional[0], ctx, None)?;
+        let stop = parse_sql_expr(args.positional[1], ctx, None)?;
+        let step = args.positional.get(2).copied();
+        let mut bounds = DataFrame::empty().lazy().select([start.clone()]);
+        let dtype = ctx
+            .get_frame_schema(&mut bounds)?
+            .get_at_index(0)
+            .unwrap()
+            .1
+            .clone();
+
+        let series = match dtype {
+            dtype if dtype.is_integer() => {
+                let step = match step {
+                    Some(step) => integer_value(step).ok_or_else(|| {
+                        polars_err!(SQLSyntax: "`{}` step must be an integer; found {}", name, step)
+                    })?,
+                    None => 1,
+                };
+                polars_ensure!(step != 0, SQLSyntax: "`{}` step cannot be zero", name);
+                let end = if step > 0 {
+                    stop + lit(1)
+                } else {
+                    stop - lit(1)
+                };
+                int_range(start, end, step, DataType::Int64)
+            },
+            DataType::Date => {
+                date_range(start, stop, interval_step(name, step)?, ClosedWindow::Both)
+            },
+            DataType::Datetime(time_unit, time_zone) => datetime_range(
+                start,
+                stop,
+                interval_step(name, step)?,
+                ClosedWindow::Both,
+                Some(time_unit),
+                time_zone,
+            ),
+            dtype => polars_bail!(
+                SQLSyntax: "`{}` expects integer, date or timestamp bounds; found {}", name, dtype
+            ),
+        };
+        let lf = DataFrame::empty().lazy().select([series.alias(name)]);
+        Ok((name.to_string(), lf))
     }
 }
 
@@ -146,6 +343,242 @@ impl PolarsTableFunctions {
             "read_ipc",
             #[cfg(feature = "json")]
             "read_json",
+            #[cfg(feature = "json")]
+            "read_ndjson",
+            #[cfg(feature = "avro")]
+            "read_avro",
+            "generate_series",
         ]
     }
 }
+
+/// The arguments of a table function call; positional arguments come first, followed by
+/// named arguments (`name => value`), which are taken by the function as it applies them.
+struct TableFunctionArgs<'a> {
+    function: &'static str,
+    positional: Vec<&'a SQLExpr>,
+    named: Vec<(&'a str, &'a SQLExpr)>,
+}
+
+impl<'a> TableFunctionArgs<'a> {
+    fn new(function: &'static str, args: &'a [FunctionArg]) -> PolarsResult<Self> {
+        let mut positional = vec![];
+        let mut named = vec![];
+        for arg in args {
+            match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => {
+                    polars_ensure!(
+                        named.is_empty(),
+                        SQLSyntax: "positional arguments of `{}` must come before named arguments", function
+                    );
+                    positional.push(expr);
+                },
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(expr),
+                    ..
+                } => named.push((name.value.as_str(), expr)),
+                _ => polars_bail!(SQLSyntax: "invalid argument for `{}`: {}", function, arg),
+            }
+        }
+        Ok(Self {
+            function,
+            positional,
+            named,
+        })
+    }
+
+    /// Raise an error for any named argument that was not taken.
+    fn finish(&self) -> PolarsResult<()> {
+        match self.named.first() {
+            Some((name, _)) => {
+                polars_bail!(SQLSyntax: "unknown argument '{}' for `{}`", name, self.function)
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+impl<'a> TableFunctionArgs<'a> {
+    /// The file path(s) to read, as a single-quoted string (which may be a glob pattern)
+    /// or an array of strings. Also returns the default name of the table.
+    fn paths(&self) -> PolarsResult<(String, Arc<[PathBuf]>)> {
+        polars_ensure!(
+            self.positional.len() == 1,
+            SQLSyntax: "`{}` expects a single file path; found {:?} arguments",
+            self.function, self.positional.len()
+        );
+        let paths = match string_values(self.positional[0]) {
+            Some(paths) if !paths.is_empty() => paths,
+            _ => polars_bail!(
+                SQLSyntax:
+                "expected a valid file path as a single-quoted string; found: {}", self.positional[0],
+            ),
+        };
+        let name = match paths.as_slice() {
+            [path] => path.clone(),
+            _ => self.function.to_string(),
+        };
+        Ok((name, paths.into_iter().map(PathBuf::from).collect()))
+    }
+
+    fn take(&mut self, name: &str) -> Option<&'a SQLExpr> {
+        let idx = self
+            .named
+            .iter()
+            .position(|(arg_name, _)| arg_name.eq_ignore_ascii_case(name))?;
+        Some(self.named.remove(idx).1)
+    }
+
+    fn invalid_value(&self, name: &str, expected: &str, value: &SQLExpr) -> PolarsError {
+        polars_err!(
+            SQLSyntax: "invalid value for argument '{}' of `{}`; expected {}, found {}",
+            name, self.function, expected, value
+        )
+    }
+
+    fn take_bool(&mut self, name: &str) -> PolarsResult<Option<bool>> {
+        self.take(name)
+            .map(|value| match value {
+                SQLExpr::Value(SQLValue::Boolean(b)) => Ok(*b),
+                _ => Err(self.invalid_value(name, "a boolean", value)),
+            })
+            .transpose()
+    }
+
+    fn take_usize(&mut self, name: &str) -> PolarsResult<Option<usize>> {
+        self.take(name)
+            .map(|value| match value {
+                SQLExpr::Value(SQLValue::Number(n, _)) => n
+                    .parse::<usize>()
+                    .map_err(|_| self.invalid_value(name, "a non-negative integer", value)),
+                _ => Err(self.invalid_value(name, "a non-negative integer", value)),
+            })
+            .transpose()
+    }
+
+    #[cfg(feature = "csv")]
+    fn take_string(&mut self, name: &str) -> PolarsResult<Option<String>> {
+        self.take(name)
+            .map(|value| match value {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Ok(s.clone()),
+                _ => Err(self.invalid_value(name, "a string", value)),
+            })
+            .transpose()
+    }
+
+    #[cfg(feature = "csv")]
+    fn take_char(&mut self, name: &str) -> PolarsResult<Option<u8>> {
+        self.take(name)
+            .map(|value| match value {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) if s.len() == 1 => {
+                    Ok(s.as_bytes()[0])
+                },
+                _ => Err(self.invalid_value(name, "a single-byte string", value)),
+            })
+            .transpose()
+    }
+
+    fn take_strings(&mut self, name: &str) -> PolarsResult<Option<Vec<String>>> {
+        self.take(name)
+            .map(|value| {
+                string_values(value)
+                    .ok_or_else(|| self.invalid_value(name, "a string or array of strings", value))
+            })
+            .transpose()
+    }
+
+    /// Cloud options, given as `storage_options => {'key': 'value', ...}`.
+    fn take_cloud_options(&mut self, path: &str) -> PolarsResult<Option<CloudOptions>> {
+        let name = "storage_options";
+        let Some(value) = self.take(name) else {
+            return Ok(None);
+        };
+        let SQLExpr::Dictionary(fields) = value else {
+            return Err(self.invalid_value(name, "a dictionary of strings", value));
+        };
+        let config = fields
+            .iter()
+            .map(|field| match field.value.as_ref() {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) => {
+                    Ok((field.key.value.as_str(), s.as_str()))
+                },
+                _ => Err(self.invalid_value(name, "a dictionary of strings", value)),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        CloudOptions::from_untyped_config(path, config).map(Some)
+    }
+}
+
+/// A single-quoted string, or an array of them.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+fn string_values(expr: &SQLExpr) -> Option<Vec<String>> {
+    match expr {
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(vec![s.clone()]),
+        SQLExpr::Array(array) => array
+            .elem
+            .iter()
+            .map(|elem| match elem {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// An integer literal, optionally negated.
+fn integer_value(expr: &SQLExpr) -> Option<i64> {
+    match expr {
+        SQLExpr::Value(SQLValue::Number(n, _)) => n.parse().ok(),
+        SQLExpr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => integer_value(expr).map(|n| -n),
+        _ => None,
+    }
+}
+
+/// The step of a date or timestamp series, as an `INTERVAL`; defaults to one day.
+fn interval_step(function: &str, step: Option<&SQLExpr>) -> PolarsResult<Duration> {
+    match step {
+        None => Ok(Duration::parse("1d")),
+        Some(SQLExpr::Interval(interval)) => match interval.value.as_ref() {
+            SQLExpr::Value(SQLValue::SingleQuotedString(s)) if !s.contains('-') => {
+                Ok(Duration::parse_interval(s))
+            },
+            _ => polars_bail!(SQLSyntax: "invalid `{}` step ({})", function, interval),
+        },
+        Some(step) => polars_bail!(
+            SQLSyntax: "`{}` step must be an INTERVAL for dates and timestamps; found {}", function, step
+        ),
+    }
+}
+
+/// Select the given columns of a scanned table.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+fn select_columns(lf: LazyFrame, columns: Option<Vec<String>>) -> LazyFrame {
+    match columns {
+        Some(columns) => lf.select(columns.iter().map(|c| col(c)).collect::<Vec<_>>()),
+        None => lf,
+    }
+}
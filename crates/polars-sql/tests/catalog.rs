@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::catalog::Catalog;
+use polars_sql::*;
+
+/// A catalog with a single `sales` schema, held in memory.
+struct SalesCatalog;
+
+impl Catalog for SalesCatalog {
+    fn schema_names(&self) -> PolarsResult<Vec<String>> {
+        Ok(vec!["sales".to_string()])
+    }
+
+    fn table_names(&self, schema: &str) -> PolarsResult<Vec<String>> {
+        Ok(match schema {
+            "sales" => vec!["orders".to_string()],
+            _ => vec![],
+        })
+    }
+
+    fn get_table(&self, schema: &str, name: &str) -> PolarsResult<Option<LazyFrame>> {
+        Ok(match (schema, name) {
+            ("sales", "orders") => Some(
+                df! {
+                  "id" => [1, 2, 3],
+                  "customer_id" => [10, 20, 10],
+                  "amount" => [1.5, 2.5, 3.0],
+                }?
+                .lazy(),
+            ),
+            _ => None,
+        })
+    }
+}
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+      "customer_id" => [10, 20],
+      "name" => ["a", "b"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new().with_catalog(Arc::new(SalesCatalog));
+    ctx.register("customers", customers.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+#[test]
+fn test_catalog_tables() {
+    let sql = r#"
+      SELECT c.name, SUM(orders.amount) AS total
+      FROM sales.orders
+      JOIN public.customers AS c ON orders.customer_id = c.customer_id
+      GROUP BY c.name
+      ORDER BY c.name
+    "#;
+    let expected = df! {
+      "name" => ["a", "b"],
+      "total" => [4.5, 2.5],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    let sql = "SELECT o.id FROM sales.orders AS o WHERE o.amount > 2 ORDER BY o.id";
+    assert_sql_eq(sql, df! { "id" => [2, 3] }.unwrap());
+
+    // Catalog tables are only in scope for the statement that references them.
+    let mut ctx = create_ctx();
+    ctx.execute("SELECT * FROM sales.orders")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(ctx.execute("SELECT * FROM orders").is_err());
+}
+
+#[test]
+fn test_catalog_tables_shadowing() {
+    // Registered tables with the same name as a catalog table do not shadow it.
+    let mut ctx = create_ctx();
+    let orders = df! {
+      "id" => [2, 3, 4],
+      "status" => ["open", "closed", "open"],
+    }
+    .unwrap();
+    ctx.register("orders", orders.lazy());
+    ctx.register("tables", DataFrame::empty().lazy());
+
+    let sql = r#"
+      SELECT s.id, s.amount, p.status
+      FROM sales.orders AS s
+      JOIN public.orders AS p ON s.id = p.id
+      ORDER BY s.id
+    "#;
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "id" => [2, 3],
+      "amount" => [2.5, 3.0],
+      "status" => ["open", "closed"],
+    }
+    .unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+
+    let sql = "SELECT table_name FROM information_schema.tables ORDER BY table_name";
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "table_name" => ["customers", "orders", "orders", "tables"] }.unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+
+    let sql = "SELECT SUM(amount) AS total FROM sales.orders";
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        out.equals(&df! { "total" => [7.0] }.unwrap()),
+        "{sql}\n{out:?}"
+    );
+}
+
+#[test]
+fn test_information_schema() {
+    let sql = "SELECT * FROM information_schema.tables";
+    let expected = df! {
+      "table_schema" => ["public", "sales"],
+      "table_name" => ["customers", "orders"],
+      "table_type" => ["BASE TABLE", "BASE TABLE"],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    let sql = r#"
+      SELECT table_name, column_name, ordinal_position, data_type
+      FROM information_schema.columns
+      WHERE table_schema = 'sales'
+    "#;
+    let expected = df! {
+      "table_name" => ["orders", "orders", "orders"],
+      "column_name" => ["id", "customer_id", "amount"],
+      "ordinal_position" => [1i64, 2, 3],
+      "data_type" => ["INTEGER", "INTEGER", "DOUBLE"],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_describe_and_show_tables() {
+    let expected = df! {
+      "column_name" => ["customer_id", "name"],
+      "column_type" => ["INTEGER", "VARCHAR"],
+    }
+    .unwrap();
+    assert_sql_eq("DESCRIBE customers", expected);
+
+    let expected = df! {
+      "column_name" => ["id", "customer_id", "amount"],
+      "column_type" => ["INTEGER", "INTEGER", "DOUBLE"],
+    }
+    .unwrap();
+    assert_sql_eq("DESCRIBE sales.orders", expected);
+
+    assert_sql_eq("SHOW TABLES", df! { "name" => ["customers"] }.unwrap());
+    assert_sql_eq(
+        "SHOW TABLES FROM sales",
+        df! { "name" => ["orders"] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_catalog_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT * FROM sales.missing",
+        "SELECT * FROM missing.orders",
+        "SELECT * FROM public.orders",
+        "SELECT * FROM information_schema.missing",
+        "SELECT * FROM db.sales.orders",
+        "DESCRIBE missing",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    // Without a catalog, only the built-in schemas can be referenced.
+    let mut ctx = SQLContext::new();
+    assert!(ctx.execute("SELECT * FROM sales.orders").is_err());
+    assert!(ctx.execute("SHOW TABLES FROM sales").is_err());
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_directory_catalog() {
+    use polars_io::prelude::ParquetWriter;
+    use polars_sql::catalog::DirectoryCatalog;
+
+    let root = std::env::temp_dir().join("polars_sql_directory_catalog");
+    let _ = std::fs::remove_dir_all(&root);
+    let partition = root.join("warehouse").join("events").join("year=2024");
+    std::fs::create_dir_all(&partition).unwrap();
+
+    let mut items = df! { "id" => [1, 2], "name" => ["x", "y"] }.unwrap();
+    let file = std::fs::File::create(root.join("warehouse").join("items.parquet")).unwrap();
+    ParquetWriter::new(file).finish(&mut items).unwrap();
+    let mut events = df! { "id" => [1, 1, 2] }.unwrap();
+    let file = std::fs::File::create(partition.join("0.parquet")).unwrap();
+    ParquetWriter::new(file).finish(&mut events).unwrap();
+
+    let mut ctx = SQLContext::new().with_catalog(Arc::new(DirectoryCatalog::new(&root)));
+    let sql = r#"
+      SELECT items.name, COUNT(e.id) AS n, MAX(e.year) AS year
+      FROM warehouse.events AS e
+      JOIN warehouse.items ON e.id = items.id
+      GROUP BY items.name
+      ORDER BY items.name
+    "#;
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "name" => ["x", "y"],
+      "n" => [2 as IdxSize, 1],
+      "year" => [2024i64, 2024],
+    }
+    .unwrap();
+    assert!(out.equals(&expected), "{out:?}");
+
+    let out = ctx
+        .execute("SELECT table_schema, table_name FROM information_schema.tables")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+      "table_schema" => ["warehouse", "warehouse"],
+      "table_name" => ["events", "items"],
+    }
+    .unwrap();
+    assert!(out.equals(&expected), "{out:?}");
+    assert!(ctx.execute("SELECT * FROM warehouse.missing").is_err());
+    assert!(ctx.execute("SELECT * FROM warehouse.\"..\"").is_err());
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -109,3 +109,44 @@ fn read_ipc_tbl() {
     assert_eq!(df_2.height(), 27);
     assert_eq!(df_2.width(), 4);
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_named_args() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_csv(
+              '../../examples/datasets/foods1.csv',
+              has_header => false,
+              skip_rows => 1,
+              n_rows => 5,
+              columns => ['column_1', 'column_2']
+            )"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.get_column_names(), &["column_1", "column_2"]);
+    assert_eq!(df_sql.height(), 5);
+
+    // Glob patterns and lists of paths
+    for sql in [
+        "SELECT COUNT(category) AS n FROM read_csv('../../examples/datasets/foods[12].csv')",
+        r#"SELECT COUNT(category) AS n FROM read_csv(
+             ['../../examples/datasets/foods1.csv', '../../examples/datasets/foods2.csv']
+           )"#,
+    ] {
+        let df_sql = context.execute(sql).unwrap().collect().unwrap();
+        assert_eq!(
+            df_sql.column("n").unwrap().get(0).unwrap(),
+            AnyValue::UInt32(54)
+        );
+    }
+
+    for sql in [
+        "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', unknown => 1)",
+        "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', separator => ';;')",
+        "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', has_header => 'yes')",
+        "SELECT * FROM read_csv(separator => ',', '../../examples/datasets/foods1.csv')",
+    ] {
+        assert!(context.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,64 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = SQLContext::new().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+#[test]
+fn test_generate_series() {
+    assert_sql_eq(
+        "SELECT * FROM generate_series(1, 5)",
+        df! { "generate_series" => [1i64, 2, 3, 4, 5] }.unwrap(),
+    );
+    assert_sql_eq(
+        "SELECT n FROM generate_series(10, 1, -4) AS t(n)",
+        df! { "n" => [10i64, 6, 2] }.unwrap(),
+    );
+    assert_sql_eq(
+        "SELECT s.n * 2 AS x FROM generate_series(1, 2 + 1) s(n) WHERE s.n > 1",
+        df! { "x" => [4i64, 6] }.unwrap(),
+    );
+    assert_sql_eq(
+        r#"
+        SELECT n, m
+        FROM generate_series(1, 2) AS s(n) CROSS JOIN generate_series(5, 6) AS t(m)
+        ORDER BY n, m
+        "#,
+        df! { "n" => [1i64, 1, 2, 2], "m" => [5i64, 6, 5, 6] }.unwrap(),
+    );
+    let out = SQLContext::new()
+        .execute(
+            r#"
+            SELECT d FROM generate_series(
+              DATE '2024-01-01', DATE '2024-01-20', INTERVAL '1 week'
+            ) AS t(d)
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "d" => ["2024-01-01", "2024-01-08", "2024-01-15"] }
+        .unwrap()
+        .lazy()
+        .select([col("d").cast(DataType::Date)])
+        .collect()
+        .unwrap();
+    assert!(out.equals(&expected), "{out:?}");
+}
+
+#[test]
+fn test_generate_series_errors() {
+    let mut ctx = SQLContext::new();
+    for sql in [
+        "SELECT * FROM generate_series(1)",
+        "SELECT * FROM generate_series(1, 5, 0)",
+        "SELECT * FROM generate_series(1, 5, step => 1)",
+        "SELECT * FROM generate_series('a', 'b')",
+        "SELECT * FROM generate_series(DATE '2024-01-01', DATE '2024-01-20', 1)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
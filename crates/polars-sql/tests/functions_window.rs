@@ -0,0 +1,256 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [1, 2, 3, 4, 5, 6, 7],
+      "grp" => ["a", "a", "a", "a", "b", "b", "b"],
+      "val" => [Some(3), Some(1), Some(3), Some(2), Some(7), None, Some(7)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_column(df: &DataFrame, expected: Series) {
+    let actual = df.column(expected.name()).unwrap();
+    let actual = actual.cast(expected.dtype()).unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "{}: {actual} != {expected}",
+        expected.name()
+    );
+}
+
+#[test]
+fn test_ranking_window_functions() {
+    let sql = r#"
+      SELECT
+        id,
+        ROW_NUMBER() OVER (PARTITION BY grp ORDER BY val, id) AS rn,
+        RANK() OVER (PARTITION BY grp ORDER BY val) AS rk,
+        DENSE_RANK() OVER (PARTITION BY grp ORDER BY val) AS dr,
+        PERCENT_RANK() OVER (PARTITION BY grp ORDER BY val) AS pr,
+        NTILE(2) OVER (PARTITION BY grp ORDER BY id) AS nt,
+        ROW_NUMBER() OVER (ORDER BY val DESC NULLS LAST, id) AS rn_all
+      FROM df
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    assert_column(&out, Series::new("rn", [3, 1, 4, 2, 1, 3, 2]));
+    assert_column(&out, Series::new("rk", [3, 1, 3, 2, 1, 3, 1]));
+    assert_column(&out, Series::new("dr", [3, 1, 3, 2, 1, 2, 1]));
+    assert_column(
+        &out,
+        Series::new("pr", [2.0 / 3.0, 0.0, 2.0 / 3.0, 1.0 / 3.0, 0.0, 1.0, 0.0]),
+    );
+    assert_column(&out, Series::new("nt", [1, 1, 2, 2, 1, 1, 2]));
+    assert_column(&out, Series::new("rn_all", [3, 6, 4, 5, 1, 7, 2]));
+}
+
+#[test]
+fn test_offset_window_functions() {
+    let sql = r#"
+      SELECT
+        id,
+        LAG(val) OVER (PARTITION BY grp ORDER BY id) AS lg,
+        LEAD(val, 1, 0) OVER (PARTITION BY grp ORDER BY id) AS ld,
+        FIRST_VALUE(id) OVER (PARTITION BY grp ORDER BY val DESC, id) AS fv,
+        LAST_VALUE(val) OVER (PARTITION BY grp ORDER BY val) AS lv,
+        NTH_VALUE(id, 2) OVER (PARTITION BY grp ORDER BY id) AS nv
+      FROM df
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    assert_column(
+        &out,
+        Series::new("lg", [None, Some(3), Some(1), Some(3), None, Some(7), None]),
+    );
+    assert_column(
+        &out,
+        Series::new(
+            "ld",
+            [Some(1), Some(3), Some(2), Some(0), None, Some(7), Some(0)],
+        ),
+    );
+    assert_column(&out, Series::new("fv", [1, 1, 1, 1, 6, 6, 6]));
+    assert_column(
+        &out,
+        Series::new(
+            "lv",
+            [Some(3), Some(1), Some(3), Some(2), Some(7), None, Some(7)],
+        ),
+    );
+    assert_column(
+        &out,
+        Series::new(
+            "nv",
+            [None, Some(2), Some(2), Some(2), None, Some(6), Some(6)],
+        ),
+    );
+}
+
+#[test]
+fn test_window_function_requires_over() {
+    let mut ctx = create_ctx();
+    assert!(ctx.execute("SELECT ROW_NUMBER() AS rn FROM df").is_err());
+    assert!(ctx
+        .execute("SELECT NTILE(0) OVER (ORDER BY id) AS nt FROM df")
+        .is_err());
+}
+
+#[test]
+fn test_rows_window_frames() {
+    let sql = r#"
+      SELECT
+        id,
+        AVG(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS avg3,
+        SUM(val) OVER (PARTITION BY grp ORDER BY id ROWS UNBOUNDED PRECEDING) AS running_sum,
+        SUM(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS rest_sum,
+        MAX(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS prev_max,
+        MIN(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING) AS next_min,
+        LAST_VALUE(id) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS last_id
+      FROM df
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    assert_column(
+        &out,
+        Series::new("avg3", [2.0, 7.0 / 3.0, 2.0, 2.5, 7.0, 7.0, 7.0]),
+    );
+    assert_column(&out, Series::new("running_sum", [3, 4, 7, 9, 7, 7, 14]));
+    assert_column(&out, Series::new("rest_sum", [9, 6, 5, 2, 14, 7, 7]));
+    assert_column(
+        &out,
+        Series::new(
+            "prev_max",
+            [None, Some(3), Some(3), Some(3), None, Some(7), Some(7)],
+        ),
+    );
+    assert_column(&out, Series::new("next_min", [1, 1, 1, 1, 7, 7, 7]));
+    assert_column(&out, Series::new("last_id", [4, 4, 4, 4, 7, 7, 7]));
+}
+
+#[test]
+fn test_count_window_frames() {
+    let sql = r#"
+      SELECT
+        id,
+        COUNT(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS cnt3,
+        COUNT(*) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS rows3,
+        COUNT(val) OVER (PARTITION BY grp ORDER BY id ROWS UNBOUNDED PRECEDING) AS running_cnt,
+        COUNT(*) OVER (PARTITION BY grp ORDER BY val RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS peers_cnt
+      FROM df
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    assert_column(&out, Series::new("cnt3", [2, 3, 3, 2, 1, 2, 1]));
+    assert_column(&out, Series::new("rows3", [2, 3, 3, 2, 2, 3, 2]));
+    assert_column(&out, Series::new("running_cnt", [1, 2, 3, 4, 1, 1, 2]));
+    assert_column(&out, Series::new("peers_cnt", [4, 1, 4, 2, 2, 3, 2]));
+}
+
+#[test]
+fn test_count_empty_window_frames() {
+    let sql = r#"
+      SELECT
+        id,
+        COUNT(*) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS prev_rows,
+        COUNT(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS prev_cnt,
+        COUNT(*) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING) AS before_rows,
+        COUNT(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING) AS before_cnt,
+        COUNT(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING) AS after_cnt,
+        SUM(val) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS prev_sum
+      FROM df
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    // The frames are empty at the start (or the end) of the partitions.
+    assert_column(&out, Series::new("prev_rows", [0, 1, 2, 2, 0, 1, 2]));
+    assert_column(&out, Series::new("prev_cnt", [0, 1, 2, 2, 0, 1, 1]));
+    assert_column(&out, Series::new("before_rows", [0, 1, 2, 3, 0, 1, 2]));
+    assert_column(&out, Series::new("before_cnt", [0, 1, 2, 3, 0, 1, 1]));
+    assert_column(&out, Series::new("after_cnt", [3, 2, 1, 0, 1, 1, 0]));
+    assert_column(
+        &out,
+        Series::new(
+            "prev_sum",
+            [None, Some(3), Some(4), Some(4), None, Some(7), Some(7)],
+        ),
+    );
+}
+
+#[test]
+fn test_range_window_frames() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        id,
+        SUM(val) OVER (PARTITION BY grp ORDER BY val RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS running_sum,
+        MAX(id) OVER (PARTITION BY grp ORDER BY val RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS max_id
+      FROM df
+      ORDER BY id
+    "#;
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_column(&out, Series::new("running_sum", [9, 1, 9, 3, 14, 14, 14]));
+    assert_column(&out, Series::new("max_id", [3, 4, 3, 4, 7, 6, 7]));
+
+    // 2024-01-01, 2024-01-02, 2024-01-04, 2024-01-05, 2024-01-05
+    let dt = Series::new("dt", [19723, 19724, 19726, 19727, 19727])
+        .cast(&DataType::Date)
+        .unwrap();
+    let df = DataFrame::new(vec![dt, Series::new("v", [1, 2, 3, 4, 5])]).unwrap();
+    ctx.register("ts", df.lazy());
+    let sql = r#"
+      SELECT
+        v,
+        SUM(v) OVER (ORDER BY dt RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW) AS sum_3d
+      FROM ts
+      ORDER BY v
+    "#;
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_column(&out, Series::new("sum_3d", [1, 3, 5, 12, 12]));
+}
+
+#[test]
+fn test_named_windows() {
+    let sql = r#"
+      SELECT
+        id,
+        ROW_NUMBER() OVER w AS rn,
+        SUM(val) OVER (w ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS pair_sum,
+        MAX(val) OVER w_all AS max_val
+      FROM df
+      WINDOW w_all AS (PARTITION BY grp), w AS (w_all ORDER BY id)
+      ORDER BY id
+    "#;
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+
+    assert_column(&out, Series::new("rn", [1, 2, 3, 4, 1, 2, 3]));
+    assert_column(&out, Series::new("pair_sum", [3, 4, 4, 5, 7, 7, 7]));
+    assert_column(&out, Series::new("max_val", [3, 3, 3, 3, 7, 7, 7]));
+}
+
+#[test]
+fn test_window_frame_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT SUM(val) OVER w AS s FROM df",
+        "SELECT SUM(val) OVER (w ORDER BY id) AS s FROM df WINDOW w AS (PARTITION BY grp ORDER BY val)",
+        "SELECT SUM(val) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS s FROM df",
+        "SELECT SUM(val) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND CURRENT ROW) AS s FROM df",
+        "SELECT MEDIAN(val) OVER (ORDER BY id ROWS UNBOUNDED PRECEDING) AS s FROM df",
+        "SELECT FIRST_VALUE(val) OVER (ORDER BY id ROWS 1 PRECEDING) AS s FROM df",
+        "SELECT COUNT(DISTINCT val) OVER (ORDER BY id ROWS 1 PRECEDING) AS s FROM df",
+        "SELECT LAST(val) OVER (ORDER BY id ROWS 1 PRECEDING) AS s FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,121 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "grp" => ["a", "a", "a", "b", "b"],
+      "val" => [1, 2, 3, 4, 5],
+      "flag" => [Some(true), Some(false), Some(true), None, Some(false)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+#[test]
+fn test_aggregate_filter() {
+    let sql = r#"
+      SELECT
+        grp,
+        SUM(val) FILTER (WHERE flag) AS flagged,
+        COUNT(*) FILTER (WHERE val > 1) AS n,
+        COUNT(DISTINCT val) FILTER (WHERE val > 3) AS n_distinct,
+        MAX(val) FILTER (WHERE val > 10) AS none
+      FROM df
+      GROUP BY grp
+      ORDER BY grp
+    "#;
+    let expected = df! {
+      "grp" => ["a", "b"],
+      "flagged" => [Some(4), None],
+      "n" => [2 as IdxSize, 2],
+      "n_distinct" => [0 as IdxSize, 2],
+      "none" => [None::<i32>, None],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Without GROUP BY and as a window aggregate.
+    let sql = "SELECT COUNT(val) FILTER (WHERE NOT flag) AS n FROM df";
+    assert_sql_eq(sql, df! { "n" => [2 as IdxSize] }.unwrap());
+
+    let sql = r#"
+      SELECT val, SUM(val) FILTER (WHERE val % 2 = 1) OVER (PARTITION BY grp) AS odd
+      FROM df
+      ORDER BY val
+    "#;
+    let expected = df! {
+      "val" => [1, 2, 3, 4, 5],
+      "odd" => [4, 4, 4, 5, 5],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_qualify() {
+    let sql = r#"
+      SELECT grp, val
+      FROM df
+      QUALIFY ROW_NUMBER() OVER (PARTITION BY grp ORDER BY val DESC) = 1
+      ORDER BY grp
+    "#;
+    let expected = df! {
+      "grp" => ["a", "b"],
+      "val" => [3, 5],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // The aliases of the projection can be referenced.
+    let sql = r#"
+      SELECT val, SUM(val) OVER (PARTITION BY grp) AS total
+      FROM df
+      WHERE val > 1
+      QUALIFY total > 5
+    "#;
+    let expected = df! {
+      "val" => [4, 5],
+      "total" => [9, 9],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // After the aggregation of a GROUP BY.
+    let sql = r#"
+      SELECT grp, SUM(val) AS total
+      FROM df
+      GROUP BY grp
+      QUALIFY RANK() OVER (ORDER BY total DESC) = 1
+    "#;
+    let expected = df! {
+      "grp" => ["b"],
+      "total" => [9],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_filter_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT UPPER(grp) FILTER (WHERE flag) FROM df",
+        "SELECT SUM(val) FILTER (WHERE flag) OVER (PARTITION BY grp ORDER BY val) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    for sql in [
+        "SELECT SUM(val) FILTER (WHERE missing) FROM df",
+        "SELECT val FROM df QUALIFY missing > 1",
+    ] {
+        assert!(ctx.execute(sql).unwrap().collect().is_err(), "{sql}");
+    }
+}
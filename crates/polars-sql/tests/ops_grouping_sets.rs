@@ -0,0 +1,136 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "region" => ["eu", "eu", "us", "us", "us"],
+      "product" => ["a", "b", "a", "a", "b"],
+      "sales" => [1, 2, 3, 4, 5],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}");
+}
+
+#[test]
+fn test_rollup() {
+    let expected = df! {
+      "region" => [Some("eu"), Some("eu"), Some("eu"), Some("us"), Some("us"), Some("us"), None],
+      "product" => [Some("a"), Some("b"), None, Some("a"), Some("b"), None, None],
+      "total" => [1, 2, 3, 7, 5, 12, 15],
+    }
+    .unwrap();
+    for sql in [
+        r#"
+        SELECT region, product, SUM(sales) AS total
+        FROM df
+        GROUP BY ROLLUP (region, product)
+        ORDER BY region NULLS LAST, product NULLS LAST
+        "#,
+        r#"
+        SELECT region, product, SUM(sales) AS total
+        FROM df
+        GROUP BY region, product WITH ROLLUP
+        ORDER BY region NULLS LAST, product NULLS LAST
+        "#,
+        r#"
+        SELECT region, product, SUM(sales) AS total
+        FROM df
+        GROUP BY GROUPING SETS ((region, product), (region), ())
+        ORDER BY region NULLS LAST, product NULLS LAST
+        "#,
+    ] {
+        assert_sql_eq(sql, expected.clone());
+    }
+}
+
+#[test]
+fn test_cube_with_grouping() {
+    let sql = r#"
+      SELECT
+        region,
+        product,
+        GROUPING(region, product) AS lvl,
+        CASE WHEN GROUPING(product) = 1 THEN 'all' ELSE product END AS label,
+        COUNT(*) AS n
+      FROM df
+      GROUP BY CUBE (region, product)
+      HAVING n > 1
+      ORDER BY lvl, region, product
+    "#;
+    let expected = df! {
+      "region" => [Some("us"), Some("eu"), Some("us"), None, None, None],
+      "product" => [Some("a"), None, None, Some("a"), Some("b"), None],
+      "lvl" => [0u32, 1, 1, 2, 2, 3],
+      "label" => ["a", "all", "all", "a", "b", "all"],
+      "n" => [2 as IdxSize, 2, 3, 3, 2, 5],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_partial_rollup() {
+    // Grouping sets are combined with the plain keys: (region, product) and (region).
+    let sql = r#"
+      SELECT region, product, MAX(sales) AS top, GROUPING(product) AS g
+      FROM df
+      GROUP BY region, ROLLUP (product)
+      ORDER BY region, product NULLS LAST
+    "#;
+    let expected = df! {
+      "region" => ["eu", "eu", "eu", "us", "us", "us"],
+      "product" => [Some("a"), Some("b"), None, Some("a"), Some("b"), None],
+      "top" => [1, 2, 2, 4, 5, 5],
+      "g" => [0u32, 0, 1, 0, 0, 1],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Without grouping sets every key is part of every group.
+    let sql = "SELECT region, GROUPING(region) AS g FROM df GROUP BY region ORDER BY region";
+    let expected = df! {
+      "region" => ["eu", "us"],
+      "g" => [0u32, 0],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_grouping_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT GROUPING(region) FROM df",
+        "SELECT region, GROUPING(product) FROM df GROUP BY ROLLUP (region)",
+        "SELECT region, SUM(sales) FROM df GROUP BY ROLLUP (region) HAVING GROUPING(region) = 0",
+        "SELECT region, SUM(sales) FROM df GROUP BY ROLLUP (region) WITH CUBE",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn test_grouping_sets_limit() {
+    // Fails before building the 2^40 grouping sets.
+    let keys = vec!["region"; 40].join(", ");
+    let sql = format!("SELECT SUM(sales) FROM df GROUP BY CUBE ({keys})");
+    assert!(create_ctx().execute(&sql).is_err());
+
+    // The cross product of two cubes of 10 keys has 2^20 grouping sets.
+    let keys = ["region"; 10].join(", ");
+    let sql = format!("SELECT SUM(sales) FROM df GROUP BY CUBE ({keys}), CUBE ({keys})");
+    assert!(create_ctx().execute(&sql).is_err());
+
+    let keys = ["region"; 12].join(", ");
+    let sql = format!("SELECT SUM(sales) AS s FROM df GROUP BY CUBE ({keys})");
+    let out = create_ctx().execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(out.height(), 2 * 4095 + 1);
+}
@@ -0,0 +1,117 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+      "region" => ["eu", "eu", "us", "us", "us"],
+      "quarter" => ["q1", "q2", "q1", "q1", "q3"],
+      "amount" => [1, 2, 3, 4, 5],
+    }
+    .unwrap();
+    let quarterly = df! {
+      "region" => ["eu", "us"],
+      "q1" => [Some(1), Some(7)],
+      "q2" => [Some(2), None],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx.register("quarterly", quarterly.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+#[test]
+fn test_pivot() {
+    let sql = r#"
+      SELECT * FROM sales
+      PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2', 'q4'))
+      ORDER BY region
+    "#;
+    let expected = df! {
+      "region" => ["eu", "us"],
+      "q1" => [Some(1), Some(7)],
+      "q2" => [Some(2), None],
+      "q4" => [None::<i32>, None],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    let sql = r#"
+      SELECT p.region, p.first_n, p.first_top
+      FROM sales
+      PIVOT (COUNT(*) AS n, MAX(amount) AS top FOR quarter IN ('q1' AS first, 'q3') DEFAULT ON NULL (0)) AS p
+      ORDER BY p.region
+    "#;
+    let expected = df! {
+      "region" => ["eu", "us"],
+      "first_n" => [1 as IdxSize, 2],
+      "first_top" => [1, 4],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Without remaining columns, the result has a single row.
+    let sql = r#"
+      SELECT * FROM (SELECT quarter, amount FROM sales) AS s
+      PIVOT (AVG(amount) FOR quarter IN ('q1', 'q3'))
+    "#;
+    let expected = df! {
+      "q1" => [Some(8.0 / 3.0)],
+      "q3" => [Some(5.0)],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_unpivot() {
+    let sql = r#"
+      SELECT * FROM quarterly
+      UNPIVOT (amount FOR quarter IN (q1, q2))
+      ORDER BY region, quarter
+    "#;
+    let expected = df! {
+      "region" => ["eu", "eu", "us"],
+      "quarter" => ["q1", "q2", "q1"],
+      "amount" => [1, 2, 7],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Unpivoting a pivot is a round trip.
+    let sql = r#"
+      SELECT u.* FROM (
+        SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2'))
+      ) AS p UNPIVOT (total FOR qtr IN (q1, q2)) AS u (r, q, total)
+      ORDER BY r, q
+    "#;
+    let expected = df! {
+      "r" => ["eu", "eu", "us"],
+      "q" => ["q1", "q2", "q1"],
+      "total" => [1, 2, 7],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_pivot_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR missing IN ('q1'))",
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY))",
+        "SELECT * FROM sales PIVOT (SUM(amount), MAX(amount) FOR quarter IN ('q1'))",
+        "SELECT * FROM quarterly UNPIVOT (amount FOR quarter IN (q1, missing))",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    let sql = "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY ORDER BY quarter))";
+    let err = ctx.execute(sql).err().unwrap().to_string();
+    assert!(err.contains("explicit list of values"), "{err}");
+}
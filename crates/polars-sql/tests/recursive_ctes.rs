@@ -0,0 +1,131 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let employees = df! {
+      "id" => [1, 2, 3, 4, 5],
+      "name" => ["ceo", "cto", "dev1", "dev2", "cfo"],
+      "manager_id" => [None, Some(1), Some(2), Some(3), Some(1)],
+    }
+    .unwrap();
+    let edges = df! {
+      "src" => ["a", "b", "c", "c"],
+      "dst" => ["b", "c", "a", "d"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+    ctx.register("edges", edges.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+#[test]
+fn test_recursive_cte_series() {
+    let sql = r#"
+      WITH RECURSIVE t(n) AS (
+        SELECT 1
+        UNION ALL
+        SELECT n + 1 FROM t WHERE n < 5
+      )
+      SELECT n FROM t
+    "#;
+    assert_sql_eq(sql, df! { "n" => [1, 2, 3, 4, 5] }.unwrap());
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let sql = r#"
+      WITH RECURSIVE reports AS (
+        SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+        UNION ALL
+        SELECT e.id, e.name, r.depth + 1
+        FROM employees e JOIN reports r ON e.manager_id = r.id
+      )
+      SELECT name, depth FROM reports ORDER BY depth, name
+    "#;
+    let expected = df! {
+      "name" => ["ceo", "cfo", "cto", "dev1", "dev2"],
+      "depth" => [0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Non-recursive CTEs can be mixed with recursive ones.
+    let sql = r#"
+      WITH RECURSIVE
+        roots AS (SELECT id FROM employees WHERE name = 'cto'),
+        tree AS (
+          SELECT id FROM roots
+          UNION ALL
+          SELECT e.id FROM employees e JOIN tree ON e.manager_id = tree.id
+        )
+      SELECT COUNT(*) AS n FROM tree
+    "#;
+    assert_sql_eq(sql, df! { "n" => [3 as IdxSize] }.unwrap());
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() {
+    // With UNION, the traversal of a cyclic graph terminates.
+    let sql = r#"
+      WITH RECURSIVE reachable(node) AS (
+        SELECT 'a'
+        UNION
+        SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+      )
+      SELECT node FROM reachable ORDER BY node
+    "#;
+    assert_sql_eq(sql, df! { "node" => ["a", "b", "c", "d"] }.unwrap());
+}
+
+#[test]
+fn test_recursive_cte_errors() {
+    let mut ctx = create_ctx();
+    // With UNION ALL, the traversal of a cyclic graph does not terminate.
+    let sql = r#"
+      WITH RECURSIVE reachable(node) AS (
+        SELECT 'a'
+        UNION ALL
+        SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+      )
+      SELECT node FROM reachable
+    "#;
+    let err = ctx.execute(sql).unwrap().collect().unwrap_err().to_string();
+    assert!(
+        err.contains("did not terminate after 1000 iterations"),
+        "{err}"
+    );
+
+    // The maximum number of iterations can be configured.
+    let sql = r#"
+      WITH RECURSIVE t(n) AS (
+        SELECT 1
+        UNION ALL
+        SELECT n + 1 FROM t WHERE n < 10
+      )
+      SELECT n FROM t
+    "#;
+    let mut ctx = create_ctx().with_recursive_cte_max_iterations(5);
+    let err = ctx.execute(sql).unwrap().collect().unwrap_err().to_string();
+    assert!(
+        err.contains("did not terminate after 5 iterations"),
+        "{err}"
+    );
+    let mut ctx = create_ctx().with_recursive_cte_max_iterations(10);
+    assert_eq!(ctx.execute(sql).unwrap().collect().unwrap().height(), 10);
+
+    for sql in [
+        "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 AS a, n AS b FROM t WHERE n < 3) SELECT * FROM t",
+        "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 3 LIMIT 2) SELECT * FROM t",
+        // Without RECURSIVE, a CTE cannot reference itself.
+        "WITH t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 3) SELECT * FROM t",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,218 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let items = df! {
+      "id" => [1, 2, 3],
+      "name" => ["a", "b", "c"],
+      "qty" => [10, 20, 30],
+    }
+    .unwrap();
+    let updates = df! {
+      "id" => [2i64, 3, 4],
+      "qty" => [0, 35, 40],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("items", items.lazy());
+    ctx.register("updates", updates.lazy());
+    ctx
+}
+
+/// Execute the statements in order, then query the `items` table.
+fn execute_all(ctx: &mut SQLContext, statements: &[&str]) -> DataFrame {
+    for sql in statements {
+        ctx.execute(sql).unwrap().collect().unwrap();
+    }
+    ctx.execute("SELECT * FROM items ORDER BY id")
+        .unwrap()
+        .collect()
+        .unwrap()
+}
+
+fn assert_items_eq(statements: &[&str], expected: DataFrame) {
+    let out = execute_all(&mut create_ctx(), statements);
+    assert!(out.equals_missing(&expected), "{statements:?}\n{out:?}");
+}
+
+#[test]
+fn test_insert() {
+    assert_items_eq(
+        &[
+            "INSERT INTO items VALUES (4, 'd', 40)",
+            "INSERT INTO items (qty, id) VALUES (50, 5), (60, 6)",
+            "INSERT INTO items SELECT id + 10, 'x', qty FROM updates WHERE qty > 35",
+        ],
+        df! {
+          "id" => [1, 2, 3, 4, 5, 6, 14],
+          "name" => [Some("a"), Some("b"), Some("c"), Some("d"), None, None, Some("x")],
+          "qty" => [10, 20, 30, 40, 50, 60, 40],
+        }
+        .unwrap(),
+    );
+    assert_items_eq(
+        &["INSERT OVERWRITE TABLE items SELECT id, 'new', qty FROM updates"],
+        df! {
+          "id" => [2, 3, 4],
+          "name" => ["new", "new", "new"],
+          "qty" => [0, 35, 40],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_update() {
+    assert_items_eq(
+        &[
+            "UPDATE items SET qty = qty + 1, name = upper(name) WHERE id >= 2",
+            "UPDATE items AS i SET qty = i.qty * 2 WHERE i.name = 'a'",
+        ],
+        df! {
+          "id" => [1, 2, 3],
+          "name" => ["a", "B", "C"],
+          "qty" => [20, 21, 31],
+        }
+        .unwrap(),
+    );
+    // With a correlated subquery
+    assert_items_eq(
+        &[r#"
+          UPDATE items SET qty = (SELECT u.qty FROM updates u WHERE u.id = items.id)
+          WHERE EXISTS (SELECT 1 FROM updates u WHERE u.id = items.id)
+        "#],
+        df! {
+          "id" => [1, 2, 3],
+          "name" => ["a", "b", "c"],
+          "qty" => [10, 0, 35],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_delete() {
+    assert_items_eq(
+        &[
+            "DELETE FROM items WHERE id IN (SELECT id FROM updates WHERE qty = 0)",
+            "DELETE FROM items WHERE name IS NULL",
+        ],
+        df! {
+          "id" => [1, 3],
+          "name" => ["a", "c"],
+          "qty" => [10, 30],
+        }
+        .unwrap(),
+    );
+    assert_items_eq(
+        &["DELETE FROM items"],
+        df! {
+          "id" => Vec::<i32>::new(),
+          "name" => Vec::<&str>::new(),
+          "qty" => Vec::<i32>::new(),
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge() {
+    assert_items_eq(
+        &[r#"
+          MERGE INTO items t
+          USING updates s
+          ON t.id = s.id
+          WHEN MATCHED AND s.qty = 0 THEN DELETE
+          WHEN MATCHED THEN UPDATE SET qty = s.qty
+          WHEN NOT MATCHED THEN INSERT (id, name, qty) VALUES (s.id, 'new', s.qty)
+        "#],
+        df! {
+          "id" => [1, 3, 4],
+          "name" => ["a", "c", "new"],
+          "qty" => [10, 35, 40],
+        }
+        .unwrap(),
+    );
+    assert_items_eq(
+        &[r#"
+          MERGE INTO items
+          USING (SELECT id AS key, qty FROM updates) AS s
+          ON items.id = s.key
+          WHEN NOT MATCHED BY SOURCE THEN UPDATE SET qty = 0
+          WHEN NOT MATCHED THEN INSERT (id, qty) VALUES (key, s.qty)
+        "#],
+        df! {
+          "id" => [1, 2, 3, 4],
+          "name" => [Some("a"), Some("b"), Some("c"), None],
+          "qty" => [0, 20, 30, 40],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge_duplicate_source_keys() {
+    // Duplicate source rows are inserted if they don't match a target row.
+    assert_items_eq(
+        &[r#"
+          MERGE INTO items t
+          USING (SELECT * FROM updates UNION ALL SELECT 4 AS id, 45 AS qty) AS s
+          ON t.id = s.id
+          WHEN MATCHED THEN UPDATE SET qty = s.qty
+          WHEN NOT MATCHED THEN INSERT (id, qty) VALUES (s.id, s.qty)
+        "#],
+        df! {
+          "id" => [1, 2, 3, 4, 4],
+          "name" => [Some("a"), Some("b"), Some("c"), None, None],
+          "qty" => [10, 0, 35, 40, 45],
+        }
+        .unwrap(),
+    );
+
+    // But a target row cannot match more than one source row.
+    let mut ctx = create_ctx();
+    let sql = r#"
+      MERGE INTO items t
+      USING (SELECT * FROM updates UNION ALL SELECT 2 AS id, 25 AS qty) AS s
+      ON t.id = s.id
+      WHEN MATCHED THEN UPDATE SET qty = s.qty
+    "#;
+    assert!(ctx.execute(sql).unwrap().collect().is_ok());
+    let err = ctx
+        .execute("SELECT * FROM items")
+        .unwrap()
+        .collect()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("more than one source row"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_dml_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "INSERT INTO missing VALUES (1)",
+        "INSERT INTO items VALUES (1, 'a')",
+        "INSERT INTO items (id, missing) VALUES (1, 2)",
+        "UPDATE items SET missing = 1",
+        "UPDATE items SET qty = 1 FROM updates",
+        "DELETE FROM items USING updates",
+        // Unqualified columns of both tables are ambiguous.
+        "MERGE INTO items t USING updates s ON t.id = s.id WHEN MATCHED THEN UPDATE SET qty = qty",
+        "MERGE INTO items t USING updates s ON t.id > s.id WHEN MATCHED THEN DELETE",
+        "MERGE INTO items t USING updates s ON t.id = s.id WHEN NOT MATCHED THEN DELETE",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    // Values that cannot be cast to the column type.
+    let sql = "INSERT INTO items VALUES ('x', 'y', 'z')";
+    assert!(ctx.execute(sql).unwrap().collect().is_ok());
+    assert!(ctx
+        .execute("SELECT * FROM items")
+        .unwrap()
+        .collect()
+        .is_err());
+}
@@ -0,0 +1,227 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["a", "b", "c", "d"],
+      "region" => [Some("eu"), Some("us"), Some("eu"), None],
+    }
+    .unwrap();
+    let orders = df! {
+      "cust_id" => [1, 1, 2, 5],
+      "amount" => [10, 20, 5, 7],
+    }
+    .unwrap();
+    let employees = df! {
+      "name" => ["x", "y", "z", "w"],
+      "dept" => ["eng", "eng", "ops", "ops"],
+      "salary" => [100, 200, 50, 50],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx.register("employees", employees.lazy());
+    ctx
+}
+
+fn assert_sql_eq(sql: &str, expected: DataFrame) {
+    let out = create_ctx().execute(sql).unwrap().collect().unwrap();
+    assert!(out.equals_missing(&expected), "{sql}\n{out:?}");
+}
+
+fn assert_names_eq(sql: &str, names: &[&str]) {
+    assert_sql_eq(sql, df! { "name" => names }.unwrap());
+}
+
+#[test]
+fn test_exists() {
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id)
+        ORDER BY name
+        "#,
+        &["a", "b"],
+    );
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE NOT EXISTS (SELECT * FROM orders WHERE cust_id = id)
+        ORDER BY name
+        "#,
+        &["c", "d"],
+    );
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id AND o.amount > 10)
+          AND region = 'eu'
+        "#,
+        &["a"],
+    );
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE region = 'us' OR NOT EXISTS (SELECT 1 FROM orders o WHERE c.id = o.cust_id)
+        ORDER BY name
+        "#,
+        &["b", "c", "d"],
+    );
+    // Uncorrelated
+    assert_names_eq(
+        "SELECT name FROM customers WHERE EXISTS (SELECT 1 FROM orders WHERE amount > 100)",
+        &[],
+    );
+}
+
+#[test]
+fn test_exists_in_select() {
+    let sql = r#"
+      SELECT
+        name,
+        EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id) AS has_orders
+      FROM customers c
+      ORDER BY name
+    "#;
+    let expected = df! {
+      "name" => ["a", "b", "c", "d"],
+      "has_orders" => [true, true, false, false],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+}
+
+#[test]
+fn test_correlated_in() {
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE c.id * 10 IN (SELECT amount FROM orders o WHERE o.cust_id = c.id)
+        "#,
+        &["a"],
+    );
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers c
+        WHERE c.id * 10 NOT IN (SELECT amount FROM orders o WHERE o.cust_id = c.id)
+        ORDER BY name
+        "#,
+        &["b", "c", "d"],
+    );
+}
+
+#[test]
+fn test_correlated_in_nulls() {
+    // The expected results are those of PostgreSQL.
+    let outer = df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "grp" => [Some("a"), Some("a"), Some("b"), Some("c"), None, Some("a")],
+      "x" => [Some(1), None, Some(1), None, Some(1), Some(3)],
+    }
+    .unwrap();
+    let inner = df! {
+      "grp" => ["a", "a", "b", "b"],
+      "y" => [Some(1), Some(2), None, Some(2)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("o", outer.lazy());
+    ctx.register("i", inner.lazy());
+    let mut execute = |sql: &str| ctx.execute(sql).unwrap().collect().unwrap();
+
+    let out = execute(
+        r#"
+        SELECT id FROM o
+        WHERE x NOT IN (SELECT y FROM i WHERE i.grp = o.grp)
+        ORDER BY id
+        "#,
+    );
+    assert!(out.equals(&df! { "id" => [4, 5, 6] }.unwrap()), "{out:?}");
+
+    let out = execute(
+        r#"
+        SELECT id FROM o
+        WHERE x IN (SELECT y FROM i WHERE i.grp = o.grp)
+        ORDER BY id
+        "#,
+    );
+    assert!(out.equals(&df! { "id" => [1] }.unwrap()), "{out:?}");
+
+    let out = execute(
+        r#"
+        SELECT
+          id,
+          x IN (SELECT y FROM i WHERE i.grp = o.grp) AS is_in,
+          x NOT IN (SELECT y FROM i WHERE i.grp = o.grp) AS not_in
+        FROM o
+        ORDER BY id
+        "#,
+    );
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "is_in" => [Some(true), None, None, Some(false), Some(false), Some(false)],
+      "not_in" => [Some(false), None, None, Some(true), Some(true), Some(true)],
+    }
+    .unwrap();
+    assert!(out.equals_missing(&expected), "{out:?}");
+}
+
+#[test]
+fn test_scalar_subquery() {
+    let sql = r#"
+      SELECT
+        name,
+        (SELECT SUM(amount) FROM orders o WHERE o.cust_id = c.id) AS total,
+        (SELECT COUNT(*) FROM orders o WHERE o.cust_id = c.id) AS n,
+        (SELECT MAX(amount) FROM orders) AS top
+      FROM customers c
+      ORDER BY name
+    "#;
+    let expected = df! {
+      "name" => ["a", "b", "c", "d"],
+      "total" => [Some(30), Some(5), None, None],
+      "n" => [2 as IdxSize, 1, 0, 0],
+      "top" => [20, 20, 20, 20],
+    }
+    .unwrap();
+    assert_sql_eq(sql, expected);
+
+    // Compare with the average salary of the same department.
+    assert_names_eq(
+        r#"
+        SELECT name FROM employees e1
+        WHERE salary > (SELECT AVG(salary) FROM employees e2 WHERE e2.dept = e1.dept)
+        "#,
+        &["y"],
+    );
+    assert_names_eq(
+        r#"
+        SELECT name FROM customers
+        WHERE id = (SELECT MAX(cust_id) FROM orders WHERE cust_id < 5)
+        "#,
+        &["b"],
+    );
+}
+
+#[test]
+fn test_subquery_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        // Correlated predicates must be equalities.
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id > c.id)",
+        // Correlated subqueries cannot group.
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id GROUP BY o.amount)",
+        // Outer columns can only be referenced in the WHERE clause.
+        "SELECT (SELECT c.id + amount FROM orders o WHERE o.cust_id = c.id) FROM customers c",
+        "SELECT name FROM customers c ORDER BY (SELECT COUNT(*) FROM orders o WHERE o.cust_id = c.id)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+
+    // A scalar subquery returns at most one row per outer row.
+    let sql = "SELECT name, (SELECT amount FROM orders o WHERE o.cust_id = c.id) FROM customers c";
+    assert!(ctx.execute(sql).unwrap().collect().is_err());
+}
@@ -39,3 +39,22 @@ impl StreamExpr {
         }
     }
 }
+
+/// Evaluates the expressions on the dataframe, broadcasting unit-length results
+/// (e.g. literals) to the height of the dataframe.
+pub(crate) async fn evaluate_broadcast(
+    exprs: &[StreamExpr],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Series>> {
+    let mut out = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        let s = expr.evaluate(df, state).await?;
+        if s.len() == 1 && df.height() != 1 {
+            out.push(s.new_from_index(0, df.height()));
+        } else {
+            out.push(s);
+        }
+    }
+    Ok(out)
+}
@@ -2,11 +2,13 @@ mod async_executor;
 mod async_primitives;
 mod skeleton;
 
+pub use memory::with_force_spill;
 pub use skeleton::run_query;
 
 mod execute;
 pub(crate) mod expression;
 mod graph;
+mod memory;
 mod morsel;
 mod nodes;
 mod physical_plan;
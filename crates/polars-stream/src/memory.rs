@@ -0,0 +1,315 @@
+//! Accounting of the memory held by streaming operators, and spilling of
+//! their state to disk once the memory limit is hit.
+use std::cell::Cell;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+use polars_core::config::verbose;
+use polars_core::frame::DataFrame;
+use polars_error::{polars_err, PolarsResult};
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_io::{SerReader, SerWriter};
+use polars_utils::sys::MEMINFO;
+
+static MEMORY_MANAGER: OnceLock<Result<MemoryManager, String>> = OnceLock::new();
+
+thread_local! {
+    /// Whether the operators registered on this thread are asked to spill as
+    /// soon as they can, see [`with_force_spill`].
+    static FORCE_SPILL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` with the operators of the queries it runs on the current thread
+/// asked to spill as soon as they can, like `POLARS_FORCE_OOC` does for all
+/// queries.
+pub fn with_force_spill<R>(f: impl FnOnce() -> R) -> R {
+    struct Reset(bool);
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            FORCE_SPILL.set(self.0);
+        }
+    }
+
+    let _reset = Reset(FORCE_SPILL.replace(true));
+    f()
+}
+
+/// Returns the memory manager shared by all streaming queries.
+///
+/// The limit is set in bytes with `POLARS_MEMORY_LIMIT`, it defaults to three
+/// quarters of the memory available when the first query runs.
+pub fn memory_manager() -> PolarsResult<&'static MemoryManager> {
+    MEMORY_MANAGER
+        .get_or_init(|| Ok(MemoryManager::new(Some(memory_limit()?))))
+        .as_ref()
+        .map_err(|e| polars_err!(InvalidOperation: "{e}"))
+}
+
+fn memory_limit() -> Result<usize, String> {
+    match std::env::var("POLARS_MEMORY_LIMIT") {
+        Ok(limit) => limit
+            .trim()
+            .parse()
+            .map_err(|_| format!("POLARS_MEMORY_LIMIT must be a number of bytes, got '{limit}'")),
+        Err(_) => Ok(MEMINFO.free() as usize / 4 * 3),
+    }
+}
+
+struct ReservationState {
+    name: &'static str,
+    bytes: AtomicUsize,
+    peak: AtomicUsize,
+    spill_requested: AtomicBool,
+}
+
+/// Keeps track of the memory reserved by the registered operators. Once the
+/// total exceeds the limit, the operators holding the most memory are asked to
+/// spill.
+pub struct MemoryManager {
+    limit: Option<usize>,
+    used: AtomicUsize,
+    /// The usage above which spilling is requested next. Requests are only
+    /// repeated once the usage grew by a step, or dropped below the limit.
+    next_spill_request: AtomicUsize,
+    reservations: Mutex<Vec<Arc<ReservationState>>>,
+}
+
+impl MemoryManager {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+            next_spill_request: AtomicUsize::new(limit.unwrap_or(usize::MAX)),
+            reservations: Mutex::default(),
+        }
+    }
+
+    /// The growth in usage after which spilling is requested again.
+    fn spill_request_step(&self) -> usize {
+        const MIN_STEP: usize = 1 << 20;
+        self.limit
+            .map_or(usize::MAX, |limit| (limit / 16).max(MIN_STEP))
+    }
+
+    /// Called after `used` changed, requests spilling if needed.
+    fn on_usage_change(&self, used: usize) {
+        let Some(limit) = self.limit else {
+            return;
+        };
+        if used <= limit {
+            self.next_spill_request.store(limit, Ordering::Relaxed);
+        } else if used > self.next_spill_request.load(Ordering::Relaxed) {
+            self.next_spill_request.store(
+                used.saturating_add(self.spill_request_step()),
+                Ordering::Relaxed,
+            );
+            self.request_spill(used - limit);
+        }
+    }
+
+    /// Registers an operator which can spill its state to disk. If
+    /// `POLARS_FORCE_OOC` is set or the operator is registered within
+    /// [`with_force_spill`], it is asked to spill as soon as it can.
+    pub fn register(&'static self, name: &'static str) -> MemoryReservation {
+        let state = Arc::new(ReservationState {
+            name,
+            bytes: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            spill_requested: AtomicBool::new(false),
+        });
+        self.reservations.lock().push(state.clone());
+        MemoryReservation {
+            manager: self,
+            state,
+            force_spill: FORCE_SPILL.get() || std::env::var("POLARS_FORCE_OOC").is_ok(),
+        }
+    }
+
+    /// Returns the name and reserved bytes of every registered operator.
+    pub fn reservations(&self) -> Vec<(&'static str, usize)> {
+        self.reservations
+            .lock()
+            .iter()
+            .map(|r| (r.name, r.bytes.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Asks the operators with the largest reservations which are not already
+    /// spilling to spill, until they hold at least `excess` bytes.
+    fn request_spill(&self, excess: usize) {
+        // Another thread is already requesting spills.
+        let Some(reservations) = self.reservations.try_lock() else {
+            return;
+        };
+        let mut candidates: Vec<_> = reservations
+            .iter()
+            .filter(|r| !r.spill_requested.load(Ordering::Relaxed))
+            .map(|r| (r.bytes.load(Ordering::Relaxed), r.clone()))
+            .filter(|(bytes, _)| *bytes > 0)
+            .collect();
+        drop(reservations);
+        candidates.sort_unstable_by_key(|(bytes, _)| std::cmp::Reverse(*bytes));
+
+        let mut requested = 0;
+        for (bytes, r) in candidates {
+            if requested >= excess {
+                break;
+            }
+            requested += bytes;
+            if !r.spill_requested.swap(true, Ordering::Relaxed) && verbose() {
+                eprintln!(
+                    "memory limit exceeded, requesting {} to spill, reservations: {:?}",
+                    r.name,
+                    self.reservations()
+                );
+            }
+        }
+    }
+}
+
+/// The memory reserved by a single operator. It is released when dropped.
+pub struct MemoryReservation {
+    manager: &'static MemoryManager,
+    state: Arc<ReservationState>,
+    force_spill: bool,
+}
+
+impl MemoryReservation {
+    /// Reserves `bytes` more, which may request operators to spill.
+    pub fn grow(&self, bytes: usize) {
+        let reserved = self.state.bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.state.peak.fetch_max(reserved, Ordering::Relaxed);
+        let used = self.manager.used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.manager.on_usage_change(used);
+    }
+
+    /// Releases `bytes` of the reservation, which satisfies a spill request.
+    pub fn shrink(&self, bytes: usize) {
+        self.state.bytes.fetch_sub(bytes, Ordering::Relaxed);
+        self.state.spill_requested.store(false, Ordering::Relaxed);
+        let used = self.manager.used.fetch_sub(bytes, Ordering::Relaxed) - bytes;
+        self.manager.on_usage_change(used);
+    }
+
+    /// Releases the whole reservation.
+    pub fn release(&self) {
+        let bytes = self.state.bytes.swap(0, Ordering::Relaxed);
+        self.state.spill_requested.store(false, Ordering::Relaxed);
+        let used = self.manager.used.fetch_sub(bytes, Ordering::Relaxed) - bytes;
+        self.manager.on_usage_change(used);
+    }
+
+    /// Whether the operator was asked to spill.
+    pub fn spill_requested(&self) -> bool {
+        self.force_spill || self.state.spill_requested.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.release();
+        self.manager
+            .reservations
+            .lock()
+            .retain(|r| !Arc::ptr_eq(r, &self.state));
+        if verbose() {
+            eprintln!(
+                "{} reserved at most {} bytes",
+                self.state.name,
+                self.state.peak.load(Ordering::Relaxed)
+            );
+        }
+    }
+}
+
+/// A directory holding the spilled dataframes of an operator, which is removed
+/// when dropped.
+pub struct SpillDir {
+    path: PathBuf,
+    num_files: AtomicUsize,
+}
+
+impl SpillDir {
+    fn new(name: &str) -> PolarsResult<Self> {
+        let path = POLARS_TEMP_DIR_BASE_PATH.join(format!(
+            "{name}-{}-{:016x}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::create_dir_all(&path)?;
+        if verbose() {
+            eprintln!("spilling {name} to {}", path.display());
+        }
+        Ok(Self {
+            path,
+            num_files: AtomicUsize::new(0),
+        })
+    }
+
+    /// Writes a dataframe to a new file in this directory.
+    pub fn spill(&self, mut df: DataFrame) -> PolarsResult<Spilled> {
+        let idx = self.num_files.fetch_add(1, Ordering::Relaxed);
+        let path = self.path.join(format!("{idx}.ipc"));
+        IpcWriter::new(File::create(&path)?).finish(&mut df)?;
+        Ok(Spilled::OnDisk(path))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A spill directory which is only created once something is spilled.
+pub struct LazySpillDir {
+    name: &'static str,
+    dir: OnceLock<PolarsResult<Arc<SpillDir>>>,
+}
+
+impl LazySpillDir {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            dir: OnceLock::new(),
+        }
+    }
+
+    /// Returns the directory, creating it on first use.
+    pub fn get(&self) -> PolarsResult<&Arc<SpillDir>> {
+        self.dir
+            .get_or_init(|| SpillDir::new(self.name).map(Arc::new))
+            .as_ref()
+            .map_err(|e| polars_err!(ComputeError: "could not create spill directory: {e}"))
+    }
+
+    /// Returns the directory if it was created. Spilled files are only
+    /// readable as long as a reference to it is held.
+    pub fn created(&self) -> Option<Arc<SpillDir>> {
+        self.dir.get().and_then(|dir| dir.as_ref().ok()).cloned()
+    }
+}
+
+/// A dataframe which may have been spilled to disk.
+pub enum Spilled {
+    InMemory(DataFrame),
+    OnDisk(PathBuf),
+}
+
+impl Spilled {
+    /// Returns the dataframe, removing its file if it was spilled.
+    pub fn load(self) -> PolarsResult<DataFrame> {
+        match self {
+            Self::InMemory(df) => Ok(df),
+            Self::OnDisk(path) => {
+                let df = IpcReader::new(File::open(&path)?).finish()?;
+                std::fs::remove_file(&path)?;
+                Ok(df)
+            },
+        }
+    }
+}
@@ -0,0 +1,523 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use hashbrown::hash_map::RawEntryMut;
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_expr::reduce::GroupedReduction;
+use polars_utils::hashing::hash_to_partition;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use super::in_memory_source::InMemorySourceNode;
+use crate::expression::{evaluate_broadcast, StreamExpr};
+use crate::memory::{memory_manager, LazySpillDir, MemoryReservation, SpillDir, Spilled};
+
+/// A key in a group table, refers to the group with index `idx`.
+#[derive(Clone, Copy)]
+struct Key {
+    hash: u64,
+    idx: IdxSize,
+}
+
+impl Hash for Key {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+/// The groups of one hash partition. Groups are numbered in order of
+/// insertion, `key_values` holds the key columns of each group in that order.
+struct GroupTable {
+    table: PlIdHashMap<Key, ()>,
+    key_bytes: Vec<u8>,
+    key_offsets: Vec<usize>,
+    key_values: Vec<DataFrame>,
+    reductions: Vec<Box<dyn GroupedReduction>>,
+}
+
+impl GroupTable {
+    fn new(reductions: &[Box<dyn GroupedReduction>]) -> Self {
+        Self {
+            table: PlIdHashMap::default(),
+            key_bytes: Vec::new(),
+            key_offsets: vec![0],
+            key_values: Vec::new(),
+            reductions: reductions.iter().map(|r| r.new_empty()).collect(),
+        }
+    }
+
+    fn num_groups(&self) -> IdxSize {
+        (self.key_offsets.len() - 1) as IdxSize
+    }
+
+    /// Returns the approximate number of bytes a group takes, besides its key:
+    /// the hash table entry, the key offset and the reduction states.
+    fn group_size(&self) -> usize {
+        std::mem::size_of::<Key>()
+            + std::mem::size_of::<usize>()
+            + self.reductions.len() * std::mem::size_of::<u64>()
+    }
+
+    #[inline]
+    fn key(&self, idx: IdxSize) -> &[u8] {
+        let idx = idx as usize;
+        &self.key_bytes[self.key_offsets[idx]..self.key_offsets[idx + 1]]
+    }
+
+    /// Returns the index of the group with this key, if it exists.
+    #[inline]
+    fn find_key(&self, hash: u64, key: &[u8]) -> Option<IdxSize> {
+        self.table
+            .raw_entry()
+            .from_hash(hash, |k| k.hash == hash && self.key(k.idx) == key)
+            .map(|(k, _)| k.idx)
+    }
+
+    /// Returns the index of the group with this key, inserting a new group if
+    /// it doesn't exist yet. The boolean indicates whether it was inserted.
+    #[inline]
+    fn insert_key(&mut self, hash: u64, key: &[u8]) -> (IdxSize, bool) {
+        let Self {
+            table,
+            key_bytes,
+            key_offsets,
+            ..
+        } = self;
+        let entry = table.raw_entry_mut().from_hash(hash, |k| {
+            k.hash == hash
+                && &key_bytes[key_offsets[k.idx as usize]..key_offsets[k.idx as usize + 1]] == key
+        });
+        match entry {
+            RawEntryMut::Occupied(entry) => (entry.key().idx, false),
+            RawEntryMut::Vacant(entry) => {
+                let idx = (key_offsets.len() - 1) as IdxSize;
+                key_bytes.extend_from_slice(key);
+                key_offsets.push(key_bytes.len());
+                entry.insert_hashed_nocheck(hash, Key { hash, idx }, ());
+                (idx, true)
+            },
+        }
+    }
+
+    /// Finalizes the reductions of all groups into a dataframe containing the
+    /// keys followed by the reductions.
+    fn finalize(&mut self, output_schema: &Schema, key_schema: &Schema) -> PolarsResult<DataFrame> {
+        let mut df = if self.key_values.is_empty() {
+            DataFrame::empty_with_schema(key_schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.key_values))
+        };
+        let reduction_fields = output_schema.iter_fields().skip(key_schema.len());
+        for (r, field) in self.reductions.iter_mut().zip(reduction_fields) {
+            let s = r.finalize()?.with_name(&field.name).cast(&field.dtype)?;
+            unsafe { df.with_column_unchecked(s) };
+        }
+        Ok(df)
+    }
+}
+
+/// The group tables of a single pipeline, one for every partition.
+struct LocalGroupBy {
+    partitions: Vec<GroupTable>,
+    /// Whether new groups are spilled to disk instead of being added to the
+    /// tables.
+    spilling: bool,
+    /// The spilled keys and reduction inputs of every partition.
+    spilled: Vec<Vec<(MorselSeq, Spilled)>>,
+}
+
+impl LocalGroupBy {
+    fn new(partitions: Vec<GroupTable>) -> Self {
+        Self {
+            spilled: partitions.iter().map(|_| Vec::new()).collect(),
+            partitions,
+            spilling: false,
+        }
+    }
+
+    /// Adds a morsel to the groups, given its evaluated keys and reduction
+    /// inputs, returning the approximate number of bytes the tables grew by.
+    ///
+    /// If a spill directory is given, the rows of groups which don't exist yet
+    /// are spilled to it instead.
+    fn update(
+        &mut self,
+        keys: &[Series],
+        inputs: &[Vec<Series>],
+        seq: MorselSeq,
+        random_state: &RandomState,
+        spill_dir: Option<&SpillDir>,
+    ) -> PolarsResult<usize> {
+        let num_partitions = self.partitions.len();
+        let rows = _get_rows_encoded_unordered(keys)?.into_array();
+
+        // Split the rows over the partitions, remembering their groups.
+        let mut partition_rows = vec![Vec::<IdxSize>::new(); num_partitions];
+        let mut partition_groups = vec![Vec::<IdxSize>::new(); num_partitions];
+        let mut partition_new_rows = vec![Vec::<IdxSize>::new(); num_partitions];
+        let mut partition_spilled_rows = vec![Vec::<IdxSize>::new(); num_partitions];
+        let mut grown_bytes = 0;
+        for (row, key) in rows.values_iter().enumerate() {
+            let hash = random_state.hash_one(key);
+            let p = hash_to_partition(hash, num_partitions);
+            let table = &mut self.partitions[p];
+            let group_idx = if spill_dir.is_some() {
+                let Some(group_idx) = table.find_key(hash, key) else {
+                    partition_spilled_rows[p].push(row as IdxSize);
+                    continue;
+                };
+                group_idx
+            } else {
+                let (group_idx, inserted) = table.insert_key(hash, key);
+                if inserted {
+                    partition_new_rows[p].push(row as IdxSize);
+                    grown_bytes += key.len() + table.group_size();
+                }
+                group_idx
+            };
+            partition_rows[p].push(row as IdxSize);
+            partition_groups[p].push(group_idx);
+        }
+
+        if let Some(spill_dir) = spill_dir {
+            for (p, rows) in partition_spilled_rows.iter().enumerate() {
+                if rows.is_empty() {
+                    continue;
+                }
+                // Columns are named by position, as the inputs may share names.
+                let columns = keys
+                    .iter()
+                    .chain(inputs.iter().flatten())
+                    .enumerate()
+                    .map(|(i, s)| unsafe { s.take_slice_unchecked(rows) }.with_name(&i.to_string()))
+                    .collect();
+                let df = unsafe { DataFrame::new_no_checks(columns) };
+                self.spilled[p].push((seq, spill_dir.spill(df)?));
+            }
+        }
+
+        let key_df = unsafe { DataFrame::new_no_checks(keys.to_vec()) };
+        for (p, table) in self.partitions.iter_mut().enumerate() {
+            let rows = &partition_rows[p];
+            if rows.is_empty() {
+                continue;
+            }
+
+            let new_rows = &partition_new_rows[p];
+            if !new_rows.is_empty() {
+                let new_keys = unsafe { key_df._take_unchecked_slice(new_rows, false) };
+                grown_bytes += new_keys.estimated_size();
+                table.key_values.push(new_keys);
+            }
+
+            let num_groups = table.num_groups();
+            let all_rows = rows.len() == key_df.height();
+            for (r, input) in table.reductions.iter_mut().zip(inputs) {
+                r.resize(num_groups);
+                if all_rows {
+                    unsafe { r.update_groups(input, &partition_groups[p], seq.to_u64())? };
+                } else {
+                    let values: Vec<_> = input
+                        .iter()
+                        .map(|s| unsafe { s.take_slice_unchecked(rows) })
+                        .collect();
+                    unsafe { r.update_groups(&values, &partition_groups[p], seq.to_u64())? };
+                }
+            }
+        }
+        Ok(grown_bytes)
+    }
+}
+
+/// Merges the tables of the same partition of all pipelines into the empty
+/// table `merged`.
+fn merge_partition(
+    mut merged: GroupTable,
+    tables: Vec<GroupTable>,
+    random_state: &RandomState,
+) -> PolarsResult<GroupTable> {
+    for mut table in tables {
+        let mut group_idxs = Vec::with_capacity(table.num_groups() as usize);
+        let mut new_groups = Vec::new();
+        for idx in 0..table.num_groups() {
+            let key = table.key(idx);
+            let (group_idx, inserted) = merged.insert_key(random_state.hash_one(key), key);
+            group_idxs.push(group_idx);
+            if inserted {
+                new_groups.push(idx);
+            }
+        }
+
+        if !new_groups.is_empty() {
+            let key_values =
+                accumulate_dataframes_vertical_unchecked(std::mem::take(&mut table.key_values));
+            merged
+                .key_values
+                .push(unsafe { key_values._take_unchecked_slice(&new_groups, false) });
+        }
+
+        let num_groups = merged.num_groups();
+        for (r, other) in merged.reductions.iter_mut().zip(&table.reductions) {
+            r.resize(num_groups);
+            unsafe { r.combine(&**other, &group_idxs)? };
+        }
+    }
+    Ok(merged)
+}
+
+/// The tables of a partition to merge, and its spilled groups.
+type MergePartition = (GroupTable, Vec<GroupTable>, Vec<(MorselSeq, Spilled)>);
+
+enum GroupByState {
+    Sink { locals: Vec<LocalGroupBy> },
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// A hash group-by which aggregates its input into thread-local hash
+/// partitions, which are merged once the input is exhausted.
+pub struct GroupByNode {
+    state: GroupByState,
+    num_pipelines: usize,
+    key_selectors: Vec<StreamExpr>,
+    key_schema: Arc<Schema>,
+    reduction_selectors: Vec<Vec<StreamExpr>>,
+    reductions: Vec<Box<dyn GroupedReduction>>,
+    output_schema: Arc<Schema>,
+    random_state: RandomState,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
+}
+
+impl GroupByNode {
+    pub fn new(
+        key_selectors: Vec<StreamExpr>,
+        key_schema: Arc<Schema>,
+        reduction_selectors: Vec<Vec<StreamExpr>>,
+        reductions: Vec<Box<dyn GroupedReduction>>,
+        output_schema: Arc<Schema>,
+    ) -> PolarsResult<Self> {
+        Ok(Self {
+            state: GroupByState::Sink { locals: Vec::new() },
+            num_pipelines: 0,
+            key_selectors,
+            key_schema,
+            reduction_selectors,
+            reductions,
+            output_schema,
+            random_state: RandomState::new(),
+            reservation: memory_manager()?.register("group_by"),
+            spill_dir: LazySpillDir::new("group_by"),
+        })
+    }
+
+    /// Adds the spilled rows of a partition to its merged table and finalizes
+    /// it. The spilled columns are the keys followed by the inputs of every
+    /// reduction.
+    ///
+    /// The spilled chunks are loaded and merged one at a time. Once asked to
+    /// spill, the rows of new groups are spilled again instead, and are merged
+    /// into a new table after the groups of the current one are finalized.
+    fn finalize_spilled(
+        &self,
+        mut table: GroupTable,
+        mut spilled: Vec<(MorselSeq, Spilled)>,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        let num_keys = self.key_selectors.len();
+        let spill_dir = self.spill_dir.get()?;
+        let mut dfs = Vec::new();
+        while !spilled.is_empty() {
+            let mut local = LocalGroupBy::new(vec![table]);
+            let mut grown_bytes = 0;
+            for (i, (seq, df)) in spilled.into_iter().enumerate() {
+                let df = df.load()?;
+                let mut columns = df.get_columns().iter().cloned();
+                let keys: Vec<_> = columns.by_ref().take(num_keys).collect();
+                let inputs: Vec<Vec<_>> = self
+                    .reduction_selectors
+                    .iter()
+                    .map(|s| columns.by_ref().take(s.len()).collect())
+                    .collect();
+                // The groups of the first chunk are always added, such that
+                // every table makes progress.
+                local.spilling |= i > 0 && self.reservation.spill_requested();
+                let spill_dir = local.spilling.then_some(&**spill_dir);
+                let bytes = local.update(&keys, &inputs, seq, &self.random_state, spill_dir)?;
+                self.reservation.grow(bytes);
+                grown_bytes += bytes;
+            }
+
+            // All rows of the groups in the table have been merged.
+            let mut merged = local.partitions.pop().unwrap();
+            dfs.push(merged.finalize(&self.output_schema, &self.key_schema)?);
+            drop(merged);
+            self.reservation.shrink(grown_bytes);
+
+            table = GroupTable::new(&self.reductions);
+            spilled = local.spilled.pop().unwrap();
+        }
+        Ok(dfs)
+    }
+
+    /// Merges the local group tables of all pipelines and computes the result.
+    fn finalize(&self, locals: Vec<LocalGroupBy>) -> PolarsResult<DataFrame> {
+        let mut partitions: Vec<MergePartition> = (0..self.num_pipelines)
+            .map(|_| (GroupTable::new(&self.reductions), Vec::new(), Vec::new()))
+            .collect();
+        for local in locals {
+            let tables = local.partitions.into_iter().zip(local.spilled);
+            for (p, (table, spilled)) in tables.enumerate() {
+                partitions[p].1.push(table);
+                partitions[p].2.extend(spilled);
+            }
+        }
+
+        let (output_schema, key_schema) = (&*self.output_schema, &*self.key_schema);
+        let random_state = &self.random_state;
+        // Partitions with spilled groups are merged one at a time, such that
+        // the groups of only one of them are reloaded at once.
+        let (spilled, in_memory): (Vec<_>, Vec<_>) = partitions
+            .into_iter()
+            .partition(|(_, _, spilled)| !spilled.is_empty());
+        let mut dfs = POOL.install(|| {
+            in_memory
+                .into_par_iter()
+                .map(|(merged, tables, _)| {
+                    merge_partition(merged, tables, random_state)?
+                        .finalize(output_schema, key_schema)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for (merged, tables, spilled) in spilled {
+            let merged = merge_partition(merged, tables, random_state)?;
+            dfs.extend(self.finalize_spilled(merged, spilled)?);
+        }
+
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        // Keys are named after the output.
+        for (s, name) in unsafe { df.get_columns_mut() }
+            .iter_mut()
+            .zip(self.output_schema.iter_names())
+        {
+            s.rename(name);
+        }
+        Ok(df)
+    }
+}
+
+impl ComputeNode for GroupByNode {
+    fn name(&self) -> &str {
+        "group_by"
+    }
+
+    fn initialize(&mut self, num_pipelines: usize) {
+        self.num_pipelines = num_pipelines;
+        if let GroupByState::Sink { locals } = &mut self.state {
+            *locals = (0..num_pipelines)
+                .map(|_| {
+                    LocalGroupBy::new(
+                        (0..num_pipelines)
+                            .map(|_| GroupTable::new(&self.reductions))
+                            .collect(),
+                    )
+                })
+                .collect();
+        }
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self.state, GroupByState::Done) {
+            self.state = GroupByState::Done;
+            self.reservation.release();
+        }
+
+        // If the input is done, merge the groups and transition to being a source.
+        if let GroupByState::Sink { locals } = &mut self.state {
+            if recv[0] == PortState::Done {
+                let locals = std::mem::take(locals);
+                let df = self.finalize(locals)?;
+                let mut source_node = InMemorySourceNode::new(Arc::new(df));
+                source_node.initialize(self.num_pipelines);
+                self.state = GroupByState::Source(source_node);
+            }
+        }
+
+        match &mut self.state {
+            GroupByState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            GroupByState::Source(source_node) => {
+                recv[0] = PortState::Done;
+                source_node.update_state(&mut [], send)?;
+            },
+            GroupByState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, GroupByState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 1 && send.len() == 1);
+        match &mut self.state {
+            GroupByState::Sink { locals } => {
+                assert!(send[0].is_none());
+                let receivers = recv[0].take().unwrap().parallel();
+                let key_selectors = &self.key_selectors;
+                let reduction_selectors = &self.reduction_selectors;
+                let random_state = &self.random_state;
+                let reservation = &self.reservation;
+                let spill_dir = &self.spill_dir;
+
+                for (mut recv, local) in receivers.into_iter().zip(locals.iter_mut()) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let (df, seq, _source_token, consume_token) = morsel.into_inner();
+                            drop(consume_token);
+
+                            let keys = evaluate_broadcast(key_selectors, &df, state).await?;
+                            let mut inputs = Vec::with_capacity(reduction_selectors.len());
+                            for selectors in reduction_selectors {
+                                inputs.push(evaluate_broadcast(selectors, &df, state).await?);
+                            }
+                            // Once asked to spill, new groups no longer fit in memory.
+                            local.spilling |= reservation.spill_requested();
+                            let spill_dir = if local.spilling {
+                                Some(&**spill_dir.get()?)
+                            } else {
+                                None
+                            };
+                            let grown_bytes =
+                                local.update(&keys, &inputs, seq, random_state, spill_dir)?;
+                            reservation.grow(grown_bytes);
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            GroupByState::Source(source) => source.spawn(scope, &mut [], send, state, join_handles),
+            GroupByState::Done => unreachable!(),
+        }
+    }
+}
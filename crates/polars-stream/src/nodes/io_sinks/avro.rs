@@ -0,0 +1,31 @@
+use std::fs::File;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::avro::{AvroWriterOptions, BatchedWriter};
+
+use super::SinkWriter;
+
+/// Writes Avro files, one block per morsel.
+pub struct AvroSinkWriter {
+    writer: BatchedWriter<File>,
+}
+
+impl AvroSinkWriter {
+    pub fn new(path: &Path, options: AvroWriterOptions, schema: &Schema) -> PolarsResult<Self> {
+        let file = File::create(path)?;
+        let writer = options.to_writer(file).batched(schema)?;
+        Ok(Self { writer })
+    }
+}
+
+impl SinkWriter for AvroSinkWriter {
+    fn write_batch(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        df.align_chunks();
+        self.writer.write_batch(&df)
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
+}
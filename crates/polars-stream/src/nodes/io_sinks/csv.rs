@@ -0,0 +1,46 @@
+use std::fs::File;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::csv::write::{BatchedWriter, CsvWriter, CsvWriterOptions};
+use polars_io::SerWriter;
+
+use super::SinkWriter;
+
+/// Writes CSV files, the header is written with the first batch.
+pub struct CsvSinkWriter {
+    writer: BatchedWriter<File>,
+}
+
+impl CsvSinkWriter {
+    pub fn new(path: &Path, options: CsvWriterOptions, schema: &Schema) -> PolarsResult<Self> {
+        let file = File::create(path)?;
+        let writer = CsvWriter::new(file)
+            .include_bom(options.include_bom)
+            .include_header(options.include_header)
+            .with_separator(options.serialize_options.separator)
+            .with_line_terminator(options.serialize_options.line_terminator)
+            .with_quote_char(options.serialize_options.quote_char)
+            .with_batch_size(options.batch_size)
+            .with_datetime_format(options.serialize_options.datetime_format)
+            .with_date_format(options.serialize_options.date_format)
+            .with_time_format(options.serialize_options.time_format)
+            .with_float_scientific(options.serialize_options.float_scientific)
+            .with_float_precision(options.serialize_options.float_precision)
+            .with_null_value(options.serialize_options.null)
+            .with_quote_style(options.serialize_options.quote_style)
+            .batched(schema)?;
+        Ok(Self { writer })
+    }
+}
+
+impl SinkWriter for CsvSinkWriter {
+    fn write_batch(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        df.align_chunks();
+        self.writer.write_batch(&df)
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
+}
@@ -0,0 +1,34 @@
+use std::fs::File;
+use std::path::Path;
+
+use futures::io::AllowStdIo;
+use polars_core::prelude::*;
+use polars_io::ipc::{BatchedWriterAsync, IpcWriter, IpcWriterOptions};
+
+use super::SinkWriter;
+
+/// Writes IPC files through the async IPC writer.
+pub struct IpcSinkWriter {
+    writer: BatchedWriterAsync<'static, AllowStdIo<File>>,
+}
+
+impl IpcSinkWriter {
+    pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<Self> {
+        let file = File::create(path)?;
+        let writer = IpcWriter::new_async(AllowStdIo::new(file))
+            .with_compression(options.compression)
+            .batched_async(schema)?;
+        Ok(Self { writer })
+    }
+}
+
+impl SinkWriter for IpcSinkWriter {
+    fn write_batch(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        df.align_chunks();
+        futures::executor::block_on(self.writer.write_batch(&df))
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        futures::executor::block_on(self.writer.finish())
+    }
+}
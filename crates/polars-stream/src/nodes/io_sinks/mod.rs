@@ -0,0 +1,176 @@
+use std::path::Path;
+use std::thread::JoinHandle as ThreadHandle;
+
+use parking_lot::Mutex;
+use polars_core::schema::Schema;
+use polars_plan::prelude::FileType;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+
+use super::compute_node_prelude::*;
+
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "ipc")]
+pub mod ipc;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+/// The number of morsels buffered ahead of the writer thread.
+const WRITER_BUFFER_SIZE: usize = 4;
+
+/// Writes a sequence of dataframes to a file.
+///
+/// Writers do blocking IO, they are driven from a dedicated thread.
+pub trait SinkWriter: Send {
+    /// Writes the next batch of the sink.
+    fn write_batch(&mut self, df: DataFrame) -> PolarsResult<()>;
+
+    /// Writes any buffered data and the footer of the file.
+    fn finish(&mut self) -> PolarsResult<()>;
+}
+
+/// Creates the writer for a sink to a local file.
+///
+/// # Panics
+/// If the file type has no streaming writer.
+pub fn file_sink_writer(
+    path: &Path,
+    file_type: &FileType,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter>> {
+    Ok(match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => Box::new(parquet::ParquetSinkWriter::new(
+            path,
+            options.clone(),
+            schema,
+        )?),
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => Box::new(ipc::IpcSinkWriter::new(path, *options, schema)?),
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => Box::new(csv::CsvSinkWriter::new(path, options.clone(), schema)?),
+        #[cfg(feature = "avro")]
+        FileType::Avro(options) => {
+            Box::new(avro::AvroSinkWriter::new(path, options.clone(), schema)?)
+        },
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("file type has no streaming writer"),
+    })
+}
+
+/// Spawns a thread driving the writer. At most `capacity` dataframes are
+/// buffered ahead of the writer.
+fn spawn_writer_thread(
+    mut writer: Box<dyn SinkWriter>,
+    capacity: usize,
+) -> (Sender<DataFrame>, ThreadHandle<PolarsResult<()>>) {
+    let (tx, mut rx): (_, Receiver<DataFrame>) = channel(capacity);
+    let handle = std::thread::Builder::new()
+        .name("polars-stream-sink".to_string())
+        .spawn(move || {
+            while let Some(df) = rx.blocking_recv() {
+                writer.write_batch(df)?;
+            }
+            writer.finish()
+        })
+        .unwrap();
+    (tx, handle)
+}
+
+/// A sink node writing the morsels it receives, in order, to a [`SinkWriter`].
+pub struct FileSinkNode {
+    name: &'static str,
+    writer: Option<Box<dyn SinkWriter>>,
+    sender: Option<Sender<DataFrame>>,
+    writer_thread: Mutex<Option<ThreadHandle<PolarsResult<()>>>>,
+}
+
+impl FileSinkNode {
+    pub fn new(name: &'static str, writer: Box<dyn SinkWriter>) -> Self {
+        Self {
+            name,
+            writer: Some(writer),
+            sender: None,
+            writer_thread: Mutex::default(),
+        }
+    }
+}
+
+/// Waits for the writer thread to finish, returning its result.
+fn join_writer_thread(
+    writer_thread: &Mutex<Option<ThreadHandle<PolarsResult<()>>>>,
+) -> PolarsResult<()> {
+    match writer_thread.lock().take() {
+        Some(handle) => handle
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic)),
+        None => Ok(()),
+    }
+}
+
+impl ComputeNode for FileSinkNode {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        // We are always ready to receive, unless the sender is done, then we're
+        // also done.
+        if recv[0] != PortState::Done {
+            recv[0] = PortState::Ready;
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 1 && send.is_empty());
+        if let Some(writer) = self.writer.take() {
+            let (tx, handle) = spawn_writer_thread(writer, WRITER_BUFFER_SIZE);
+            self.sender = Some(tx);
+            *self.writer_thread.get_mut() = Some(handle);
+        }
+
+        // The serial receiver linearizes the morsels of all pipelines, so we
+        // write them in order.
+        let mut recv = recv[0].take().unwrap().serial();
+        let sender = self.sender.clone().unwrap();
+        let writer_thread = &self.writer_thread;
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(mut morsel) = recv.recv().await {
+                // Only release the morsel once the writer accepted it, this
+                // gives us backpressure once the writer falls behind.
+                let consume_token = morsel.take_consume_token();
+                if sender.send(morsel.into_df()).await.is_err() {
+                    // The writer thread only stops early if it failed.
+                    return join_writer_thread(writer_thread);
+                }
+                drop(consume_token);
+            }
+            Ok(())
+        }));
+    }
+
+    fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
+        // The writer was never started if we did not receive any morsels.
+        if let Some(mut writer) = self.writer.take() {
+            writer.finish()?;
+        }
+        // Dropping the sender lets the writer thread finish the file.
+        self.sender = None;
+        join_writer_thread(&self.writer_thread)?;
+        Ok(Some(DataFrame::empty()))
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs::File;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::parquet::write::{BatchedWriter, ParquetWriteOptions, ParquetWriter};
+
+use super::SinkWriter;
+
+/// The row group size used if none is given, the same as for eager writes.
+const DEFAULT_ROW_GROUP_SIZE: usize = 512 * 512;
+
+/// Writes Parquet files, buffering the batches until they fill a row group.
+pub struct ParquetSinkWriter {
+    writer: BatchedWriter<File>,
+    row_group_size: usize,
+    buffered: Vec<DataFrame>,
+    buffered_rows: usize,
+}
+
+impl ParquetSinkWriter {
+    pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
+        let file = File::create(path)?;
+        // We write from a dedicated thread, so encoding the columns of a row
+        // group in parallel cannot deadlock.
+        let writer = ParquetWriter::new(file)
+            .with_compression(options.compression)
+            .with_data_page_size(options.data_page_size)
+            .with_statistics(options.statistics)
+            .with_bloom_filter_columns(options.bloom_filter_columns)
+            .with_column_options(options.column_options)
+            .with_encryption(options.encryption)
+            .set_parallel(true)
+            .batched(schema)?;
+
+        Ok(Self {
+            writer,
+            row_group_size: options.row_group_size.unwrap_or(DEFAULT_ROW_GROUP_SIZE),
+            buffered: Vec::new(),
+            buffered_rows: 0,
+        })
+    }
+
+    fn flush_row_groups(&mut self, flush_all: bool) -> PolarsResult<()> {
+        if self.buffered_rows == 0 {
+            return Ok(());
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(self.buffered.drain(..));
+        self.buffered_rows = 0;
+
+        while df.height() >= self.row_group_size {
+            let (mut row_group, rest) = df.split_at(self.row_group_size as i64);
+            row_group.as_single_chunk_par();
+            self.writer.write_batch(&row_group)?;
+            df = rest;
+        }
+        if flush_all && df.height() > 0 {
+            df.as_single_chunk_par();
+            self.writer.write_batch(&df)?;
+        } else if df.height() > 0 {
+            self.buffered_rows = df.height();
+            self.buffered.push(df);
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for ParquetSinkWriter {
+    fn write_batch(&mut self, df: DataFrame) -> PolarsResult<()> {
+        self.buffered_rows += df.height();
+        self.buffered.push(df);
+        if self.buffered_rows >= self.row_group_size {
+            self.flush_row_groups(false)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        self.flush_row_groups(true)?;
+        self.writer.finish()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_io::avro::{AvroReader, BatchedAvroReader};
+use polars_io::cloud::CloudOptions;
+use polars_io::predicates::{apply_predicate, PhysicalIoExpr};
+use polars_io::utils::materialize_projection;
+use polars_io::SerReader;
+use polars_plan::plans::hive::HivePartitions;
+use polars_plan::plans::FileInfo;
+use polars_plan::prelude::FileScanOptions;
+
+use super::{open_scan_file, BlockingSourceReader, RowSlice};
+
+/// Reads Avro files one file at a time, every batch holds the rows of the
+/// blocks that are decoded in parallel at once. Cloud files are read through
+/// the file cache.
+pub struct AvroSourceReader {
+    paths: Arc<[PathBuf]>,
+    file_info: FileInfo,
+    hive_parts: Option<Arc<[HivePartitions]>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    cloud_options: Option<CloudOptions>,
+    file_options: FileScanOptions,
+    /// The part of the slice still to be read.
+    slice: RowSlice,
+    /// The number of rows read from the files before the current batch.
+    rows_read: usize,
+    path_idx: usize,
+    current: Option<BatchedAvroReader<File>>,
+}
+
+impl AvroSourceReader {
+    pub fn new(
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        hive_parts: Option<Arc<[HivePartitions]>>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        cloud_options: Option<CloudOptions>,
+        file_options: FileScanOptions,
+    ) -> Self {
+        let slice = RowSlice::new(file_options.slice);
+
+        Self {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            cloud_options,
+            file_options,
+            slice,
+            rows_read: 0,
+            path_idx: 0,
+            current: None,
+        }
+    }
+
+    fn open_next_file(&mut self) -> PolarsResult<Option<BatchedAvroReader<File>>> {
+        if self.path_idx == self.paths.len() {
+            return Ok(None);
+        }
+        let path_idx = self.path_idx;
+        self.path_idx += 1;
+
+        let path = &self.paths[path_idx];
+        let projection = materialize_projection(
+            self.file_options.with_columns.as_deref(),
+            &self.file_info.schema,
+            None,
+            self.file_options.row_index.is_some(),
+        )
+        // The output schema of an Avro scan is in the order of the file.
+        .map(|mut projection| {
+            projection.sort_unstable();
+            projection
+        });
+        let row_index = self.file_options.row_index.clone().map(|mut ri| {
+            ri.offset += self.rows_read as IdxSize;
+            ri
+        });
+
+        AvroReader::new(open_scan_file(path, self.cloud_options.as_ref())?)
+            .with_n_rows(self.slice.n_rows())
+            .with_row_index(row_index)
+            .with_projection(projection)
+            .with_hive_partition_columns(
+                self.hive_parts
+                    .as_ref()
+                    .map(|parts| parts[path_idx].materialize_partition_columns()),
+            )
+            .with_include_file_path(
+                self.file_options
+                    .include_file_paths
+                    .as_ref()
+                    .map(|col| (col.clone(), Arc::from(path.to_str().unwrap()))),
+            )
+            .batched()
+            .map(Some)
+    }
+}
+
+impl BlockingSourceReader for AvroSourceReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if self.slice.is_done() {
+                return Ok(None);
+            }
+            let reader = match &mut self.current {
+                Some(reader) => reader,
+                None => match self.open_next_file()? {
+                    Some(reader) => self.current.insert(reader),
+                    None => return Ok(None),
+                },
+            };
+            let Some(df) = reader.next_batch()? else {
+                self.current = None;
+                continue;
+            };
+
+            self.rows_read += df.height();
+            let mut df = self.slice.apply(df);
+
+            // The predicate must be applied after the row index and the slice.
+            apply_predicate(&mut df, self.predicate.as_deref(), true)?;
+            return Ok(Some(df));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_io::cloud::CloudOptions;
+use polars_io::csv::read::{CsvReadOptions, OwnedBatchedCsvReader};
+use polars_io::predicates::PhysicalIoExpr;
+use polars_plan::plans::FileInfo;
+use polars_plan::prelude::FileScanOptions;
+
+use super::{open_maybe_decompressed, BlockingSourceReader, RowSlice};
+
+/// Reads CSV files in batches of chunks, one file after the other.
+pub struct CsvSourceReader {
+    paths: Arc<[PathBuf]>,
+    options: CsvReadOptions,
+    cloud_options: Option<CloudOptions>,
+    file_options: FileScanOptions,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    /// The part of the slice still to be read.
+    slice: RowSlice,
+    /// The number of rows read from the files before the current one.
+    rows_read: usize,
+    path_idx: usize,
+    current: Option<OwnedBatchedCsvReader>,
+}
+
+impl CsvSourceReader {
+    pub fn new(
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        options: CsvReadOptions,
+        cloud_options: Option<CloudOptions>,
+        file_options: FileScanOptions,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Self {
+        let with_columns = file_options
+            .with_columns
+            .clone()
+            // Interpret selecting no columns as selecting all columns.
+            .filter(|columns| !columns.is_empty());
+        let options = options
+            .with_schema(Some(file_info.reader_schema.unwrap().unwrap_right()))
+            .with_columns(with_columns)
+            .with_rechunk(false)
+            .with_row_index(None)
+            .with_path::<&str>(None);
+        let slice = RowSlice::new(file_options.slice);
+
+        Self {
+            paths,
+            options,
+            cloud_options,
+            file_options,
+            predicate,
+            slice,
+            rows_read: 0,
+            path_idx: 0,
+            current: None,
+        }
+    }
+
+    fn open_next_file(&mut self) -> PolarsResult<Option<OwnedBatchedCsvReader>> {
+        let Some(path) = self.paths.get(self.path_idx) else {
+            return Ok(None);
+        };
+        self.path_idx += 1;
+
+        let row_index = self.file_options.row_index.clone().map(|mut ri| {
+            ri.offset += self.rows_read as IdxSize;
+            ri
+        });
+        let schema = self.options.schema.clone();
+        let reader = self
+            .options
+            .clone()
+            .with_row_index(row_index)
+            .with_n_rows(self.slice.n_rows())
+            .into_reader_with_file_handle(open_maybe_decompressed(
+                path,
+                self.cloud_options.as_ref(),
+            )?);
+        reader.batched(schema).map(Some)
+    }
+}
+
+impl BlockingSourceReader for CsvSourceReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if self.slice.is_done() {
+                return Ok(None);
+            }
+
+            let reader = match &mut self.current {
+                Some(reader) => reader,
+                None => match self.open_next_file()? {
+                    Some(reader) => self.current.insert(reader),
+                    None => return Ok(None),
+                },
+            };
+
+            let Some(dfs) = reader.next_batches(POOL.current_num_threads())? else {
+                self.current = None;
+                continue;
+            };
+
+            let df = accumulate_dataframes_vertical_unchecked(dfs);
+            self.rows_read += df.height();
+            let mut df = self.slice.apply(df);
+
+            if let Some(col) = &self.file_options.include_file_paths {
+                let path = self.paths[self.path_idx - 1].to_str().unwrap();
+                unsafe {
+                    df.with_column_unchecked(
+                        StringChunked::full(col, path, df.height()).into_series(),
+                    )
+                };
+            }
+
+            // The predicate must be applied after the row index and the slice.
+            if let Some(predicate) = &self.predicate {
+                let mask = predicate.evaluate_io(&df)?;
+                let mask = mask
+                    .bool()
+                    .expect("filter predicates was not of type boolean");
+                df = df.filter(mask)?;
+            }
+            return Ok(Some(df));
+        }
+    }
+}
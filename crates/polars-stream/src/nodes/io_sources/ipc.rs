@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::ipc::{IpcReader, IpcScanOptions};
+use polars_io::predicates::{apply_predicate, PhysicalIoExpr};
+use polars_io::utils::materialize_projection;
+use polars_io::SerReader;
+use polars_plan::plans::hive::HivePartitions;
+use polars_plan::plans::FileInfo;
+use polars_plan::prelude::FileScanOptions;
+
+use super::{open_scan_file, BlockingSourceReader, RowSlice};
+
+/// Reads IPC files one file at a time, cloud files are read through the file
+/// cache.
+pub struct IpcSourceReader {
+    paths: Arc<[PathBuf]>,
+    file_info: FileInfo,
+    hive_parts: Option<Arc<[HivePartitions]>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    options: IpcScanOptions,
+    cloud_options: Option<CloudOptions>,
+    file_options: FileScanOptions,
+    /// The part of the slice still to be read.
+    slice: RowSlice,
+    /// The number of rows read from the files before the current one.
+    rows_read: usize,
+    path_idx: usize,
+}
+
+impl IpcSourceReader {
+    pub fn new(
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        hive_parts: Option<Arc<[HivePartitions]>>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        options: IpcScanOptions,
+        cloud_options: Option<CloudOptions>,
+        file_options: FileScanOptions,
+    ) -> Self {
+        let slice = RowSlice::new(file_options.slice);
+
+        Self {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            options,
+            cloud_options,
+            file_options,
+            slice,
+            rows_read: 0,
+            path_idx: 0,
+        }
+    }
+}
+
+impl BlockingSourceReader for IpcSourceReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.path_idx == self.paths.len() || self.slice.is_done() {
+            return Ok(None);
+        }
+        let path_idx = self.path_idx;
+        self.path_idx += 1;
+
+        let path = &self.paths[path_idx];
+        let projection = materialize_projection(
+            self.file_options.with_columns.as_deref(),
+            &self.file_info.schema,
+            None,
+            self.file_options.row_index.is_some(),
+        );
+        let row_index = self.file_options.row_index.clone().map(|mut ri| {
+            ri.offset += self.rows_read as IdxSize;
+            ri
+        });
+
+        let is_cloud = polars_io::path_utils::is_cloud_url(path);
+        let df = IpcReader::new(open_scan_file(path, self.cloud_options.as_ref())?)
+            .with_n_rows(self.slice.n_rows())
+            .with_row_index(row_index)
+            .with_projection(projection)
+            .with_hive_partition_columns(
+                self.hive_parts
+                    .as_ref()
+                    .map(|parts| parts[path_idx].materialize_partition_columns()),
+            )
+            .with_include_file_path(
+                self.file_options
+                    .include_file_paths
+                    .as_ref()
+                    .map(|col| (col.clone(), Arc::from(path.to_str().unwrap()))),
+            )
+            .memory_mapped((self.options.memory_map && !is_cloud).then(|| path.clone()))
+            .finish()?;
+
+        self.rows_read += df.height();
+        let mut df = self.slice.apply(df);
+
+        // The predicate must be applied after the row index and the slice.
+        apply_predicate(&mut df, self.predicate.as_deref(), true)?;
+        Ok(Some(df))
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::VecDeque;
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+use std::fs::File;
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+use polars_core::frame::DataFrame;
+use polars_error::PolarsResult;
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+use polars_io::cloud::CloudOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::mmap::MmapBytesReader;
+use polars_io::predicates::PhysicalIoExpr;
+use polars_plan::plans::hive::HivePartitions;
+use polars_plan::plans::{FileInfo, FileScan};
+use polars_plan::prelude::FileScanOptions;
+use tokio::sync::mpsc::{channel, Receiver};
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::morsel::{get_ideal_morsel_size, SourceToken};
+
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "ipc")]
+pub mod ipc;
+#[cfg(feature = "json")]
+pub mod ndjson;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+/// Reads the data of a scan as a sequence of dataframes.
+///
+/// Readers are driven from a task on the async IO runtime.
+pub trait SourceReader: Send {
+    /// Returns the next batch of the scan, or `None` once it is exhausted.
+    fn next_batch(&mut self) -> BoxFuture<'_, PolarsResult<Option<DataFrame>>>;
+
+    /// The sequence number of the batch returned last, for readers which
+    /// number their batches themselves. The numbers may not decrease.
+    fn batch_seq(&self) -> Option<MorselSeq> {
+        None
+    }
+}
+
+/// A [`SourceReader`] doing blocking IO or heavy compute, which is run such
+/// that it does not starve the other tasks of the async IO runtime.
+pub trait BlockingSourceReader: Send {
+    /// Returns the next batch of the scan, or `None` once it is exhausted.
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>>;
+
+    /// See [`SourceReader::batch_seq`].
+    fn batch_seq(&self) -> Option<MorselSeq> {
+        None
+    }
+}
+
+impl<R: BlockingSourceReader> SourceReader for R {
+    fn next_batch(&mut self) -> BoxFuture<'_, PolarsResult<Option<DataFrame>>> {
+        Box::pin(
+            async move { tokio::task::block_in_place(|| BlockingSourceReader::next_batch(self)) },
+        )
+    }
+
+    fn batch_seq(&self) -> Option<MorselSeq> {
+        BlockingSourceReader::batch_seq(self)
+    }
+}
+
+/// A positive slice over the rows of consecutive batches, for readers which
+/// can't skip rows themselves.
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+#[derive(Clone, Copy)]
+struct RowSlice {
+    /// The number of rows still to be skipped.
+    offset: usize,
+    /// The number of rows still to be emitted.
+    len: usize,
+}
+
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+impl RowSlice {
+    fn new(slice: Option<(i64, usize)>) -> Self {
+        match slice {
+            Some((offset, len)) => Self {
+                offset: usize::try_from(offset).expect("negative slices are not streamed"),
+                len,
+            },
+            None => Self {
+                offset: 0,
+                len: usize::MAX,
+            },
+        }
+    }
+
+    /// The number of rows which have to be read to cover the slice.
+    fn n_rows(&self) -> Option<usize> {
+        (self.len != usize::MAX).then(|| self.offset.saturating_add(self.len))
+    }
+
+    fn is_done(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Takes the part of the slice in `df`, the rows following the rows of
+    /// previous batches.
+    fn apply(&mut self, df: DataFrame) -> DataFrame {
+        let skip = self.offset.min(df.height());
+        self.offset -= skip;
+        let df = df.slice(skip as i64, self.len);
+        self.len -= df.height();
+        df
+    }
+}
+
+/// A reader producing the result of a function in a single batch, used for
+/// scans that are executed by the in-memory engine.
+pub struct OneShotReader {
+    read: Option<Box<dyn FnOnce() -> PolarsResult<DataFrame> + Send>>,
+}
+
+impl OneShotReader {
+    pub fn new(read: Box<dyn FnOnce() -> PolarsResult<DataFrame> + Send>) -> Self {
+        Self { read: Some(read) }
+    }
+}
+
+impl BlockingSourceReader for OneShotReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        self.read.take().map(|read| read()).transpose()
+    }
+}
+
+/// Opens the file of a scan, cloud files are downloaded to the file cache.
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json", feature = "avro"))]
+#[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+fn open_scan_file(path: &Path, cloud_options: Option<&CloudOptions>) -> PolarsResult<File> {
+    if polars_io::path_utils::is_cloud_url(path) {
+        #[cfg(feature = "cloud")]
+        {
+            let uri: Arc<str> = Arc::from(path.to_str().unwrap());
+            let entries = polars_io::file_cache::init_entries_from_uri_list(&[uri], cloud_options)?;
+            return entries[0].try_open_check_latest();
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            panic!("activate cloud feature")
+        }
+    }
+    polars_utils::open_file(path)
+}
+
+/// Opens the file of a scan, decompressing it in memory if it is compressed.
+#[cfg(any(feature = "csv", feature = "json"))]
+fn open_maybe_decompressed(
+    path: &Path,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Box<dyn MmapBytesReader>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = open_scan_file(path, cloud_options)?;
+    let mut magic = Vec::with_capacity(4);
+    (&mut file).take(4).read_to_end(&mut magic)?;
+    file.seek(SeekFrom::Start(0))?;
+    if magic.len() < 4 || !polars_io::utils::is_compressed(&magic) {
+        return Ok(Box::new(file));
+    }
+
+    let mut bytes = vec![];
+    file.read_to_end(&mut bytes)?;
+    let mut decompressed = vec![];
+    // SAFETY: The returned slice is not used, it borrows `decompressed` only.
+    unsafe { polars_io::utils::maybe_decompress_bytes(&bytes, &mut decompressed)? };
+    Ok(Box::new(std::io::Cursor::new(decompressed)))
+}
+
+/// Creates the reader for a scan.
+///
+/// # Panics
+/// If the scan type has no streaming reader.
+#[cfg_attr(
+    not(any(feature = "ipc", feature = "parquet", feature = "avro")),
+    allow(unused_variables)
+)]
+pub fn file_scan_reader(
+    paths: Arc<[PathBuf]>,
+    file_info: FileInfo,
+    hive_parts: Option<Arc<[HivePartitions]>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    scan_type: FileScan,
+    file_options: FileScanOptions,
+) -> Box<dyn SourceReader> {
+    match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet {
+            options,
+            cloud_options,
+            metadata,
+            decryption,
+        } => Box::new(parquet::ParquetSourceReader::new(
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            options,
+            cloud_options,
+            metadata,
+            decryption,
+            file_options,
+        )),
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc {
+            options,
+            cloud_options,
+            ..
+        } => Box::new(ipc::IpcSourceReader::new(
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            options,
+            cloud_options,
+            file_options,
+        )),
+        #[cfg(feature = "csv")]
+        FileScan::Csv {
+            options,
+            cloud_options,
+        } => Box::new(csv::CsvSourceReader::new(
+            paths,
+            file_info,
+            options,
+            cloud_options,
+            file_options,
+            predicate,
+        )),
+        #[cfg(feature = "json")]
+        FileScan::NDJson {
+            options,
+            cloud_options,
+        } => Box::new(ndjson::NDJsonSourceReader::new(
+            paths,
+            file_info,
+            predicate,
+            options,
+            cloud_options,
+            file_options,
+        )),
+        #[cfg(feature = "avro")]
+        FileScan::Avro { cloud_options } => Box::new(avro::AvroSourceReader::new(
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            cloud_options,
+            file_options,
+        )),
+        _ => unreachable!("scan type has no streaming reader"),
+    }
+}
+
+type MorselData = PolarsResult<(MorselSeq, DataFrame)>;
+
+/// Spawns a task on the async IO runtime driving the reader, which splits the
+/// batches it reads into numbered morsels. At most `capacity` morsels are
+/// buffered ahead of the consumers.
+fn spawn_reader_task(
+    mut reader: Box<dyn SourceReader>,
+    capacity: usize,
+) -> (Receiver<MorselData>, tokio::task::JoinHandle<()>) {
+    let (tx, rx) = channel(capacity);
+    let handle = polars_io::pl_async::get_runtime().spawn(async move {
+        let morsel_size = get_ideal_morsel_size();
+        let mut seq = MorselSeq::default();
+        loop {
+            let df = match reader.next_batch().await {
+                Ok(Some(df)) => df,
+                Ok(None) => break,
+                Err(e) => {
+                    let _ = tx.send(Err(e)).await;
+                    break;
+                },
+            };
+
+            // Batches numbered by the reader are sent whole, such that the
+            // rows of a sequence number can't be reordered between pipelines.
+            if let Some(batch_seq) = reader.batch_seq() {
+                if tx.send(Ok((batch_seq, df))).await.is_err() {
+                    return;
+                }
+                continue;
+            }
+
+            let mut offset = 0;
+            while offset < df.height() {
+                let morsel_df = df.slice(offset as i64, morsel_size);
+                offset += morsel_df.height();
+                if tx.send(Ok((seq, morsel_df))).await.is_err() {
+                    // The scan is no longer needed.
+                    return;
+                }
+                seq = seq.successor();
+            }
+        }
+    });
+    (rx, handle)
+}
+
+/// A source node streaming the batches of a [`SourceReader`] as morsels.
+pub struct ScanSourceNode {
+    name: &'static str,
+    /// The reader until its task is started. Readers need not be `Sync`,
+    /// the mutex makes the node shareable.
+    reader: Mutex<Option<Box<dyn SourceReader>>>,
+    morsels: Option<tokio::sync::Mutex<Receiver<MorselData>>>,
+    reader_task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    /// Morsels which could not be sent in an earlier execution phase, ordered
+    /// by their sequence number.
+    pending: Mutex<VecDeque<(MorselSeq, DataFrame)>>,
+    num_pipelines: usize,
+    exhausted: AtomicBool,
+}
+
+impl ScanSourceNode {
+    pub fn new(name: &'static str, reader: Box<dyn SourceReader>) -> Self {
+        Self {
+            name,
+            reader: Mutex::new(Some(reader)),
+            morsels: None,
+            reader_task: Mutex::default(),
+            pending: Mutex::default(),
+            num_pipelines: 0,
+            exhausted: AtomicBool::new(false),
+        }
+    }
+}
+
+impl ComputeNode for ScanSourceNode {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn initialize(&mut self, num_pipelines: usize) {
+        self.num_pipelines = num_pipelines;
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.is_empty() && send.len() == 1);
+
+        let exhausted = self.exhausted.load(Ordering::Relaxed) && self.pending.lock().is_empty();
+        if send[0] == PortState::Done || exhausted {
+            send[0] = PortState::Done;
+            // Dropping the receiver stops the reader task.
+            *self.reader.get_mut() = None;
+            self.morsels = None;
+            if let Some(handle) = self.reader_task.get_mut().take() {
+                handle.abort();
+            }
+        } else {
+            send[0] = PortState::Ready;
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.is_empty() && send.len() == 1);
+        if let Some(reader) = self.reader.get_mut().take() {
+            let (rx, handle) = spawn_reader_task(reader, self.num_pipelines.max(1));
+            self.morsels = Some(tokio::sync::Mutex::new(rx));
+            *self.reader_task.get_mut() = Some(handle);
+        }
+
+        let senders = send[0].take().unwrap().parallel();
+        let morsels = self.morsels.as_ref().unwrap();
+        let pending = &self.pending;
+        let exhausted = &self.exhausted;
+        let reader_task = &self.reader_task;
+        let source_token = SourceToken::new();
+
+        for mut send in senders {
+            let source_token = source_token.clone();
+            join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                let wait_group = WaitGroup::default();
+                loop {
+                    let pending_morsel = pending.lock().pop_front();
+                    let (seq, df) = match pending_morsel {
+                        Some(morsel) => morsel,
+                        None => match morsels.lock().await.recv().await {
+                            Some(morsel) => morsel?,
+                            None => {
+                                exhausted.store(true, Ordering::Relaxed);
+                                // Propagate a panic of the reader instead of
+                                // silently ending the scan.
+                                let handle = reader_task.lock().take();
+                                if let Some(handle) = handle {
+                                    if let Err(e) = handle.await {
+                                        if e.is_panic() {
+                                            std::panic::resume_unwind(e.into_panic());
+                                        }
+                                    }
+                                }
+                                break;
+                            },
+                        },
+                    };
+
+                    let mut morsel = Morsel::new(df, seq, source_token.clone());
+                    morsel.set_consume_token(wait_group.token());
+                    if let Err(morsel) = send.send(morsel).await {
+                        let mut pending = pending.lock();
+                        let idx = pending.partition_point(|(seq, _)| *seq < morsel.seq());
+                        pending.insert(idx, (morsel.seq(), morsel.into_df()));
+                        break;
+                    }
+
+                    wait_group.wait().await;
+                    if source_token.stop_requested() {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+}
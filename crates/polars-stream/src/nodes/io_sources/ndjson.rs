@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::ndjson::core::JsonLineReader;
+use polars_io::predicates::{apply_predicate, PhysicalIoExpr};
+use polars_io::{RowIndex, SerReader};
+use polars_plan::plans::FileInfo;
+use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
+
+use super::{open_maybe_decompressed, BlockingSourceReader, RowSlice};
+
+/// Reads NDJSON files one file at a time.
+pub struct NDJsonSourceReader {
+    paths: Arc<[PathBuf]>,
+    schema: SchemaRef,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    options: NDJsonReadOptions,
+    cloud_options: Option<CloudOptions>,
+    file_options: FileScanOptions,
+    /// The row index, its offset is advanced by the reader.
+    row_index: Option<RowIndex>,
+    /// The part of the slice still to be read.
+    slice: RowSlice,
+    path_idx: usize,
+}
+
+impl NDJsonSourceReader {
+    pub fn new(
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        options: NDJsonReadOptions,
+        cloud_options: Option<CloudOptions>,
+        mut file_options: FileScanOptions,
+    ) -> Self {
+        let slice = RowSlice::new(file_options.slice);
+
+        Self {
+            paths,
+            schema: file_info.reader_schema.unwrap().unwrap_right(),
+            predicate,
+            options,
+            cloud_options,
+            row_index: file_options.row_index.take(),
+            file_options,
+            slice,
+            path_idx: 0,
+        }
+    }
+}
+
+impl BlockingSourceReader for NDJsonSourceReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.path_idx == self.paths.len() || self.slice.is_done() {
+            return Ok(None);
+        }
+        let path = &self.paths[self.path_idx];
+        self.path_idx += 1;
+
+        let reader = open_maybe_decompressed(path, self.cloud_options.as_ref())?;
+        let df = JsonLineReader::new(reader)
+            .with_schema(self.schema.clone())
+            .with_rechunk(false)
+            .with_chunk_size(Some(self.options.chunk_size))
+            .with_row_index(self.row_index.as_mut())
+            .with_projection(self.file_options.with_columns.clone())
+            .low_memory(self.options.low_memory)
+            .with_n_rows(self.slice.n_rows())
+            .with_ignore_errors(self.options.ignore_errors)
+            .finish()?;
+        let mut df = self.slice.apply(df);
+
+        if let Some(col) = &self.file_options.include_file_paths {
+            let path = path.to_str().unwrap();
+            unsafe {
+                df.with_column_unchecked(StringChunked::full(col, path, df.height()).into_series())
+            };
+        }
+
+        // The predicate must be applied after the row index and the slice.
+        apply_predicate(&mut df, self.predicate.as_deref(), true)?;
+        Ok(Some(df))
+    }
+}
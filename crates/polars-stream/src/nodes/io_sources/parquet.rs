@@ -0,0 +1,220 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_io::cloud::CloudOptions;
+use polars_io::parquet::metadata::FileMetaDataRef;
+#[cfg(feature = "cloud")]
+use polars_io::parquet::read::ParquetAsyncReader;
+use polars_io::parquet::read::{
+    BatchedParquetReader, FileDecryptionProperties, ParquetOptions, ParquetReader,
+};
+use polars_io::predicates::{apply_predicate, PhysicalIoExpr};
+use polars_io::utils::materialize_projection;
+use polars_io::utils::slice::split_slice_at_file;
+use polars_io::SerReader;
+use polars_plan::plans::hive::HivePartitions;
+use polars_plan::plans::FileInfo;
+use polars_plan::prelude::FileScanOptions;
+
+use super::SourceReader;
+use crate::morsel::get_ideal_morsel_size;
+
+/// Reads Parquet files in batches of row groups, one file after the other.
+///
+/// Projection, predicate, row index and slice are pushed into the
+/// [`BatchedParquetReader`] of every file, cloud files are read with the
+/// [`ParquetAsyncReader`].
+pub struct ParquetSourceReader {
+    paths: Arc<[PathBuf]>,
+    file_info: FileInfo,
+    hive_parts: Option<Arc<[HivePartitions]>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    /// The predicate to apply to the batches after reading them, the reader
+    /// computes the wrong row index when it applies the predicate itself.
+    post_predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    options: ParquetOptions,
+    #[cfg_attr(not(feature = "cloud"), allow(dead_code))]
+    cloud_options: Option<CloudOptions>,
+    /// The metadata of the first file, read while resolving the schema.
+    #[cfg_attr(not(feature = "cloud"), allow(dead_code))]
+    first_metadata: Option<FileMetaDataRef>,
+    decryption: Option<FileDecryptionProperties>,
+    file_options: FileScanOptions,
+    /// The global slice as (start, end).
+    slice: (usize, usize),
+    /// The number of rows in the files before the current one.
+    row_offset: usize,
+    path_idx: usize,
+    current: Option<BatchedParquetReader>,
+}
+
+impl ParquetSourceReader {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        hive_parts: Option<Arc<[HivePartitions]>>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        options: ParquetOptions,
+        cloud_options: Option<CloudOptions>,
+        first_metadata: Option<FileMetaDataRef>,
+        decryption: Option<FileDecryptionProperties>,
+        file_options: FileScanOptions,
+    ) -> Self {
+        let (predicate, post_predicate) = match file_options.row_index {
+            Some(_) => (None, predicate),
+            None => (predicate, None),
+        };
+        let slice = match file_options.slice {
+            Some((offset, len)) => {
+                let offset = usize::try_from(offset).expect("negative slices are not streamed");
+                (offset, offset.saturating_add(len))
+            },
+            None => (0, usize::MAX),
+        };
+
+        Self {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            post_predicate,
+            options,
+            cloud_options,
+            first_metadata,
+            decryption,
+            file_options,
+            slice,
+            row_offset: 0,
+            path_idx: 0,
+            current: None,
+        }
+    }
+
+    async fn open_next_file(&mut self) -> PolarsResult<Option<BatchedParquetReader>> {
+        while self.path_idx < self.paths.len() && self.row_offset < self.slice.1 {
+            let path_idx = self.path_idx;
+            self.path_idx += 1;
+
+            let path = &self.paths[path_idx];
+            let hive_partitions = self
+                .hive_parts
+                .as_ref()
+                .map(|parts| parts[path_idx].materialize_partition_columns());
+            let projection = materialize_projection(
+                self.file_options.with_columns.as_deref(),
+                &self.file_info.schema,
+                hive_partitions.as_deref(),
+                self.file_options.row_index.is_some(),
+            );
+            let include_file_path = self
+                .file_options
+                .include_file_paths
+                .as_ref()
+                .map(|col| (col.clone(), Arc::from(path.to_str().unwrap())));
+            let reader_schema = self.file_info.reader_schema.as_ref().unwrap();
+            let reader_schema = reader_schema.as_ref().unwrap_left();
+            let rows_before = self.row_offset;
+            let row_index = self.file_options.row_index.clone().map(|mut ri| {
+                ri.offset += rows_before as IdxSize;
+                ri
+            });
+
+            if polars_io::path_utils::is_cloud_url(path) {
+                #[cfg(feature = "cloud")]
+                {
+                    let metadata = self.first_metadata.clone().filter(|_| path_idx == 0);
+                    let mut reader = ParquetAsyncReader::from_uri(
+                        path.to_str().unwrap(),
+                        self.cloud_options.as_ref(),
+                        metadata,
+                    )
+                    .await?
+                    .with_decryption(self.decryption.clone());
+                    let num_rows = reader.num_rows().await?;
+                    let (offset, len) = split_slice_at_file(
+                        &mut self.row_offset,
+                        num_rows,
+                        self.slice.0,
+                        self.slice.1,
+                    );
+                    if len == 0 {
+                        continue;
+                    }
+
+                    let reader = reader
+                        .read_parallel(self.options.parallel)
+                        .use_statistics(self.options.use_statistics)
+                        .with_slice(Some((offset, len)))
+                        .with_projection(projection)
+                        .with_row_index(row_index)
+                        .with_predicate(self.predicate.clone())
+                        .with_hive_partition_columns(hive_partitions)
+                        .with_include_file_path(include_file_path)
+                        .check_schema(reader_schema)
+                        .await?
+                        .batched(get_ideal_morsel_size())
+                        .await?;
+                    return Ok(Some(reader));
+                }
+                #[cfg(not(feature = "cloud"))]
+                {
+                    panic!("activate cloud feature")
+                }
+            }
+
+            let mut reader = ParquetReader::new(polars_utils::open_file(path)?)
+                .with_decryption(self.decryption.clone());
+            let num_rows = reader.num_rows()?;
+            let (offset, len) =
+                split_slice_at_file(&mut self.row_offset, num_rows, self.slice.0, self.slice.1);
+            if len == 0 {
+                continue;
+            }
+
+            let reader = reader
+                .read_parallel(self.options.parallel)
+                .use_statistics(self.options.use_statistics)
+                .with_slice(Some((offset, len)))
+                .with_projection(projection)
+                .with_row_index(row_index)
+                .with_predicate(self.predicate.clone())
+                .with_hive_partition_columns(hive_partitions)
+                .with_include_file_path(include_file_path)
+                .check_schema(reader_schema)?
+                .batched(get_ideal_morsel_size())?;
+            return Ok(Some(reader));
+        }
+
+        Ok(None)
+    }
+}
+
+impl SourceReader for ParquetSourceReader {
+    fn next_batch(&mut self) -> BoxFuture<'_, PolarsResult<Option<DataFrame>>> {
+        Box::pin(async move {
+            loop {
+                let reader = match &mut self.current {
+                    Some(reader) => reader,
+                    None => match self.open_next_file().await? {
+                        Some(reader) => self.current.insert(reader),
+                        None => return Ok(None),
+                    },
+                };
+
+                let Some(dfs) = reader.next_batches(POOL.current_num_threads()).await? else {
+                    self.current = None;
+                    continue;
+                };
+
+                let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+                apply_predicate(&mut df, self.post_predicate.as_deref(), true)?;
+                return Ok(Some(df));
+            }
+        })
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::VecDeque;
+
+use arrow::array::BinaryArray;
+use polars_core::prelude::sort::arg_sort_multiple::{
+    _get_rows_encoded, _get_rows_encoded_unordered,
+};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{polars_bail, polars_ensure};
+use polars_ops::frame::{AsofStrategy, DataFrameJoinOps, JoinArgs, JoinType};
+use polars_ops::series::SeriesMethods;
+use polars_utils::aliases::PlHashSet;
+
+use crate::expression::StreamExpr;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+/// Converts a tolerance given as a duration string to the unit of the asof
+/// key, like the in-memory engine does.
+pub fn resolve_tolerance(args: &mut JoinArgs, key_dtype: &DataType) -> PolarsResult<()> {
+    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+
+    let JoinType::AsOf(options) = &mut args.how else {
+        return Ok(());
+    };
+    let Some(tolerance) = &options.tolerance_str else {
+        return Ok(());
+    };
+    let duration = polars_time::Duration::parse(tolerance);
+    polars_ensure!(
+        duration.months() == 0,
+        ComputeError: "cannot use month offset in timedelta of an asof join; \
+        consider using 4 weeks"
+    );
+    options.tolerance = Some(match key_dtype {
+        DataType::Datetime(tu, _) | DataType::Duration(tu) => AnyValue::from(match tu {
+            TimeUnit::Nanoseconds => duration.duration_ns(),
+            TimeUnit::Microseconds => duration.duration_us(),
+            TimeUnit::Milliseconds => duration.duration_ms(),
+        }),
+        DataType::Date => AnyValue::from((duration.duration_ms() / MILLISECONDS_IN_DAY) as i32),
+        DataType::Time => AnyValue::from(duration.duration_ns()),
+        dt => polars_bail!(
+            ComputeError: "can only use timedelta string language with Date/Datetime/Duration/Time dtypes, got {dt}"
+        ),
+    });
+    Ok(())
+}
+
+/// The keys of a sorted input, row encoded such that they compare as bytes.
+/// Nulls can only occur at the start of a sorted input.
+struct SortedKeys {
+    rows: BinaryArray<i64>,
+    null_count: usize,
+}
+
+impl SortedKeys {
+    fn new(key: &Series) -> PolarsResult<Self> {
+        let rows = _get_rows_encoded(&[key.clone()], &[false], &[false])?.into_array();
+        Ok(Self {
+            rows,
+            null_count: key.null_count(),
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn get(&self, idx: usize) -> &[u8] {
+        self.rows.value(idx)
+    }
+
+    fn first(&self) -> Option<&[u8]> {
+        (self.null_count < self.len()).then(|| self.get(self.null_count))
+    }
+
+    fn last(&self) -> Option<&[u8]> {
+        (self.null_count < self.len()).then(|| self.get(self.len() - 1))
+    }
+
+    /// The index of the first non-null key for which `pred` is false, the
+    /// keys for which `pred` is true must come first.
+    fn partition_point<F: Fn(&[u8]) -> bool>(&self, pred: F) -> usize {
+        let (mut lo, mut hi) = (self.null_count, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.get(mid)) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Checks that these keys are sorted and come after the keys of the
+    /// previous morsel of the same input.
+    fn check_sorted(&self, key: &Series, last: &mut Option<Vec<u8>>) -> PolarsResult<()> {
+        key.ensure_sorted_arg("asof_join")?;
+        if let Some(prev) = last {
+            polars_ensure!(
+                self.null_count == 0 && self.first().map_or(true, |first| prev.as_slice() <= first),
+                InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+            );
+        }
+        if let Some(key) = self.last() {
+            *last = Some(key.to_vec());
+        }
+        Ok(())
+    }
+}
+
+fn encode_by(df: &DataFrame, by: &Option<Vec<String>>) -> PolarsResult<Option<BinaryArray<i64>>> {
+    by.as_ref()
+        .map(|by| {
+            let by: Vec<Series> = df.columns(by)?.into_iter().cloned().collect();
+            Ok(_get_rows_encoded_unordered(&by)?.into_array())
+        })
+        .transpose()
+}
+
+/// The group of a row, all rows are in the same group without `by` columns.
+fn group(by: &Option<BinaryArray<i64>>, idx: usize) -> &[u8] {
+    by.as_ref().map_or(&[], |by| by.value(idx))
+}
+
+/// Buffered rows of the right input.
+struct RightChunk {
+    df: DataFrame,
+    key: Series,
+    keys: SortedKeys,
+    by: Option<BinaryArray<i64>>,
+}
+
+impl RightChunk {
+    fn new(df: DataFrame, key: Series, by: &Option<Vec<String>>) -> PolarsResult<Self> {
+        Ok(Self {
+            keys: SortedKeys::new(&key)?,
+            by: encode_by(&df, by)?,
+            df,
+            key,
+        })
+    }
+
+    /// Keeps only the rows at the given indices.
+    fn take(&mut self, idx: Vec<IdxSize>, by: &Option<Vec<String>>) -> PolarsResult<()> {
+        if idx.len() == self.key.len() {
+            return Ok(());
+        }
+        let idx = IdxCa::from_vec("", idx);
+        let df = self.df.take(&idx)?;
+        let key = self.key.take(&idx)?;
+        *self = Self::new(df, key, by)?;
+        Ok(())
+    }
+}
+
+/// Tracks whether enough of the right input is buffered to join a left morsel,
+/// given that later right rows have larger keys.
+struct Lookahead {
+    strategy: AsofStrategy,
+    /// The largest key of the left morsel.
+    max_key: Vec<u8>,
+    /// The groups of the left morsel for which no right row with a key after
+    /// `max_key` was seen yet.
+    groups: PlHashSet<Vec<u8>>,
+    /// A nearest join picks the last of equal right keys, so it needs a right
+    /// key larger than the first key after `max_key` of each group.
+    bound: Option<Vec<u8>>,
+    done: bool,
+}
+
+impl Lookahead {
+    fn new(
+        strategy: AsofStrategy,
+        keys: &SortedKeys,
+        by: &Option<BinaryArray<i64>>,
+    ) -> Option<Self> {
+        // Null keys aren't joined.
+        let max_key = keys.last()?.to_vec();
+        let groups = (keys.null_count..keys.len())
+            .map(|i| group(by, i).to_vec())
+            .collect();
+        Some(Self {
+            strategy,
+            max_key,
+            groups,
+            bound: None,
+            done: false,
+        })
+    }
+
+    fn update(&mut self, chunk: &RightChunk) {
+        let keys = &chunk.keys;
+        let start = keys.partition_point(|k| k < self.max_key.as_slice());
+        for i in start..keys.len() {
+            if self.done {
+                return;
+            }
+            let key = keys.get(i);
+            match self.strategy {
+                AsofStrategy::Backward => self.done = key > self.max_key.as_slice(),
+                AsofStrategy::Forward => {
+                    self.groups.remove(group(&chunk.by, i));
+                    self.done = self.groups.is_empty();
+                },
+                AsofStrategy::Nearest => {
+                    if let Some(bound) = &self.bound {
+                        self.done = key > bound.as_slice();
+                    } else if key > self.max_key.as_slice() {
+                        self.groups.remove(group(&chunk.by, i));
+                        if self.groups.is_empty() {
+                            self.bound = Some(key.to_vec());
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// An asof join of a sorted left input with a sorted right input.
+///
+/// Both inputs are consumed in lockstep: the right rows that can still be
+/// matched are buffered until each left morsel can be joined with the
+/// in-memory asof join, after which the right rows that can't be matched by
+/// later left morsels are dropped.
+pub struct AsOfJoinNode {
+    left_key_selector: StreamExpr,
+    right_key_selector: StreamExpr,
+    right_input_schema: Arc<Schema>,
+    args: JoinArgs,
+    strategy: AsofStrategy,
+    left_by: Option<Vec<String>>,
+    right_by: Option<Vec<String>>,
+    left_head: VecDeque<Morsel>,
+    right: VecDeque<RightChunk>,
+    right_done: bool,
+    last_left_key: Option<Vec<u8>>,
+    last_right_key: Option<Vec<u8>>,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        left_key_selector: StreamExpr,
+        right_key_selector: StreamExpr,
+        right_input_schema: Arc<Schema>,
+        args: JoinArgs,
+    ) -> Self {
+        let JoinType::AsOf(options) = &args.how else {
+            unreachable!()
+        };
+        Self {
+            left_key_selector,
+            right_key_selector,
+            right_input_schema,
+            strategy: options.strategy,
+            left_by: options
+                .left_by
+                .as_ref()
+                .map(|by| by.iter().map(|s| s.to_string()).collect()),
+            right_by: options
+                .right_by
+                .as_ref()
+                .map(|by| by.iter().map(|s| s.to_string()).collect()),
+            args,
+            left_head: VecDeque::new(),
+            right: VecDeque::new(),
+            right_done: false,
+            last_left_key: None,
+            last_right_key: None,
+        }
+    }
+
+    async fn receive_right(&mut self, morsel: Morsel, state: &ExecutionState) -> PolarsResult<()> {
+        let df = morsel.into_df();
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let key = self.right_key_selector.evaluate(&df, state).await?;
+        let chunk = RightChunk::new(df, key, &self.right_by)?;
+        chunk
+            .keys
+            .check_sorted(&chunk.key, &mut self.last_right_key)?;
+        self.right.push_back(chunk);
+        Ok(())
+    }
+
+    async fn check_left(&mut self, morsel: &Morsel, state: &ExecutionState) -> PolarsResult<()> {
+        let key = self.left_key_selector.evaluate(morsel.df(), state).await?;
+        SortedKeys::new(&key)?.check_sorted(&key, &mut self.last_left_key)
+    }
+
+    async fn join(
+        &self,
+        left: &DataFrame,
+        left_key: Series,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let (right, right_key) = if self.right.is_empty() {
+            let right = DataFrame::empty_with_schema(&self.right_input_schema);
+            let right_key = self.right_key_selector.evaluate(&right, state).await?;
+            (right, right_key)
+        } else {
+            let right =
+                accumulate_dataframes_vertical_unchecked(self.right.iter().map(|c| c.df.clone()));
+            let mut right_key = self.right[0].key.clone();
+            for chunk in self.right.iter().skip(1) {
+                right_key.append(&chunk.key)?;
+            }
+            (right, right_key)
+        };
+        left._join_impl(
+            &right,
+            vec![left_key],
+            vec![right_key],
+            self.args.clone(),
+            true,
+            false,
+        )
+    }
+
+    /// Drops the right rows which can't be matched by keys from `max_key` on.
+    fn prune(&mut self, max_key: &[u8]) -> PolarsResult<()> {
+        match self.strategy {
+            AsofStrategy::Forward => {
+                while let Some(chunk) = self.right.front_mut() {
+                    let start = chunk.keys.partition_point(|k| k < max_key);
+                    if start < chunk.keys.len() {
+                        chunk.take(
+                            (start as IdxSize..chunk.keys.len() as IdxSize).collect(),
+                            &self.right_by,
+                        )?;
+                        break;
+                    }
+                    self.right.pop_front();
+                }
+            },
+            AsofStrategy::Backward | AsofStrategy::Nearest => {
+                // Keep the last row with a key up to `max_key` of every group.
+                let mut seen = PlHashSet::new();
+                for c in (0..self.right.len()).rev() {
+                    let chunk = &mut self.right[c];
+                    let end = chunk.keys.partition_point(|k| k <= max_key);
+                    let mut keep = vec![];
+                    for i in (chunk.keys.null_count..end).rev() {
+                        if seen.insert(group(&chunk.by, i).to_vec()) {
+                            keep.push(i as IdxSize);
+                        }
+                    }
+                    keep.reverse();
+                    keep.extend(end as IdxSize..chunk.keys.len() as IdxSize);
+                    if keep.is_empty() {
+                        self.right.remove(c);
+                    } else {
+                        chunk.take(keep, &self.right_by)?;
+                    }
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof_join"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        self.right_done = recv[1] == PortState::Done;
+        let left_done = recv[0] == PortState::Done && self.left_head.is_empty();
+        if send[0] == PortState::Done || left_done {
+            self.left_head.clear();
+            self.right.clear();
+            send[0] = PortState::Done;
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            return Ok(());
+        }
+
+        let left_blocked = recv[0] == PortState::Blocked && self.left_head.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        if send[0] == PortState::Blocked || left_blocked || right_blocked {
+            send[0] = if left_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+            for r in recv.iter_mut() {
+                if *r != PortState::Done {
+                    *r = PortState::Blocked;
+                }
+            }
+        } else {
+            send[0] = PortState::Ready;
+            for r in recv.iter_mut() {
+                if *r != PortState::Done {
+                    *r = PortState::Ready;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 2 && send.len() == 1);
+        let mut sender = send[0].take().unwrap().serial();
+        let mut left_recv = recv[0].take().map(|r| r.serial());
+        let mut right_recv = recv[1].take().map(|r| r.serial());
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let source_token = SourceToken::new();
+            'morsels: loop {
+                if source_token.stop_requested() {
+                    break;
+                }
+
+                let morsel = match self.left_head.pop_front() {
+                    Some(morsel) => morsel,
+                    None => match &mut left_recv {
+                        Some(recv) => match recv.recv().await {
+                            Ok(morsel) => {
+                                self.check_left(&morsel, state).await?;
+                                morsel
+                            },
+                            Err(_) => break,
+                        },
+                        None => break,
+                    },
+                };
+                if morsel.df().height() == 0 {
+                    continue;
+                }
+
+                let left_key = self.left_key_selector.evaluate(morsel.df(), state).await?;
+                let left_keys = SortedKeys::new(&left_key)?;
+                let left_by = encode_by(morsel.df(), &self.left_by)?;
+                let mut lookahead = Lookahead::new(self.strategy, &left_keys, &left_by);
+
+                // Receive right morsels until the left morsel can be joined.
+                if let Some(lookahead) = &mut lookahead {
+                    for chunk in &self.right {
+                        lookahead.update(chunk);
+                    }
+                    while !lookahead.done && !self.right_done {
+                        let received = match &mut right_recv {
+                            Some(recv) => recv.recv().await.ok(),
+                            None => None,
+                        };
+                        let Some(right_morsel) = received else {
+                            // The right input is blocked, we continue in the
+                            // next phase.
+                            self.left_head.push_front(morsel);
+                            break 'morsels;
+                        };
+                        self.receive_right(right_morsel, state).await?;
+                        if let Some(chunk) = self.right.back() {
+                            lookahead.update(chunk);
+                        }
+                    }
+                }
+
+                let out = self.join(morsel.df(), left_key, state).await?;
+                if let Some(max_key) = left_keys.last() {
+                    self.prune(max_key)?;
+                }
+
+                let out = Morsel::new(out, morsel.seq(), source_token.clone());
+                drop(morsel);
+                if sender.send(out).await.is_err() {
+                    return Ok(());
+                }
+            }
+
+            // Stop the inputs and store the morsels that are still flowing
+            // for the next phase.
+            for morsel in &mut self.left_head {
+                morsel.source_token().stop();
+                drop(morsel.take_consume_token());
+            }
+            if let Some(recv) = &mut left_recv {
+                while let Ok(mut morsel) = recv.recv().await {
+                    morsel.source_token().stop();
+                    drop(morsel.take_consume_token());
+                    self.check_left(&morsel, state).await?;
+                    self.left_head.push_back(morsel);
+                }
+            }
+            if let Some(recv) = &mut right_recv {
+                while let Ok(morsel) = recv.recv().await {
+                    morsel.source_token().stop();
+                    self.receive_right(morsel, state).await?;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -0,0 +1,701 @@
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow::array::{Array, BinaryArray};
+use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::concatenate::concatenate;
+use arrow::compute::utils::combine_validities_and_many;
+use hashbrown::hash_map::RawEntryMut;
+use parking_lot::Mutex;
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_ops::frame::{JoinArgs, JoinType, _finish_join};
+use polars_utils::hashing::hash_to_partition;
+use polars_utils::idx_vec::UnitVec;
+use polars_utils::unitvec;
+use rayon::prelude::*;
+
+use crate::expression::{evaluate_broadcast, StreamExpr};
+use crate::memory::{memory_manager, LazySpillDir, MemoryReservation, SpillDir, Spilled};
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::{BlockingSourceReader, ScanSourceNode};
+
+/// The number of partitions the join is split into when it spills. The build
+/// side of a single partition has to fit in memory.
+const NUM_SPILL_PARTITIONS: usize = 16;
+
+/// The name of the column holding the row-encoded keys of spilled rows.
+const JOIN_KEY_COLUMN: &str = "__POLARS_JOIN_KEY";
+
+/// A key in the build table, refers to the first build row with this key.
+#[derive(Clone, Copy)]
+struct Key {
+    hash: u64,
+    idx: IdxSize,
+}
+
+impl Hash for Key {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+/// A morsel of the build side, together with its row-encoded join keys.
+struct BuildChunk {
+    seq: MorselSeq,
+    payload: DataFrame,
+    keys: BinaryArray<i64>,
+}
+
+impl BuildChunk {
+    /// Splits the rows belonging to the first `num_spilled` spill partitions
+    /// off this chunk, returning them by partition.
+    fn split_spilled_rows(
+        &mut self,
+        random_state: &RandomState,
+        num_spilled: usize,
+    ) -> Vec<Option<DataFrame>> {
+        let payload = std::mem::take(&mut self.payload);
+        let keys = std::mem::replace(
+            &mut self.keys,
+            BinaryArray::new_empty(ArrowDataType::LargeBinary),
+        );
+        let (payload, keys, spilled) = split_spilled_rows(random_state, payload, keys, num_spilled);
+        (self.payload, self.keys) = (payload, keys);
+        spilled
+    }
+
+    /// Returns the approximate number of bytes this chunk takes.
+    fn size(&self) -> usize {
+        self.payload.estimated_size() + estimated_bytes_size(&self.keys)
+    }
+
+    fn from_df(seq: MorselSeq, df: DataFrame) -> PolarsResult<Self> {
+        let (payload, keys) = split_key_column(df)?;
+        Ok(Self { seq, payload, keys })
+    }
+}
+
+/// Adds the row-encoded keys as extra column, to spill them along with `df`.
+fn with_key_column(mut df: DataFrame, keys: BinaryArray<i64>) -> DataFrame {
+    let keys = BinaryOffsetChunked::with_chunk(JOIN_KEY_COLUMN, keys).into_series();
+    unsafe { df.with_column_unchecked(keys) };
+    df
+}
+
+/// Splits the row-encoded keys off a dataframe created by [`with_key_column`].
+fn split_key_column(mut df: DataFrame) -> PolarsResult<(DataFrame, BinaryArray<i64>)> {
+    let keys = df.drop_in_place(JOIN_KEY_COLUMN)?;
+    let keys = keys.binary_offset()?.rechunk();
+    let keys = keys.downcast_iter().next().unwrap().clone();
+    Ok((df, keys))
+}
+
+/// Splits the rows belonging to the first `num_spilled` spill partitions off
+/// a dataframe and its keys, returning the spilled rows by partition.
+fn split_spilled_rows(
+    random_state: &RandomState,
+    df: DataFrame,
+    keys: BinaryArray<i64>,
+    num_spilled: usize,
+) -> (DataFrame, BinaryArray<i64>, Vec<Option<DataFrame>>) {
+    let mut kept = Vec::with_capacity(keys.len());
+    let mut spilled = vec![Vec::new(); num_spilled];
+    for (idx, key) in keys.iter().enumerate() {
+        // Null keys never match, they stay with the in-memory rows.
+        let p = key.map(|k| hash_to_partition(random_state.hash_one(k), NUM_SPILL_PARTITIONS));
+        match p {
+            Some(p) if p < num_spilled => spilled[p].push(idx as IdxSize),
+            _ => kept.push(idx as IdxSize),
+        }
+    }
+    if kept.len() == keys.len() {
+        return (df, keys, spilled.iter().map(|_| None).collect());
+    }
+
+    let df = with_key_column(df, keys);
+    let spilled = spilled
+        .iter()
+        .map(|idxs| (!idxs.is_empty()).then(|| unsafe { df._take_unchecked_slice(idxs, false) }))
+        .collect();
+    let (df, keys) =
+        split_key_column(unsafe { df._take_unchecked_slice(&kept, false) }).expect("invariant");
+    (df, keys, spilled)
+}
+
+/// The rows of a spill partition, which are joined once the probe input is
+/// exhausted.
+#[derive(Default)]
+struct SpillPartition {
+    build: Vec<(MorselSeq, Spilled)>,
+    probe: Vec<(MorselSeq, Spilled)>,
+}
+
+/// The build side of the join, hash partitioned on the join keys. Every
+/// partition maps a key to all the row indices in `payload` having that key.
+struct BuildTable {
+    payload: DataFrame,
+    keys: BinaryArray<i64>,
+    partitions: Vec<PlIdHashMap<Key, UnitVec<IdxSize>>>,
+    random_state: RandomState,
+}
+
+impl BuildTable {
+    fn new(mut chunks: Vec<BuildChunk>, payload_schema: &Schema, num_partitions: usize) -> Self {
+        // Keep the build side in a deterministic order.
+        chunks.sort_by_key(|c| c.seq);
+
+        let (payload, keys) = if chunks.is_empty() {
+            (
+                DataFrame::empty_with_schema(payload_schema),
+                BinaryArray::new_empty(ArrowDataType::LargeBinary),
+            )
+        } else {
+            let keys = chunks
+                .iter()
+                .map(|c| &c.keys as &dyn Array)
+                .collect::<Vec<_>>();
+            let keys = concatenate(&keys).unwrap();
+            let keys = keys
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .clone();
+            let payload =
+                accumulate_dataframes_vertical_unchecked(chunks.into_iter().map(|c| c.payload));
+            (payload, keys)
+        };
+
+        let random_state = RandomState::new();
+        let hashes: Vec<u64> = POOL.install(|| {
+            (0..keys.len())
+                .into_par_iter()
+                .map(|i| random_state.hash_one(unsafe { keys.value_unchecked(i) }))
+                .collect()
+        });
+
+        let partitions = POOL.install(|| {
+            (0..num_partitions)
+                .into_par_iter()
+                .map(|partition| {
+                    let mut table = PlIdHashMap::<Key, UnitVec<IdxSize>>::default();
+                    for (idx, h) in hashes.iter().enumerate() {
+                        if hash_to_partition(*h, num_partitions) != partition || !keys.is_valid(idx)
+                        {
+                            continue;
+                        }
+
+                        let key = unsafe { keys.value_unchecked(idx) };
+                        let entry = table.raw_entry_mut().from_hash(*h, |k| {
+                            k.hash == *h && unsafe { keys.value_unchecked(k.idx as usize) } == key
+                        });
+                        match entry {
+                            RawEntryMut::Vacant(entry) => {
+                                let k = Key {
+                                    hash: *h,
+                                    idx: idx as IdxSize,
+                                };
+                                entry.insert_hashed_nocheck(*h, k, unitvec![idx as IdxSize]);
+                            },
+                            RawEntryMut::Occupied(mut entry) => {
+                                entry.get_mut().push(idx as IdxSize);
+                            },
+                        }
+                    }
+                    table
+                })
+                .collect()
+        });
+
+        Self {
+            payload,
+            keys,
+            partitions,
+            random_state,
+        }
+    }
+
+    /// Get the build row indices matching the given row-encoded key.
+    #[inline]
+    fn get(&self, key: &[u8]) -> Option<&UnitVec<IdxSize>> {
+        let h = self.random_state.hash_one(key);
+        let partition = hash_to_partition(h, self.partitions.len());
+        self.partitions[partition]
+            .raw_entry()
+            .from_hash(h, |k| {
+                k.hash == h && unsafe { self.keys.value_unchecked(k.idx as usize) } == key
+            })
+            .map(|(_k, v)| v)
+    }
+}
+
+/// Row-encodes the join keys. If nulls should not be joined, rows with a null
+/// in any of the keys are marked invalid.
+fn encode_keys(keys: &[Series], join_nulls: bool) -> PolarsResult<BinaryArray<i64>> {
+    let rows = _get_rows_encoded_unordered(keys)?.into_array();
+    if join_nulls {
+        return Ok(rows);
+    }
+
+    let validities = keys
+        .iter()
+        .map(|s| s.rechunk().chunks()[0].validity().cloned())
+        .collect::<Vec<_>>();
+    Ok(rows.with_validity(combine_validities_and_many(&validities)))
+}
+
+enum EquiJoinState {
+    Build(Mutex<Vec<BuildChunk>>),
+    Probe(BuildTable),
+    /// Joining the spill partitions.
+    Spilled(ScanSourceNode),
+    Done,
+}
+
+/// A hash join which collects the right input into a partitioned hash table,
+/// after which the left input is streamed through it.
+///
+/// When asked to spill, the rows of a growing number of spill partitions are
+/// written to disk instead, those partitions are joined one at a time after
+/// the left input is exhausted.
+pub struct EquiJoinNode {
+    state: EquiJoinState,
+    num_pipelines: usize,
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    /// The schema of the right input that is kept in the output.
+    payload_schema: Arc<Schema>,
+    args: JoinArgs,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
+    spill_random_state: RandomState,
+    /// The first `num_spilled` spill partitions are spilled.
+    num_spilled: AtomicUsize,
+    spill_partitions: Mutex<Vec<SpillPartition>>,
+}
+
+impl EquiJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        right_key_names: Vec<String>,
+        args: JoinArgs,
+    ) -> PolarsResult<Self> {
+        let payload_schema = match args.how {
+            JoinType::Inner | JoinType::Left if args.should_coalesce() => right_input_schema
+                .iter()
+                .filter(|(name, _)| !right_key_names.iter().any(|k| k == name.as_str()))
+                .map(|(name, dtype)| Field::new(name, dtype.clone()))
+                .collect(),
+            JoinType::Inner | JoinType::Left => right_input_schema.as_ref().clone(),
+            // Semi and anti joins only need the keys.
+            _ => Schema::new(),
+        };
+
+        Ok(Self {
+            state: EquiJoinState::Build(Mutex::default()),
+            num_pipelines: 0,
+            left_key_selectors,
+            right_key_selectors,
+            payload_schema: Arc::new(payload_schema),
+            args,
+            reservation: memory_manager()?.register("equi_join"),
+            spill_dir: LazySpillDir::new("equi_join"),
+            spill_random_state: RandomState::new(),
+            num_spilled: AtomicUsize::new(0),
+            spill_partitions: Mutex::new(
+                (0..NUM_SPILL_PARTITIONS)
+                    .map(|_| SpillPartition::default())
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Spills the rows of a build chunk belonging to the first `num_spilled`
+    /// spill partitions.
+    fn spill_build_rows(&self, chunk: &mut BuildChunk, num_spilled: usize) -> PolarsResult<()> {
+        let size = chunk.size();
+        let spilled = chunk.split_spilled_rows(&self.spill_random_state, num_spilled);
+        for (p, df) in spilled.into_iter().enumerate() {
+            if let Some(df) = df {
+                let spilled = self.spill_dir.get()?.spill(df)?;
+                self.spill_partitions.lock()[p]
+                    .build
+                    .push((chunk.seq, spilled));
+            }
+        }
+        self.reservation.shrink(size - chunk.size());
+        Ok(())
+    }
+
+    /// Spills the rows of a probe morsel belonging to the first `num_spilled`
+    /// spill partitions, returning the other rows.
+    fn spill_probe_rows(
+        &self,
+        seq: MorselSeq,
+        df: DataFrame,
+        keys: BinaryArray<i64>,
+        num_spilled: usize,
+    ) -> PolarsResult<(DataFrame, BinaryArray<i64>)> {
+        let (df, keys, spilled) =
+            split_spilled_rows(&self.spill_random_state, df, keys, num_spilled);
+        for (p, df) in spilled.into_iter().enumerate() {
+            if let Some(df) = df {
+                let spilled = self.spill_dir.get()?.spill(df)?;
+                self.spill_partitions.lock()[p].probe.push((seq, spilled));
+            }
+        }
+        Ok((df, keys))
+    }
+}
+
+/// Joins a morsel of the left input, given its row-encoded keys, with the
+/// build table.
+fn probe(
+    df: DataFrame,
+    keys: &BinaryArray<i64>,
+    table: &BuildTable,
+    args: &JoinArgs,
+    payload_schema: &Schema,
+) -> PolarsResult<DataFrame> {
+    let matches = |idx: usize| {
+        if keys.is_valid(idx) {
+            table.get(unsafe { keys.value_unchecked(idx) })
+        } else {
+            None
+        }
+    };
+
+    match args.how {
+        JoinType::Inner | JoinType::Left => {
+            let is_left = args.how == JoinType::Left;
+            let mut left_idx: Vec<IdxSize> = Vec::with_capacity(df.height());
+            let mut right_idx: Vec<Option<IdxSize>> = Vec::with_capacity(df.height());
+            for idx in 0..df.height() {
+                match matches(idx) {
+                    Some(build_idxs) => {
+                        left_idx.extend(std::iter::repeat(idx as IdxSize).take(build_idxs.len()));
+                        right_idx.extend(build_idxs.iter().map(|i| Some(*i)));
+                    },
+                    None if is_left => {
+                        left_idx.push(idx as IdxSize);
+                        right_idx.push(None);
+                    },
+                    None => {},
+                }
+            }
+
+            let left_idx = IdxCa::from_vec("", left_idx);
+            let left_df = unsafe { df.take_unchecked_impl(&left_idx, false) };
+            let right_df = if table.payload.height() == 0 {
+                // Nothing to gather from, all rows are unmatched.
+                let columns = payload_schema
+                    .iter_fields()
+                    .map(|f| Series::full_null(&f.name, left_df.height(), &f.dtype))
+                    .collect();
+                unsafe { DataFrame::new_no_checks(columns) }
+            } else {
+                let right_idx: IdxCa = right_idx.into_iter().collect();
+                unsafe { table.payload.take_unchecked_impl(&right_idx, false) }
+            };
+            _finish_join(left_df, right_df, args.suffix.as_deref())
+        },
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => {
+            let is_anti = args.how == JoinType::Anti;
+            let mask: BooleanChunked = (0..df.height())
+                .map(|idx| matches(idx).is_some() != is_anti)
+                .collect();
+            df._filter_seq(&mask)
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Joins the spill partitions one at a time, once the probe input is
+/// exhausted. The joined rows keep the sequence number of their probe morsel,
+/// so the output of all partitions is spilled and merged in sequence order.
+struct SpilledJoinReader {
+    partitions: VecDeque<SpillPartition>,
+    /// The joined rows of every partition, ordered by sequence number.
+    joined: Vec<VecDeque<(MorselSeq, Spilled)>>,
+    /// The sequence number of the last batch.
+    seq: MorselSeq,
+    args: JoinArgs,
+    payload_schema: Arc<Schema>,
+    num_partitions: usize,
+    spill_dir: Arc<SpillDir>,
+}
+
+impl SpilledJoinReader {
+    fn join_partition(&mut self, partition: SpillPartition) -> PolarsResult<()> {
+        // Only probe rows can be part of the output.
+        if partition.probe.is_empty() {
+            return Ok(());
+        }
+        let chunks = partition
+            .build
+            .into_iter()
+            .map(|(seq, chunk)| BuildChunk::from_df(seq, chunk.load()?))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let table = BuildTable::new(chunks, &self.payload_schema, self.num_partitions);
+
+        let mut probe_chunks = partition.probe;
+        probe_chunks.sort_by_key(|(seq, _)| *seq);
+        let mut joined = VecDeque::with_capacity(probe_chunks.len());
+        for (seq, chunk) in probe_chunks {
+            let (df, keys) = split_key_column(chunk.load()?)?;
+            let out = probe(df, &keys, &table, &self.args, &self.payload_schema)?;
+            if out.height() > 0 {
+                joined.push_back((seq, self.spill_dir.spill(out)?));
+            }
+        }
+        self.joined.push(joined);
+        Ok(())
+    }
+}
+
+impl BlockingSourceReader for SpilledJoinReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        while let Some(partition) = self.partitions.pop_front() {
+            self.join_partition(partition)?;
+        }
+
+        let next = self
+            .joined
+            .iter()
+            .enumerate()
+            .filter_map(|(i, chunks)| chunks.front().map(|(seq, _)| (*seq, i)))
+            .min();
+        let Some((seq, i)) = next else {
+            return Ok(None);
+        };
+        let (_, chunk) = self.joined[i].pop_front().unwrap();
+        self.seq = seq;
+        chunk.load().map(Some)
+    }
+
+    fn batch_seq(&self) -> Option<MorselSeq> {
+        Some(self.seq)
+    }
+}
+
+impl ComputeNode for EquiJoinNode {
+    fn name(&self) -> &str {
+        "equi_join"
+    }
+
+    fn initialize(&mut self, num_pipelines: usize) {
+        self.num_pipelines = num_pipelines;
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self.state, EquiJoinState::Done) {
+            self.state = EquiJoinState::Done;
+            self.reservation.release();
+        }
+
+        // If the build input is done, build the hash table and start probing.
+        if let EquiJoinState::Build(chunks) = &mut self.state {
+            if recv[1] == PortState::Done {
+                let mut chunks = core::mem::take(chunks.get_mut());
+                // Pipelines which finished before the last partition was
+                // spilled may still hold rows of it.
+                let num_spilled = *self.num_spilled.get_mut();
+                if num_spilled > 0 {
+                    let spill_partitions = self.spill_partitions.get_mut();
+                    for chunk in &mut chunks {
+                        let spilled =
+                            chunk.split_spilled_rows(&self.spill_random_state, num_spilled);
+                        for (p, df) in spilled.into_iter().enumerate() {
+                            if let Some(df) = df {
+                                spill_partitions[p]
+                                    .build
+                                    .push((chunk.seq, Spilled::InMemory(df)));
+                            }
+                        }
+                    }
+                }
+                let table = BuildTable::new(chunks, &self.payload_schema, self.num_pipelines);
+                self.state = EquiJoinState::Probe(table);
+            }
+        }
+
+        // If the probe input is done, join the spill partitions if there are
+        // any, otherwise we are done.
+        if let EquiJoinState::Probe(_) = &self.state {
+            if recv[0] == PortState::Done {
+                self.reservation.release();
+                let num_spilled = *self.num_spilled.get_mut();
+                if num_spilled > 0 {
+                    let partitions = core::mem::take(self.spill_partitions.get_mut());
+                    let reader = SpilledJoinReader {
+                        partitions: partitions.into_iter().take(num_spilled).collect(),
+                        joined: Vec::new(),
+                        seq: MorselSeq::default(),
+                        args: self.args.clone(),
+                        payload_schema: self.payload_schema.clone(),
+                        num_partitions: self.num_pipelines,
+                        spill_dir: self.spill_dir.get()?.clone(),
+                    };
+                    let mut source_node = ScanSourceNode::new("equi_join", Box::new(reader));
+                    source_node.initialize(self.num_pipelines);
+                    self.state = EquiJoinState::Spilled(source_node);
+                } else {
+                    self.state = EquiJoinState::Done;
+                }
+            }
+        }
+
+        match &mut self.state {
+            EquiJoinState::Build(_) => {
+                recv[0] = PortState::Blocked;
+                recv[1] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            EquiJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            EquiJoinState::Spilled(source_node) => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                source_node.update_state(&mut [], send)?;
+            },
+            EquiJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, EquiJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 2 && send.len() == 1);
+        if matches!(self.state, EquiJoinState::Spilled(_)) {
+            let EquiJoinState::Spilled(source) = &mut self.state else {
+                unreachable!()
+            };
+            return source.spawn(scope, &mut [], send, state, join_handles);
+        }
+
+        let slf = &*self;
+        match &slf.state {
+            EquiJoinState::Build(chunks) => {
+                assert!(recv[0].is_none() && send[0].is_none());
+                let receivers = recv[1].take().unwrap().parallel();
+
+                for mut recv in receivers {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut local_chunks: Vec<BuildChunk> = Vec::new();
+                        let mut local_num_spilled = 0;
+                        while let Ok(morsel) = recv.recv().await {
+                            let (df, seq, _source_token, consume_token) = morsel.into_inner();
+                            drop(consume_token);
+
+                            let keys =
+                                evaluate_broadcast(&slf.right_key_selectors, &df, state).await?;
+                            let keys = encode_keys(&keys, slf.args.join_nulls)?;
+                            let payload = if slf.payload_schema.is_empty() {
+                                DataFrame::empty()
+                            } else {
+                                df.select(slf.payload_schema.iter_names())?
+                            };
+                            let chunk = BuildChunk { seq, payload, keys };
+                            slf.reservation.grow(chunk.size());
+                            local_chunks.push(chunk);
+
+                            // Spill one more partition for every request.
+                            if slf.reservation.spill_requested() {
+                                let _ = slf.num_spilled.fetch_update(
+                                    Ordering::Relaxed,
+                                    Ordering::Relaxed,
+                                    |n| (n < NUM_SPILL_PARTITIONS).then_some(n + 1),
+                                );
+                            }
+                            let num_spilled = slf.num_spilled.load(Ordering::Relaxed);
+                            if num_spilled > 0 {
+                                // Only the new chunk can hold rows of spilled
+                                // partitions, unless more partitions were spilled.
+                                let start = if num_spilled > local_num_spilled {
+                                    0
+                                } else {
+                                    local_chunks.len() - 1
+                                };
+                                for chunk in &mut local_chunks[start..] {
+                                    slf.spill_build_rows(chunk, num_spilled)?;
+                                }
+                                local_num_spilled = num_spilled;
+                            }
+                        }
+
+                        chunks.lock().extend(local_chunks);
+                        Ok(())
+                    }));
+                }
+            },
+            EquiJoinState::Probe(table) => {
+                assert!(recv[1].is_none());
+                let receivers = recv[0].take().unwrap().parallel();
+                let senders = send[0].take().unwrap().parallel();
+
+                let num_spilled = slf.num_spilled.load(Ordering::Relaxed);
+
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let seq = morsel.seq();
+                            let morsel = morsel
+                                .async_try_map(|df| async move {
+                                    let keys =
+                                        evaluate_broadcast(&slf.left_key_selectors, &df, state)
+                                            .await?;
+                                    let mut keys = encode_keys(&keys, slf.args.join_nulls)?;
+                                    let mut df = df;
+                                    if num_spilled > 0 {
+                                        (df, keys) =
+                                            slf.spill_probe_rows(seq, df, keys, num_spilled)?;
+                                    }
+                                    probe(df, &keys, table, &slf.args, &slf.payload_schema)
+                                })
+                                .await?;
+
+                            if morsel.df().is_empty() {
+                                continue;
+                            }
+
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+
+                        Ok(())
+                    }));
+                }
+            },
+            EquiJoinState::Spilled(_) | EquiJoinState::Done => unreachable!(),
+        }
+    }
+}
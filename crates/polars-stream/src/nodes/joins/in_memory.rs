@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use polars_core::schema::Schema;
+
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::in_memory_sink::InMemorySinkNode;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+type JoinFn = Box<dyn FnMut(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send>;
+
+/// Fallback join node which materializes both inputs and joins them with the
+/// in-memory engine.
+pub enum InMemoryJoinNode {
+    Sink {
+        left_input: InMemorySinkNode,
+        right_input: InMemorySinkNode,
+        num_pipelines: usize,
+        joiner: JoinFn,
+    },
+    Source(InMemorySourceNode),
+    Done,
+}
+
+impl InMemoryJoinNode {
+    pub fn new(
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        joiner: JoinFn,
+    ) -> Self {
+        Self::Sink {
+            left_input: InMemorySinkNode::new(left_input_schema),
+            right_input: InMemorySinkNode::new(right_input_schema),
+            num_pipelines: 0,
+            joiner,
+        }
+    }
+}
+
+impl ComputeNode for InMemoryJoinNode {
+    fn name(&self) -> &str {
+        "in_memory_join"
+    }
+
+    fn initialize(&mut self, num_pipelines_: usize) {
+        match self {
+            Self::Sink { num_pipelines, .. } => *num_pipelines = num_pipelines_,
+            _ => unreachable!(),
+        }
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self, Self::Done) {
+            *self = Self::Done;
+        }
+
+        // If both inputs are done, transition to being a source.
+        if let Self::Sink {
+            left_input,
+            right_input,
+            num_pipelines,
+            joiner,
+        } = self
+        {
+            if recv[0] == PortState::Done && recv[1] == PortState::Done {
+                let left_df = left_input.get_output()?.unwrap();
+                let right_df = right_input.get_output()?.unwrap();
+                let mut source_node = InMemorySourceNode::new(Arc::new(joiner(left_df, right_df)?));
+                source_node.initialize(*num_pipelines);
+                *self = Self::Source(source_node);
+            }
+        }
+
+        match self {
+            Self::Sink {
+                left_input,
+                right_input,
+                ..
+            } => {
+                left_input.update_state(&mut recv[0..1], &mut [])?;
+                right_input.update_state(&mut recv[1..2], &mut [])?;
+                send[0] = PortState::Blocked;
+            },
+            Self::Source(source_node) => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                source_node.update_state(&mut [], send)?;
+            },
+            Self::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self, Self::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 2 && send.len() == 1);
+        match self {
+            Self::Sink {
+                left_input,
+                right_input,
+                ..
+            } => {
+                if recv[0].is_some() {
+                    left_input.spawn(scope, &mut recv[0..1], &mut [], state, join_handles);
+                }
+                if recv[1].is_some() {
+                    right_input.spawn(scope, &mut recv[1..2], &mut [], state, join_handles);
+                }
+            },
+            Self::Source(source) => source.spawn(scope, &mut [], send, state, join_handles),
+            Self::Done => unreachable!(),
+        }
+    }
+}
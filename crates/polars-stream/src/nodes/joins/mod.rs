@@ -0,0 +1,4 @@
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
+pub mod equi_join;
+pub mod in_memory;
@@ -1,12 +1,23 @@
 pub mod filter;
+pub mod group_by;
 pub mod in_memory_map;
 pub mod in_memory_sink;
 pub mod in_memory_source;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
+pub mod io_sinks;
+pub mod io_sources;
+pub mod joins;
 pub mod map;
 pub mod ordered_union;
 pub mod reduce;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
 pub mod streaming_slice;
 pub mod zip;
 
@@ -44,7 +55,7 @@ pub trait ComputeNode: Send {
     /// Similarly, for each output pipe `send` will contain the respective
     /// state of the input port that pipe is connected to when called, and you
     /// must update it to contain the desired state of your output port.
-    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]);
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()>;
 
     /// If this node (in its current state) is a pipeline blocker, and whether
     /// this is memory intensive or not.
@@ -97,7 +97,7 @@ impl ComputeNode for ReduceNode {
         "reduce"
     }
 
-    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) {
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
         assert!(recv.len() == 1 && send.len() == 1);
 
         // State transitions.
@@ -108,17 +108,11 @@ impl ComputeNode for ReduceNode {
             },
             // Input is done, transition to being a source.
             ReduceState::Sink { reductions, .. } if matches!(recv[0], PortState::Done) => {
-                // TODO! make `update_state` fallible.
                 let columns = reductions
                     .iter_mut()
                     .zip(self.output_schema.iter_fields())
-                    .map(|(r, field)| {
-                        r.finalize().map(|scalar| {
-                            scalar.into_series(&field.name).cast(&field.dtype).unwrap()
-                        })
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()
-                    .unwrap();
+                    .map(|(r, field)| r.finalize()?.into_series(&field.name).cast(&field.dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
                 let out = unsafe { DataFrame::new_no_checks(columns) };
 
                 self.state = ReduceState::Source(Some(out));
@@ -146,6 +140,7 @@ impl ComputeNode for ReduceNode {
                 send[0] = PortState::Done;
             },
         }
+        Ok(())
     }
 
     fn spawn<'env, 's>(
@@ -0,0 +1,406 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use super::compute_node_prelude::*;
+use super::io_sources::{BlockingSourceReader, ScanSourceNode};
+use crate::expression::{evaluate_broadcast, StreamExpr};
+use crate::memory::{memory_manager, LazySpillDir, MemoryReservation, SpillDir, Spilled};
+use crate::morsel::get_ideal_morsel_size;
+
+/// The name of the column holding the row-encoded sort keys.
+const SORT_KEY_COLUMN: &str = "__POLARS_SORT_KEY";
+
+/// Splits a sorted run into chunks, spilling them if `spill_dir` is given.
+fn into_run(df: DataFrame, spill_dir: Option<&SpillDir>) -> PolarsResult<VecDeque<Spilled>> {
+    let chunk_size = get_ideal_morsel_size();
+    let mut run = VecDeque::with_capacity(df.height().div_ceil(chunk_size));
+    let mut offset = 0;
+    while offset < df.height() {
+        let chunk = df.slice(offset as i64, chunk_size);
+        offset += chunk.height();
+        run.push_back(match spill_dir {
+            Some(dir) => dir.spill(chunk)?,
+            None => Spilled::InMemory(chunk),
+        });
+    }
+    Ok(run)
+}
+
+/// Sorts a dataframe on its sort key column.
+fn sort_on_keys(df: &DataFrame, maintain_order: bool) -> PolarsResult<DataFrame> {
+    let keys = df.column(SORT_KEY_COLUMN)?.binary_offset()?;
+    let idx = keys.arg_sort(SortOptions {
+        maintain_order,
+        ..Default::default()
+    });
+    Ok(unsafe { df.take_unchecked(&idx) })
+}
+
+/// Sorts buffered input, dropping the rows which lie beyond the slice.
+fn sort_buffered(
+    buffered: impl IntoIterator<Item = DataFrame>,
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let df = accumulate_dataframes_vertical_unchecked(buffered);
+    let df = sort_on_keys(&df, maintain_order)?;
+    Ok(match slice {
+        Some((offset, len)) => df.slice(0, offset as usize + len),
+        None => df,
+    })
+}
+
+/// The input buffered by a single pipeline.
+#[derive(Default)]
+struct LocalSort {
+    buffered: Vec<DataFrame>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+    runs: Vec<VecDeque<Spilled>>,
+}
+
+impl LocalSort {
+    /// Sorts the buffered input into a run which is spilled to disk.
+    fn spill(
+        &mut self,
+        spill_dir: &SpillDir,
+        params: &SortParams,
+        reservation: &MemoryReservation,
+    ) -> PolarsResult<()> {
+        let df = sort_buffered(self.buffered.drain(..), params.maintain_order, params.slice)?;
+        self.runs.push(into_run(df, Some(spill_dir))?);
+        reservation.shrink(self.buffered_bytes);
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+}
+
+struct SortParams {
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+}
+
+impl SortParams {
+    /// Adds the row-encoded sort keys to a morsel, which compare bytewise in
+    /// the requested order. To maintain the order of equal keys the sequence
+    /// number and position of each row are included in the key.
+    fn add_sort_keys(
+        &self,
+        mut df: DataFrame,
+        mut keys: Vec<Series>,
+        seq: MorselSeq,
+    ) -> PolarsResult<DataFrame> {
+        let mut descending = self.descending.clone();
+        let mut nulls_last = self.nulls_last.clone();
+        if self.maintain_order {
+            let height = df.height();
+            keys.push(UInt64Chunked::full("", seq.to_u64(), height).into_series());
+            keys.push(IdxCa::from_vec("", (0..height as IdxSize).collect()).into_series());
+            descending.extend([false, false]);
+            nulls_last.extend([false, false]);
+        }
+        let encoded = _get_rows_encoded_ca(SORT_KEY_COLUMN, &keys, &descending, &nulls_last)?;
+        df.with_column(encoded.into_series())?;
+        Ok(df)
+    }
+}
+
+/// Produces the sorted output by merging the sorted runs.
+struct RunMerger {
+    runs: Vec<VecDeque<Spilled>>,
+    /// The input which wasn't sorted into a run yet.
+    unsorted: Vec<DataFrame>,
+    /// The current chunk of each run, its keys and the offset of the first
+    /// row which hasn't been output yet.
+    current: Vec<Option<(DataFrame, BinaryArray<i64>, usize)>>,
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+    offset: usize,
+    remaining: Option<usize>,
+    _spill_dir: Option<Arc<SpillDir>>,
+}
+
+impl RunMerger {
+    fn new(
+        runs: Vec<VecDeque<Spilled>>,
+        unsorted: Vec<DataFrame>,
+        params: &SortParams,
+        spill_dir: Option<Arc<SpillDir>>,
+    ) -> Self {
+        let (offset, remaining) = match params.slice {
+            Some((offset, len)) => (offset as usize, Some(len)),
+            None => (0, None),
+        };
+        Self {
+            current: Vec::new(),
+            runs,
+            unsorted,
+            maintain_order: params.maintain_order,
+            slice: params.slice,
+            offset,
+            remaining,
+            _spill_dir: spill_dir,
+        }
+    }
+
+    /// Makes sure every run which isn't exhausted has a current chunk with
+    /// rows left.
+    fn load_chunks(&mut self) -> PolarsResult<()> {
+        if !self.unsorted.is_empty() {
+            let df = sort_buffered(self.unsorted.drain(..), self.maintain_order, self.slice)?;
+            self.runs.push(into_run(df, None)?);
+        }
+        self.current.resize_with(self.runs.len(), || None);
+
+        for (run, current) in self.runs.iter_mut().zip(&mut self.current) {
+            while current
+                .as_ref()
+                .map_or(true, |(_, keys, i)| *i == keys.len())
+            {
+                let Some(chunk) = run.pop_front() else {
+                    *current = None;
+                    break;
+                };
+                let df = chunk.load()?;
+                let keys = df.column(SORT_KEY_COLUMN)?.binary_offset()?.rechunk();
+                let keys = keys.downcast_iter().next().unwrap().clone();
+                *current = Some((df, keys, 0));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the rows of all runs up to the smallest last key of the current
+    /// chunks, all rows up to there are known.
+    fn merge_next(&mut self) -> PolarsResult<Option<DataFrame>> {
+        self.load_chunks()?;
+        let Some(bound) = self
+            .current
+            .iter()
+            .flatten()
+            .map(|(_, keys, _)| keys.value(keys.len() - 1))
+            .min()
+            .map(|k| k.to_vec())
+        else {
+            return Ok(None);
+        };
+
+        let mut parts = Vec::with_capacity(self.current.len());
+        for (df, keys, start) in self.current.iter_mut().flatten() {
+            // Binary search for the first key beyond the bound.
+            let (mut lo, mut hi) = (*start, keys.len());
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if keys.value(mid) <= bound.as_slice() {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            if lo > *start {
+                parts.push(df.slice(*start as i64, lo - *start));
+            }
+            *start = lo;
+        }
+
+        let df = if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            sort_on_keys(
+                &accumulate_dataframes_vertical_unchecked(parts),
+                self.maintain_order,
+            )?
+        };
+        Ok(Some(df))
+    }
+}
+
+impl BlockingSourceReader for RunMerger {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if self.remaining == Some(0) {
+                return Ok(None);
+            }
+            let Some(df) = self.merge_next()? else {
+                return Ok(None);
+            };
+            let mut df = df.drop(SORT_KEY_COLUMN)?;
+
+            if self.offset > 0 {
+                let skip = self.offset.min(df.height());
+                self.offset -= skip;
+                df = df.slice(skip as i64, df.height() - skip);
+            }
+            if let Some(remaining) = &mut self.remaining {
+                df = df.slice(0, *remaining);
+                *remaining -= df.height();
+            }
+            if df.height() > 0 {
+                return Ok(Some(df));
+            }
+        }
+    }
+}
+
+enum SortState {
+    Sink { locals: Vec<LocalSort> },
+    Source(ScanSourceNode),
+    Done,
+}
+
+/// A sort which sorts the input of every pipeline into runs on row-encoded
+/// keys, spilling them to disk when asked to by the memory manager. Once the
+/// input is exhausted the runs are merged.
+pub struct SortNode {
+    state: SortState,
+    num_pipelines: usize,
+    key_selectors: Vec<StreamExpr>,
+    params: SortParams,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
+}
+
+impl SortNode {
+    pub fn new(
+        key_selectors: Vec<StreamExpr>,
+        slice: Option<(i64, usize)>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<Self> {
+        let num_keys = key_selectors.len();
+        let broadcast = |v: Vec<bool>| {
+            if v.len() == 1 {
+                vec![v[0]; num_keys]
+            } else {
+                v
+            }
+        };
+        Ok(Self {
+            state: SortState::Sink { locals: Vec::new() },
+            num_pipelines: 0,
+            key_selectors,
+            params: SortParams {
+                descending: broadcast(sort_options.descending),
+                nulls_last: broadcast(sort_options.nulls_last),
+                maintain_order: sort_options.maintain_order,
+                slice,
+            },
+            reservation: memory_manager()?.register("sort"),
+            spill_dir: LazySpillDir::new("sort"),
+        })
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn initialize(&mut self, num_pipelines: usize) {
+        self.num_pipelines = num_pipelines;
+        if let SortState::Sink { locals } = &mut self.state {
+            *locals = (0..num_pipelines).map(|_| LocalSort::default()).collect();
+        }
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self.state, SortState::Done) {
+            self.state = SortState::Done;
+            self.reservation.release();
+        }
+
+        // If the input is done, transition to being a source merging the runs.
+        if let SortState::Sink { locals } = &mut self.state {
+            if recv[0] == PortState::Done {
+                let mut runs = Vec::new();
+                let mut unsorted = Vec::new();
+                for local in std::mem::take(locals) {
+                    runs.extend(local.runs);
+                    unsorted.extend(local.buffered);
+                }
+                let spill_dir = self.spill_dir.created();
+                let merger = RunMerger::new(runs, unsorted, &self.params, spill_dir);
+                let mut source_node = ScanSourceNode::new("sort", Box::new(merger));
+                source_node.initialize(self.num_pipelines);
+                self.state = SortState::Source(source_node);
+            }
+        }
+
+        match &mut self.state {
+            SortState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(source_node) => {
+                recv[0] = PortState::Done;
+                source_node.update_state(&mut [], send)?;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv: &mut [Option<RecvPort<'_>>],
+        send: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv.len() == 1 && send.len() == 1);
+        match &mut self.state {
+            SortState::Sink { locals } => {
+                assert!(send[0].is_none());
+                let receivers = recv[0].take().unwrap().parallel();
+                let key_selectors = &self.key_selectors;
+                let params = &self.params;
+                let reservation = &self.reservation;
+                let spill_dir = &self.spill_dir;
+
+                for (mut recv, local) in receivers.into_iter().zip(locals.iter_mut()) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let (df, seq, _source_token, consume_token) = morsel.into_inner();
+                            drop(consume_token);
+
+                            let keys = evaluate_broadcast(key_selectors, &df, state).await?;
+                            let df = params.add_sort_keys(df, keys, seq)?;
+                            let bytes = df.estimated_size();
+                            local.buffered_rows += df.height();
+                            local.buffered_bytes += bytes;
+                            local.buffered.push(df);
+
+                            reservation.grow(bytes);
+                            if reservation.spill_requested()
+                                && local.buffered_rows >= get_ideal_morsel_size()
+                            {
+                                local.spill(spill_dir.get()?, params, reservation)?;
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            SortState::Source(source) => source.spawn(scope, &mut [], send, state, join_handles),
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -24,7 +24,7 @@ impl ComputeNode for ZipNode {
         "zip"
     }
 
-    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) {
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
         assert!(send.len() == 1);
         assert!(!recv.is_empty());
 
@@ -72,6 +72,7 @@ impl ComputeNode for ZipNode {
         for r in recv {
             *r = new_recv_state;
         }
+        Ok(())
     }
 
     fn spawn<'env, 's>(
@@ -1,9 +1,22 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use polars_core::prelude::DataType;
+use polars_core::schema::Schema;
 use polars_error::PolarsResult;
-use polars_expr::reduce::can_convert_into_reduction;
-use polars_plan::plans::{AExpr, Context, IR};
-use polars_plan::prelude::SinkType;
+use polars_expr::reduce::{can_convert_into_reduction, into_grouped_reduction};
+use polars_ops::frame::{JoinArgs, JoinType};
+use polars_plan::global::_set_n_rows_for_scan;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_plan::plans::{AExpr, Context, FileScan, IR};
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
+use polars_plan::prelude::FileType;
+use polars_plan::prelude::{FileScanOptions, GroupbyOptions, SinkType};
 use polars_utils::arena::{Arena, Node};
 use slotmap::SlotMap;
 
@@ -13,6 +26,181 @@ fn is_streamable(node: Node, arena: &Arena<AExpr>) -> bool {
     polars_plan::plans::is_streamable(node, arena, Context::Default)
 }
 
+/// Whether keys of this type can be row-encoded and compared bytewise.
+fn is_hashable_key_dtype(dtype: &DataType) -> bool {
+    dtype.is_numeric()
+        || dtype.is_bool()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::String | DataType::Binary)
+}
+
+/// Whether this join can be executed by the streaming hash join.
+fn is_streamable_equi_join(
+    args: &JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    left_schema: &Schema,
+    right_schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let supported_type = match args.how {
+        JoinType::Inner | JoinType::Left => true,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => true,
+        _ => false,
+    };
+    let supported_slice = args.slice.map(|(offset, _)| offset >= 0).unwrap_or(true);
+    if !supported_type || !supported_slice || args.validation.needs_checks() {
+        return false;
+    }
+
+    let key_dtype = |e: &ExprIR, schema: &Schema| {
+        expr_arena
+            .get(e.node())
+            .to_dtype(schema, Context::Default, expr_arena)
+    };
+    left_on.iter().zip(right_on).all(|(l, r)| {
+        if !is_streamable(l.node(), expr_arena) || !is_streamable(r.node(), expr_arena) {
+            return false;
+        }
+        match (key_dtype(l, left_schema), key_dtype(r, right_schema)) {
+            (Ok(l), Ok(r)) => l == r && is_hashable_key_dtype(&l),
+            _ => false,
+        }
+    })
+}
+
+/// Whether this join can be executed by the streaming asof join.
+#[cfg(feature = "asof_join")]
+fn is_streamable_asof_join(
+    args: &JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let JoinType::AsOf(options) = &args.how else {
+        return false;
+    };
+    let supported_slice = args.slice.map(|(offset, _)| offset >= 0).unwrap_or(true);
+    supported_slice
+        && options.left_by.is_some() == options.right_by.is_some()
+        && left_on.len() == 1
+        && right_on.len() == 1
+        && is_streamable(left_on[0].node(), expr_arena)
+        && is_streamable(right_on[0].node(), expr_arena)
+}
+
+/// Whether this group-by can be executed by the streaming hash group-by.
+fn is_streamable_group_by(
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    input_schema: &Schema,
+    apply: bool,
+    maintain_order: bool,
+    options: &GroupbyOptions,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    // Only plain group-bys, without rolling or dynamic windows.
+    #[allow(clippy::needless_update)]
+    let plain_options = GroupbyOptions {
+        slice: options.slice,
+        ..Default::default()
+    };
+    let supported_slice = options.slice.map(|(offset, _)| offset >= 0).unwrap_or(true);
+    if apply || maintain_order || keys.is_empty() || !supported_slice || *options != plain_options {
+        return false;
+    }
+
+    let keys_supported = keys.iter().all(|k| {
+        is_streamable(k.node(), expr_arena)
+            && expr_arena
+                .get(k.node())
+                .to_dtype(input_schema, Context::Default, expr_arena)
+                .is_ok_and(|dtype| is_hashable_key_dtype(&dtype))
+    });
+    keys_supported
+        && aggs.iter().all(
+            |a| match into_grouped_reduction(a.node(), expr_arena, input_schema) {
+                Ok(Some((_, inputs))) => inputs.iter().all(|i| is_streamable(*i, expr_arena)),
+                _ => false,
+            },
+        )
+}
+
+/// Whether this sort can be executed by the streaming sort.
+fn is_streamable_sort(
+    by_column: &[ExprIR],
+    slice: Option<(i64, usize)>,
+    input_schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let supported_slice = slice.map(|(offset, _)| offset >= 0).unwrap_or(true);
+    supported_slice
+        && by_column.iter().all(|e| {
+            is_streamable(e.node(), expr_arena)
+                && expr_arena
+                    .get(e.node())
+                    .to_dtype(input_schema, Context::Default, expr_arena)
+                    .is_ok_and(|dtype| is_hashable_key_dtype(&dtype))
+        })
+}
+
+/// Whether this scan can be read by the streaming file readers.
+fn is_streamable_scan(
+    paths: &[PathBuf],
+    scan_type: &FileScan,
+    predicate: Option<&ExprIR>,
+    file_options: &FileScanOptions,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let supported_type = match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { .. } => true,
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { .. } => true,
+        #[cfg(feature = "csv")]
+        FileScan::Csv { .. } => true,
+        #[cfg(feature = "json")]
+        FileScan::NDJson { .. } => true,
+        #[cfg(feature = "avro")]
+        FileScan::Avro { .. } => true,
+        _ => false,
+    };
+    let supported_slice = file_options
+        .slice
+        .map(|(offset, _)| offset >= 0)
+        .unwrap_or(true);
+    // Remote files can only be read with the cloud feature.
+    let readable = !paths.is_empty()
+        && (cfg!(feature = "cloud") || !paths.iter().any(polars_io::path_utils::is_cloud_url));
+    supported_type
+        && supported_slice
+        && readable
+        && predicate.map_or(true, |p| is_streamable(p.node(), expr_arena))
+}
+
+/// Whether this file type can be written by the streaming file writers.
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
+fn is_streamable_sink(file_type: &FileType) -> bool {
+    match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(_) => true,
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(_) => true,
+        #[cfg(feature = "csv")]
+        FileType::Csv(_) => true,
+        #[cfg(feature = "avro")]
+        FileType::Avro(_) => true,
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
 #[recursive::recursive]
 pub fn lower_ir(
     node: Node,
@@ -147,6 +335,56 @@ pub fn lower_ir(
             Ok(phys_node)
         },
 
+        IR::Scan {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            output_schema,
+            scan_type,
+            file_options,
+        } => {
+            let paths = paths.clone();
+            let file_info = file_info.clone();
+            let hive_parts = hive_parts.clone();
+            let predicate = predicate.clone();
+            let output_schema = output_schema.clone();
+            let scan_type = scan_type.clone();
+            let mut file_options = file_options.clone();
+
+            if is_streamable_scan(
+                &paths,
+                &scan_type,
+                predicate.as_ref(),
+                &file_options,
+                expr_arena,
+            ) {
+                file_options.slice = match file_options.slice {
+                    Some((offset, len)) => Some((offset, _set_n_rows_for_scan(Some(len)).unwrap())),
+                    None => _set_n_rows_for_scan(None).map(|len| (0, len)),
+                };
+                Ok(phys_sm.insert(PhysNode::FileScan {
+                    paths,
+                    file_info,
+                    hive_parts,
+                    predicate,
+                    output_schema,
+                    scan_type,
+                    file_options,
+                }))
+            } else {
+                Ok(phys_sm.insert(PhysNode::InMemoryFileScan {
+                    paths,
+                    file_info,
+                    hive_parts,
+                    predicate,
+                    output_schema,
+                    scan_type,
+                    file_options,
+                }))
+            }
+        },
+
         IR::Sink { input, payload } => {
             if *payload == SinkType::Memory {
                 let schema = ir_node.schema(ir_arena).into_owned();
@@ -154,6 +392,27 @@ pub fn lower_ir(
                 return Ok(phys_sm.insert(PhysNode::InMemorySink { input, schema }));
             }
 
+            #[cfg(any(
+                feature = "parquet",
+                feature = "ipc",
+                feature = "csv",
+                feature = "avro"
+            ))]
+            if let SinkType::File { path, file_type } = payload {
+                if is_streamable_sink(file_type) {
+                    let input_schema = ir_arena.get(*input).schema(ir_arena).into_owned();
+                    let path = path.clone();
+                    let file_type = file_type.clone();
+                    let input = lower_ir(*input, ir_arena, expr_arena, phys_sm)?;
+                    return Ok(phys_sm.insert(PhysNode::FileSink {
+                        input,
+                        input_schema,
+                        path,
+                        file_type,
+                    }));
+                }
+            }
+
             todo!()
         },
 
@@ -184,16 +443,88 @@ pub fn lower_ir(
             sort_options,
         } => {
             let input_schema = ir_arena.get(*input).schema(ir_arena).into_owned();
-            let phys_node = PhysNode::Sort {
-                input_schema,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-                input: lower_ir(*input, ir_arena, expr_arena, phys_sm)?,
+            let by_column = by_column.clone();
+            let slice = *slice;
+            let sort_options = sort_options.clone();
+            let input = lower_ir(*input, ir_arena, expr_arena, phys_sm)?;
+
+            let phys_node = if is_streamable_sort(&by_column, slice, &input_schema, expr_arena) {
+                PhysNode::Sort {
+                    input,
+                    by_column,
+                    slice,
+                    sort_options,
+                }
+            } else {
+                PhysNode::InMemorySort {
+                    input,
+                    input_schema,
+                    by_column,
+                    slice,
+                    sort_options,
+                }
             };
             Ok(phys_sm.insert(phys_node))
         },
 
+        IR::GroupBy {
+            input,
+            keys,
+            aggs,
+            schema: output_schema,
+            apply,
+            maintain_order,
+            options,
+        } => {
+            let input_schema = ir_arena.get(*input).schema(ir_arena).into_owned();
+            let keys = keys.clone();
+            let aggs = aggs.clone();
+            let output_schema = output_schema.clone();
+            let apply = apply.clone();
+            let maintain_order = *maintain_order;
+            let options = options.clone();
+            let input = lower_ir(*input, ir_arena, expr_arena, phys_sm)?;
+
+            if is_streamable_group_by(
+                &keys,
+                &aggs,
+                &input_schema,
+                apply.is_some(),
+                maintain_order,
+                &options,
+                expr_arena,
+            ) {
+                let mut phys_node = phys_sm.insert(PhysNode::GroupBy {
+                    input,
+                    input_schema,
+                    keys,
+                    aggs,
+                    output_schema,
+                });
+
+                if let Some((offset, length)) = options.slice {
+                    phys_node = phys_sm.insert(PhysNode::StreamingSlice {
+                        input: phys_node,
+                        offset: offset as usize,
+                        length,
+                    });
+                }
+
+                Ok(phys_node)
+            } else {
+                Ok(phys_sm.insert(PhysNode::InMemoryGroupBy {
+                    input,
+                    input_schema,
+                    keys,
+                    aggs,
+                    output_schema,
+                    apply,
+                    maintain_order,
+                    options,
+                }))
+            }
+        },
+
         IR::Union { inputs, options } => {
             if options.slice.is_some() {
                 todo!()
@@ -220,6 +551,92 @@ pub fn lower_ir(
             Ok(phys_sm.insert(PhysNode::Zip { inputs }))
         },
 
+        IR::Join {
+            input_left,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options,
+        } => {
+            let output_schema = schema.clone();
+            let input_left = *input_left;
+            let input_right = *input_right;
+            let left_on = left_on.clone();
+            let right_on = right_on.clone();
+            let options = options.clone();
+            let left_input_schema = ir_arena.get(input_left).schema(ir_arena).into_owned();
+            let right_input_schema = ir_arena.get(input_right).schema(ir_arena).into_owned();
+            let phys_left = lower_ir(input_left, ir_arena, expr_arena, phys_sm)?;
+            let phys_right = lower_ir(input_right, ir_arena, expr_arena, phys_sm)?;
+
+            if is_streamable_equi_join(
+                &options.args,
+                &left_on,
+                &right_on,
+                &left_input_schema,
+                &right_input_schema,
+                expr_arena,
+            ) {
+                let mut args = options.args.clone();
+                let slice = args.slice.take();
+                let mut phys_node = phys_sm.insert(PhysNode::EquiJoin {
+                    input_left: phys_left,
+                    input_right: phys_right,
+                    right_input_schema,
+                    left_on,
+                    right_on,
+                    args,
+                });
+
+                if let Some((offset, length)) = slice {
+                    phys_node = phys_sm.insert(PhysNode::StreamingSlice {
+                        input: phys_node,
+                        offset: offset as usize,
+                        length,
+                    });
+                }
+
+                Ok(phys_node)
+            } else {
+                #[cfg(feature = "asof_join")]
+                if is_streamable_asof_join(&options.args, &left_on, &right_on, expr_arena) {
+                    let mut args = options.args.clone();
+                    let slice = args.slice.take();
+                    let mut phys_node = phys_sm.insert(PhysNode::AsOfJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_input_schema,
+                        right_input_schema,
+                        left_on: left_on[0].clone(),
+                        right_on: right_on[0].clone(),
+                        args,
+                    });
+
+                    if let Some((offset, length)) = slice {
+                        phys_node = phys_sm.insert(PhysNode::StreamingSlice {
+                            input: phys_node,
+                            offset: offset as usize,
+                            length,
+                        });
+                    }
+
+                    return Ok(phys_node);
+                }
+
+                Ok(phys_sm.insert(PhysNode::InMemoryJoin {
+                    input_left: phys_left,
+                    input_right: phys_right,
+                    left_input_schema,
+                    right_input_schema,
+                    output_schema,
+                    left_on,
+                    right_on,
+                    options,
+                }))
+            }
+        },
+
         _ => todo!(),
     }
 }
@@ -1,10 +1,21 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::prelude::SortMultipleOptions;
-use polars_core::schema::Schema;
-use polars_plan::plans::DataFrameUdf;
+use polars_core::schema::{Schema, SchemaRef};
+use polars_ops::frame::JoinArgs;
+use polars_plan::plans::hive::HivePartitions;
+use polars_plan::plans::{DataFrameUdf, FileInfo, FileScan};
 use polars_plan::prelude::expr_ir::ExprIR;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "avro"
+))]
+use polars_plan::prelude::FileType;
+use polars_plan::prelude::{FileScanOptions, GroupbyOptions, JoinOptions};
 
 mod lower_ir;
 mod to_graph;
@@ -27,6 +38,29 @@ pub enum PhysNode {
         df: Arc<DataFrame>,
     },
 
+    /// A scan of local files, read in batches by the readers in
+    /// [`crate::nodes::io_sources`].
+    FileScan {
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        hive_parts: Option<Arc<[HivePartitions]>>,
+        predicate: Option<ExprIR>,
+        output_schema: Option<SchemaRef>,
+        scan_type: FileScan,
+        file_options: FileScanOptions,
+    },
+
+    /// Scans that can't (yet) be streamed, executed by the in-memory engine.
+    InMemoryFileScan {
+        paths: Arc<[PathBuf]>,
+        file_info: FileInfo,
+        hive_parts: Option<Arc<[HivePartitions]>>,
+        predicate: Option<ExprIR>,
+        output_schema: Option<SchemaRef>,
+        scan_type: FileScan,
+        file_options: FileScanOptions,
+    },
+
     Select {
         input: PhysNodeKey,
         selectors: Vec<ExprIR>,
@@ -41,6 +75,27 @@ pub enum PhysNode {
         output_schema: Arc<Schema>,
     },
 
+    /// A hash group-by where all aggregations are grouped reductions.
+    GroupBy {
+        input: PhysNodeKey,
+        input_schema: Arc<Schema>,
+        keys: Vec<ExprIR>,
+        aggs: Vec<ExprIR>,
+        output_schema: Arc<Schema>,
+    },
+
+    /// Group-bys that can't (yet) be streamed, executed by the in-memory engine.
+    InMemoryGroupBy {
+        input: PhysNodeKey,
+        input_schema: Arc<Schema>,
+        keys: Vec<ExprIR>,
+        aggs: Vec<ExprIR>,
+        output_schema: Arc<Schema>,
+        apply: Option<Arc<dyn DataFrameUdf>>,
+        maintain_order: bool,
+        options: Arc<GroupbyOptions>,
+    },
+
     StreamingSlice {
         input: PhysNodeKey,
         offset: usize,
@@ -63,6 +118,21 @@ pub enum PhysNode {
         schema: Arc<Schema>,
     },
 
+    /// A sink to a local file, written in order by the writers in
+    /// [`crate::nodes::io_sinks`].
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv",
+        feature = "avro"
+    ))]
+    FileSink {
+        input: PhysNodeKey,
+        input_schema: Arc<Schema>,
+        path: Arc<PathBuf>,
+        file_type: FileType,
+    },
+
     InMemoryMap {
         input: PhysNodeKey,
         input_schema: Arc<Schema>,
@@ -74,9 +144,19 @@ pub enum PhysNode {
         map: Arc<dyn DataFrameUdf>,
     },
 
+    /// A sort which sorts runs of its input, spilling them to disk when they
+    /// exceed the memory budget, and merges them.
     Sort {
         input: PhysNodeKey,
-        input_schema: Arc<Schema>, // TODO: remove when not using fallback impl.
+        by_column: Vec<ExprIR>,
+        slice: Option<(i64, usize)>,
+        sort_options: SortMultipleOptions,
+    },
+
+    /// Sorts that can't (yet) be streamed, executed by the in-memory engine.
+    InMemorySort {
+        input: PhysNodeKey,
+        input_schema: Arc<Schema>,
         by_column: Vec<ExprIR>,
         slice: Option<(i64, usize)>,
         sort_options: SortMultipleOptions,
@@ -89,4 +169,40 @@ pub enum PhysNode {
     Zip {
         inputs: Vec<PhysNodeKey>,
     },
+
+    /// A hash join where the right input is the build side and the left input
+    /// is streamed through it.
+    EquiJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        right_input_schema: Arc<Schema>,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+    },
+
+    /// An asof join of two inputs sorted on their keys, which are consumed in
+    /// lockstep.
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        left_on: ExprIR,
+        right_on: ExprIR,
+        args: JoinArgs,
+    },
+
+    /// Joins that can't (yet) be streamed, executed by the in-memory engine.
+    InMemoryJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        output_schema: Arc<Schema>,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        options: Arc<JoinOptions>,
+    },
 }
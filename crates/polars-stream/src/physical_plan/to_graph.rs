@@ -3,7 +3,8 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use polars_error::PolarsResult;
 use polars_expr::planner::{create_physical_expr, get_expr_depth_limit, ExpressionConversionState};
-use polars_expr::reduce::into_reduction;
+use polars_expr::prelude::phys_expr_to_io_expr;
+use polars_expr::reduce::{into_grouped_reduction, into_reduction};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::plans::expr_ir::ExprIR;
@@ -88,6 +89,73 @@ fn to_graph_rec<'a>(
             [],
         ),
 
+        FileScan {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            output_schema,
+            scan_type,
+            file_options,
+        } => {
+            let predicate = predicate
+                .as_ref()
+                .map(|pred| {
+                    create_physical_expr(
+                        pred,
+                        Context::Default,
+                        ctx.expr_arena,
+                        output_schema.as_ref(),
+                        &mut ctx.expr_conversion_state,
+                    )
+                })
+                .transpose()?
+                .map(phys_expr_to_io_expr);
+            let reader = nodes::io_sources::file_scan_reader(
+                paths.clone(),
+                file_info.clone(),
+                hive_parts.clone(),
+                predicate,
+                scan_type.clone(),
+                file_options.clone(),
+            );
+            ctx.graph.add_node(
+                nodes::io_sources::ScanSourceNode::new("file_scan", reader),
+                [],
+            )
+        },
+
+        InMemoryFileScan {
+            paths,
+            file_info,
+            hive_parts,
+            predicate,
+            output_schema,
+            scan_type,
+            file_options,
+        } => {
+            let mut lp_arena = Arena::default();
+            let scan_node = lp_arena.add(IR::Scan {
+                paths: paths.clone(),
+                file_info: file_info.clone(),
+                hive_parts: hive_parts.clone(),
+                predicate: predicate.clone(),
+                output_schema: output_schema.clone(),
+                scan_type: scan_type.clone(),
+                file_options: file_options.clone(),
+            });
+            let mut executor = create_physical_plan(scan_node, &mut lp_arena, ctx.expr_arena)?;
+
+            let reader = nodes::io_sources::OneShotReader::new(Box::new(move || {
+                let mut state = ExecutionState::new();
+                executor.execute(&mut state)
+            }));
+            ctx.graph.add_node(
+                nodes::io_sources::ScanSourceNode::new("in_memory_file_scan", Box::new(reader)),
+                [],
+            )
+        },
+
         StreamingSlice {
             input,
             offset,
@@ -157,6 +225,95 @@ fn to_graph_rec<'a>(
                 [input_key],
             )
         },
+        GroupBy {
+            input,
+            input_schema,
+            keys,
+            aggs,
+            output_schema,
+        } => {
+            let input_key = to_graph_rec(*input, ctx)?;
+
+            let key_selectors = keys
+                .iter()
+                .map(|e| create_stream_expr(e, ctx))
+                .collect::<PolarsResult<_>>()?;
+            let key_schema = Arc::new(
+                output_schema
+                    .iter_fields()
+                    .take(keys.len())
+                    .collect::<polars_core::schema::Schema>(),
+            );
+
+            let mut reductions = Vec::with_capacity(aggs.len());
+            let mut reduction_selectors = Vec::with_capacity(aggs.len());
+            for e in aggs {
+                let (red, input_nodes) =
+                    into_grouped_reduction(e.node(), ctx.expr_arena, input_schema.as_ref())?
+                        .expect("invariant");
+                reductions.push(red);
+
+                let selectors = input_nodes
+                    .into_iter()
+                    .map(|n| create_stream_expr(&ExprIR::from_node(n, ctx.expr_arena), ctx))
+                    .collect::<PolarsResult<_>>()?;
+                reduction_selectors.push(selectors);
+            }
+
+            ctx.graph.add_node(
+                nodes::group_by::GroupByNode::new(
+                    key_selectors,
+                    key_schema,
+                    reduction_selectors,
+                    reductions,
+                    output_schema.clone(),
+                )?,
+                [input_key],
+            )
+        },
+
+        InMemoryGroupBy {
+            input,
+            input_schema,
+            keys,
+            aggs,
+            output_schema,
+            apply,
+            maintain_order,
+            options,
+        } => {
+            let lmdf = Arc::new(LateMaterializedDataFrame::default());
+            let mut lp_arena = Arena::default();
+            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+            let group_by_node = lp_arena.add(IR::GroupBy {
+                input: df_node,
+                keys: keys.clone(),
+                aggs: aggs.clone(),
+                schema: output_schema.clone(),
+                apply: apply.clone(),
+                maintain_order: *maintain_order,
+                options: options.clone(),
+            });
+            let executor = Mutex::new(create_physical_plan(
+                group_by_node,
+                &mut lp_arena,
+                ctx.expr_arena,
+            )?);
+
+            let input_key = to_graph_rec(*input, ctx)?;
+            ctx.graph.add_node(
+                nodes::in_memory_map::InMemoryMapNode::new(
+                    input_schema.clone(),
+                    Arc::new(move |df| {
+                        lmdf.set_materialized_dataframe(df);
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                ),
+                [input_key],
+            )
+        },
+
         SimpleProjection {
             input,
             columns,
@@ -180,6 +337,26 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(any(
+            feature = "parquet",
+            feature = "ipc",
+            feature = "csv",
+            feature = "avro"
+        ))]
+        FileSink {
+            input,
+            input_schema,
+            path,
+            file_type,
+        } => {
+            let input_key = to_graph_rec(*input, ctx)?;
+            let writer = nodes::io_sinks::file_sink_writer(path, file_type, input_schema)?;
+            ctx.graph.add_node(
+                nodes::io_sinks::FileSinkNode::new("file_sink", writer),
+                [input_key],
+            )
+        },
+
         InMemoryMap {
             input,
             input_schema,
@@ -199,6 +376,23 @@ fn to_graph_rec<'a>(
         },
 
         Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } => {
+            let input_key = to_graph_rec(*input, ctx)?;
+            let key_selectors = by_column
+                .iter()
+                .map(|e| create_stream_expr(e, ctx))
+                .collect::<PolarsResult<_>>()?;
+            ctx.graph.add_node(
+                nodes::sort::SortNode::new(key_selectors, *slice, sort_options.clone())?,
+                [input_key],
+            )
+        },
+
+        InMemorySort {
             input,
             input_schema,
             by_column,
@@ -250,6 +444,122 @@ fn to_graph_rec<'a>(
                 .collect::<Result<Vec<_>, _>>()?;
             ctx.graph.add_node(nodes::zip::ZipNode::new(), input_keys)
         },
+
+        EquiJoin {
+            input_left,
+            input_right,
+            right_input_schema,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx))
+                .collect::<PolarsResult<_>>()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx))
+                .collect::<PolarsResult<_>>()?;
+            let right_key_names = right_on
+                .iter()
+                .map(|e| e.output_name().to_string())
+                .collect();
+
+            let left_input_key = to_graph_rec(*input_left, ctx)?;
+            let right_input_key = to_graph_rec(*input_right, ctx)?;
+            ctx.graph.add_node(
+                nodes::joins::equi_join::EquiJoinNode::new(
+                    right_input_schema.clone(),
+                    left_key_selectors,
+                    right_key_selectors,
+                    right_key_names,
+                    args.clone(),
+                )?,
+                [left_input_key, right_input_key],
+            )
+        },
+
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_input_schema,
+            right_input_schema,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let key_dtype = ctx.expr_arena.get(left_on.node()).to_dtype(
+                left_input_schema,
+                Context::Default,
+                ctx.expr_arena,
+            )?;
+            let mut args = args.clone();
+            nodes::joins::asof_join::resolve_tolerance(&mut args, &key_dtype)?;
+
+            let left_key_selector = create_stream_expr(left_on, ctx)?;
+            let right_key_selector = create_stream_expr(right_on, ctx)?;
+            let left_input_key = to_graph_rec(*input_left, ctx)?;
+            let right_input_key = to_graph_rec(*input_right, ctx)?;
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    left_key_selector,
+                    right_key_selector,
+                    right_input_schema.clone(),
+                    args,
+                ),
+                [left_input_key, right_input_key],
+            )
+        },
+
+        InMemoryJoin {
+            input_left,
+            input_right,
+            left_input_schema,
+            right_input_schema,
+            output_schema,
+            left_on,
+            right_on,
+            options,
+        } => {
+            let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
+            let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
+
+            let mut lp_arena = Arena::default();
+            let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema.clone()));
+            let right_node =
+                lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema.clone()));
+            let join_node = lp_arena.add(IR::Join {
+                input_left: left_node,
+                input_right: right_node,
+                schema: output_schema.clone(),
+                left_on: left_on.clone(),
+                right_on: right_on.clone(),
+                options: options.clone(),
+            });
+            let executor = Mutex::new(create_physical_plan(
+                join_node,
+                &mut lp_arena,
+                ctx.expr_arena,
+            )?);
+
+            let left_input_key = to_graph_rec(*input_left, ctx)?;
+            let right_input_key = to_graph_rec(*input_right, ctx)?;
+            ctx.graph.add_node(
+                nodes::joins::in_memory::InMemoryJoinNode::new(
+                    left_input_schema.clone(),
+                    right_input_schema.clone(),
+                    Box::new(move |left, right| {
+                        left_lmdf.set_materialized_dataframe(left);
+                        right_lmdf.set_materialized_dataframe(right);
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                ),
+                [left_input_key, right_input_key],
+            )
+        },
     };
 
     ctx.phys_to_graph.insert(phys_node_key, graph_key);
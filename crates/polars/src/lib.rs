@@ -204,6 +204,7 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write encrypted Apache Parquet files
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
@@ -224,6 +225,8 @@
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
+//!     - `iejoin` - Inequality joins, to join on range predicates without a cross join.
+//!     - `interval_join` - Join rows of which the intervals overlap.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
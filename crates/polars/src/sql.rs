@@ -1,2 +1,3 @@
+pub use polars_sql::catalog::*;
 pub use polars_sql::function_registry::*;
 pub use polars_sql::{keywords, sql_expr, SQLContext};
@@ -7,8 +7,8 @@ use arrow::io::avro::avro_schema::write::{compress, write_block, write_metadata}
 use arrow::io::avro::write;
 use arrow::record_batch::RecordBatchT;
 use avro_schema::schema::{Field as AvroField, Record, Schema as AvroSchema};
-use polars::io::avro::{AvroReader, AvroWriter};
-use polars::io::{SerReader, SerWriter};
+use polars::io::avro::{AvroCompression, AvroReader, AvroWriter};
+use polars::io::{RowIndex, SerReader, SerWriter};
 use polars::prelude::df;
 use polars_error::PolarsResult;
 
@@ -382,7 +382,11 @@ fn test_write_and_read_with_compression() -> PolarsResult<()> {
         "string" => &["a", "b"]
     )?;
 
-    let compressions = vec![None, Some(Compression::Deflate), Some(Compression::Snappy)];
+    let compressions = vec![
+        None,
+        Some(AvroCompression::Deflate),
+        Some(AvroCompression::Snappy),
+    ];
 
     for compression in compressions.into_iter() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -452,3 +456,81 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    let mut df = df!(
+        "i64" => &[1, 2],
+        "string" => &["a", "b"]
+    )?;
+    df.vstack_mut(&df.clone())?;
+    df.vstack_mut(&df.clone())?;
+    assert_eq!(df.n_chunks(), 4);
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf).finish(&mut df)?;
+
+    // Every chunk is written as a block.
+    buf.set_position(0);
+    assert_eq!(polars::io::avro::count_rows(&mut buf)?, 8);
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_blocks() -> PolarsResult<()> {
+    let df = df!(
+        "i64" => (0..100).collect::<Vec<i64>>(),
+        "f64" => (0..100).map(|i| i as f64).collect::<Vec<_>>(),
+        "string" => (0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+    )?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut writer = AvroWriter::new(&mut buf)
+        .with_compression(Some(AvroCompression::Snappy))
+        .batched(&df.schema())?;
+    for offset in (0..100).step_by(10) {
+        writer.write_batch(&df.slice(offset, 10))?;
+    }
+    writer.finish()?;
+
+    buf.set_position(0);
+    assert_eq!(polars::io::avro::count_rows(&mut buf)?, 100);
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf.clone()).finish()?;
+    assert!(df.equals(&read_df));
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf.clone())
+        .with_n_rows(Some(25))
+        .with_projection(Some(vec![2, 0]))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .finish()?;
+    let expected = df
+        .select(["string", "i64"])?
+        .slice(0, 25)
+        .with_row_index("index", Some(10))?;
+    assert!(expected.equals(&read_df));
+
+    // Only the number of rows is read.
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf)
+        .with_projection(Some(vec![]))
+        .with_n_rows(Some(42))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 0,
+        }))
+        .finish()?;
+    assert_eq!(read_df.shape(), (42, 1));
+
+    Ok(())
+}
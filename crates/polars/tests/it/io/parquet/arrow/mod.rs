@@ -1260,6 +1260,7 @@ fn integration_write(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_page_size: None,
+        dictionary: Default::default(),
     };
 
     let encodings = schema
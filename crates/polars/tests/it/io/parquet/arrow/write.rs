@@ -49,6 +49,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_page_size: None,
+        dictionary: Default::default(),
     };
 
     let iter = vec![RecordBatchT::try_new(vec![array.clone()])];
@@ -132,6 +133,39 @@ fn int64_required_delta() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn int64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "int64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn float64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn float64_optional_v1_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
 #[cfg(feature = "parquet")]
 #[test]
 fn int64_optional_v2_compressed() -> PolarsResult<()> {
@@ -414,6 +448,17 @@ fn utf8_required_v2_delta() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn utf8_optional_v2_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
 #[cfg(feature = "parquet")]
 #[test]
 fn i64_optional_v2_dict_compressed() -> PolarsResult<()> {
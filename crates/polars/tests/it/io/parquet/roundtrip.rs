@@ -24,6 +24,7 @@ fn round_trip(
         compression,
         version,
         data_page_size: None,
+        dictionary: Default::default(),
     };
 
     let iter = vec![RecordBatchT::try_new(vec![array.clone()])];
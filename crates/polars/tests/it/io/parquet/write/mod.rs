@@ -293,3 +293,305 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+#[cfg(feature = "async")]
+fn test_read_parquet_batched_include_file_path() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("test_read_parquet_batched_include_file_path.parquet");
+    let mut df = df!("a" => [1, 2, 3, 4, 5])?;
+
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+
+    // The batched reader only reads from files.
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?)
+        .with_include_file_path(Some((Arc::from("path"), Arc::from("a.parquet"))))
+        .batched(2)?;
+    let mut batches = vec![];
+    while let Some(dfs) = polars::io::pl_async::get_runtime().block_on(reader.next_batches(1))? {
+        batches.extend(dfs);
+    }
+    let read = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    std::fs::remove_file(&path)?;
+
+    let expected = df!(
+        "a" => [1, 2, 3, 4, 5],
+        "path" => ["a.parquet"; 5],
+    )?;
+    assert!(read.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_column_options() -> PolarsResult<()> {
+    use polars::io::parquet::write::{
+        ParquetColumnWriteOptions, ParquetCompression, ParquetEncoding,
+    };
+    use polars_parquet::parquet::compression::Compression;
+    use polars_parquet::parquet::encoding::Encoding;
+
+    let n = 1000i64;
+    let mut df = df![
+        "ts" => (0..n).map(|i| 1_700_000_000_000 + i * 1000).collect::<Vec<_>>(),
+        "value" => (0..n).map(|i| (i as f64).sin()).collect::<Vec<_>>(),
+        "name" => (0..n).map(|i| format!("sensor-{i:04}")).collect::<Vec<_>>(),
+    ]?;
+    df.try_apply("ts", |s| {
+        s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+    })?;
+
+    let column_options = vec![
+        (
+            "ts".to_string(),
+            ParquetColumnWriteOptions {
+                encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+                dictionary: Some(false),
+                ..Default::default()
+            },
+        ),
+        (
+            "value".to_string(),
+            ParquetColumnWriteOptions {
+                encoding: Some(ParquetEncoding::ByteStreamSplit),
+                compression: Some(ParquetCompression::Uncompressed),
+                ..Default::default()
+            },
+        ),
+        (
+            "name".to_string(),
+            ParquetColumnWriteOptions {
+                encoding: Some(ParquetEncoding::DeltaByteArray),
+                dictionary_max_cardinality: Some(10),
+                ..Default::default()
+            },
+        ),
+    ];
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_column_options(column_options)
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].columns();
+    let encodings = |i: usize| {
+        columns[i]
+            .column_encoding()
+            .iter()
+            .map(|e| Encoding::try_from(*e).unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert!(encodings(0).contains(&Encoding::DeltaBinaryPacked));
+    assert!(!encodings(0).contains(&Encoding::RleDictionary));
+    assert!(encodings(1).contains(&Encoding::ByteStreamSplit));
+    assert_eq!(columns[1].compression(), Compression::Uncompressed);
+    // The names have more distinct values than the dictionary allows.
+    assert!(encodings(2).contains(&Encoding::DeltaByteArray));
+    assert!(columns[2].dictionary_page_offset().is_none());
+
+    buf.set_position(0);
+    let read = ParquetReader::new(buf).finish()?;
+    assert!(read.equals(&df));
+
+    // Encodings are validated against the type of the column.
+    let invalid = vec![(
+        "name".to_string(),
+        ParquetColumnWriteOptions {
+            encoding: Some(ParquetEncoding::ByteStreamSplit),
+            ..Default::default()
+        },
+    )];
+    assert!(ParquetWriter::new(Cursor::new(vec![]))
+        .with_column_options(invalid)
+        .finish(&mut df)
+        .is_err());
+    let unknown = vec![("x".to_string(), ParquetColumnWriteOptions::default())];
+    assert!(ParquetWriter::new(Cursor::new(vec![]))
+        .with_column_options(unknown)
+        .finish(&mut df)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_write_parquet_encryption() -> PolarsResult<()> {
+    use polars::io::parquet::read::FileDecryptionProperties;
+    use polars::io::parquet::write::{ParquetColumnEncryptionKey, ParquetEncryptionOptions};
+    use polars_parquet::parquet::bloom_filter;
+    use polars_parquet::parquet::read::{read_columns_indexes, read_pages_locations};
+    use polars_utils::aliases::PlHashMap;
+
+    let n = 1000i64;
+    let mut df = df![
+        "id" => (0..n).collect::<Vec<_>>(),
+        "name" => (0..n).map(|i| format!("person-{}", i % 50)).collect::<Vec<_>>(),
+        "score" => (0..n).map(|i| (i % 7 == 0).then_some(i as f64)).collect::<Vec<_>>(),
+    ]?;
+    let footer_key = vec![1u8; 16];
+    let name_key = vec![2u8; 32];
+
+    let write = |df: &mut DataFrame, encryption: ParquetEncryptionOptions| {
+        let mut buf = Cursor::new(vec![]);
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(300))
+            .with_data_page_size(Some(1024))
+            .with_bloom_filter_columns(vec!["name".to_string()])
+            .with_encryption(Some(encryption))
+            .finish(df)?;
+        buf.set_position(0);
+        PolarsResult::Ok(buf)
+    };
+    let decryption = |keys: &[(&[u8], &[u8])]| {
+        let keys = keys
+            .iter()
+            .map(|(metadata, key)| (metadata.to_vec(), key.to_vec()))
+            .collect::<PlHashMap<_, _>>();
+        Some(FileDecryptionProperties::new(Arc::new(keys)))
+    };
+
+    // All columns are encrypted with the footer key.
+    let buf = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key: footer_key.clone(),
+            footer_key_metadata: Some(b"footer".to_vec()),
+            column_keys: vec![],
+        },
+    )?;
+    assert_eq!(&buf.get_ref()[..4], b"PARE");
+    assert!(ParquetReader::new(buf.clone()).finish().is_err());
+    let read = ParquetReader::new(buf.clone())
+        .with_decryption(decryption(&[(b"footer", &footer_key)]))
+        .finish()?;
+    assert!(read.equals_missing(&df));
+    // The key is verified when decrypting the footer.
+    assert!(ParquetReader::new(buf)
+        .with_decryption(decryption(&[(b"footer", &[3u8; 16])]))
+        .finish()
+        .is_err());
+
+    // Only the column with a key of its own is encrypted.
+    let buf = write(
+        &mut df,
+        ParquetEncryptionOptions {
+            footer_key: footer_key.clone(),
+            footer_key_metadata: Some(b"footer".to_vec()),
+            column_keys: vec![ParquetColumnEncryptionKey {
+                column: "name".to_string(),
+                key: name_key.clone(),
+                key_metadata: Some(b"name".to_vec()),
+            }],
+        },
+    )?;
+    let read = ParquetReader::new(buf.clone())
+        .with_decryption(decryption(&[
+            (b"footer", &footer_key),
+            (b"name", &name_key),
+        ]))
+        .finish()?;
+    assert!(read.equals_missing(&df));
+    let metadata = ParquetReader::new(buf.clone())
+        .with_decryption(decryption(&[
+            (b"footer", &footer_key),
+            (b"name", &name_key),
+        ]))
+        .get_metadata()?
+        .clone();
+    let columns = metadata.row_groups[0].columns();
+    assert!(columns[0].column_chunk().crypto_metadata.is_none());
+    assert!(columns[1]
+        .column_chunk()
+        .encrypted_column_metadata
+        .is_some());
+    // The page indexes and the bloom filter of the encrypted column are encrypted as well.
+    let mut reader = buf.clone();
+    let locations = read_pages_locations(&mut reader, columns)?;
+    assert!(locations.iter().all(|pages| !pages.is_empty()));
+    assert_eq!(read_columns_indexes(&mut reader, columns)?.len(), 3);
+    let mut bitset = vec![];
+    bloom_filter::read(&columns[1], &mut reader, &mut bitset)?;
+    assert!(bloom_filter::is_in_set(
+        &bitset,
+        bloom_filter::hash_byte("person-3")
+    ));
+    assert!(ParquetReader::new(buf)
+        .with_decryption(decryption(&[(b"footer", &footer_key)]))
+        .finish()
+        .is_err());
+
+    // Keys of columns that do not exist are rejected.
+    let encryption = ParquetEncryptionOptions {
+        footer_key,
+        footer_key_metadata: None,
+        column_keys: vec![ParquetColumnEncryptionKey {
+            column: "x".to_string(),
+            key: name_key,
+            key_metadata: None,
+        }],
+    };
+    assert!(write(&mut df, encryption).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(all(feature = "parquet_encryption", feature = "async"))]
+async fn test_read_encrypted_parquet_pages_async() -> PolarsResult<()> {
+    use futures::TryStreamExt;
+    use polars::io::parquet::read::FileDecryptionProperties;
+    use polars::io::parquet::write::ParquetEncryptionOptions;
+    use polars_parquet::parquet::page::CompressedPage;
+    use polars_parquet::parquet::read::{
+        decompress, get_page_stream, read_metadata_with_decryption,
+    };
+    use polars_utils::aliases::PlHashMap;
+
+    let mut df = df![
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("person-{}", i % 50)).collect::<Vec<_>>(),
+    ]?;
+    let key = vec![1u8; 16];
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_data_page_size(Some(1024))
+        .with_encryption(Some(ParquetEncryptionOptions {
+            footer_key: key.clone(),
+            footer_key_metadata: None,
+            column_keys: vec![],
+        }))
+        .finish(&mut df)?;
+
+    let keys = PlHashMap::from_iter([(vec![], key)]);
+    let decryption = FileDecryptionProperties::new(Arc::new(keys));
+    buf.set_position(0);
+    let metadata = read_metadata_with_decryption(&mut buf, Some(&decryption))?;
+
+    let mut reader = futures::io::Cursor::new(buf.into_inner());
+    for column in metadata.row_groups[0].columns() {
+        let pages = get_page_stream(
+            column,
+            &mut reader,
+            vec![],
+            Arc::new(|_, _| true),
+            usize::MAX,
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+        let num_values = pages
+            .iter()
+            .map(|page| match page {
+                CompressedPage::Data(page) => page.num_values(),
+                CompressedPage::Dict(_) => 0,
+            })
+            .sum::<usize>();
+        assert!(pages.len() > 1);
+        assert_eq!(num_values, 1000);
+        // The pages are decrypted, so they can be decompressed.
+        for page in pages {
+            decompress(page, &mut vec![])?;
+        }
+    }
+    Ok(())
+}
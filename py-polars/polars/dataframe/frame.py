@@ -3455,6 +3455,8 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter_columns: str | Sequence[str] | None = None,
+        column_options: dict[str, dict[str, Any]] | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -3497,10 +3499,34 @@ class DataFrame:
               - "max": column maximum value (default: `True`)
               - "distinct_count": number of unique column values (default: `False`)
               - "null_count": number of null values in column (default: `True`)
+
+            If both the minimum and maximum are written, they are also written per
+            data page as a page index, which allows readers to skip pages.
         row_group_size
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filter_columns
+            Column(s) for which a bloom filter is written in every row group. Readers
+            use bloom filters to skip row groups when filtering a column for equality
+            or membership, which is most effective for columns with many distinct
+            values. Cannot be combined with `use_pyarrow`.
+        column_options
+            Write options of individual columns, as a mapping from column name to a
+            dictionary with the following (optional) keys. Cannot be combined with
+            `use_pyarrow`.
+
+            - "encoding": the encoding of the values that are not dictionary
+              encoded, one of {'plain', 'delta_binary_packed',
+              'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split'}.
+              "byte_stream_split" makes floats compress better and
+              "delta_binary_packed" suits sorted integers and timestamps.
+            - "dictionary": whether to dictionary encode the column (default: `True`
+              for integer, temporal, string and binary columns).
+            - "dictionary_max_cardinality": the maximum number of distinct values in a
+              row group for which dictionary encoding is used. By default, dictionary
+              encoding is given up when more than 75% of the values are distinct.
+            - "compression" and "compression_level": the compression of the column.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -3563,6 +3589,12 @@ class DataFrame:
             if statistics == "full" or isinstance(statistics, dict):
                 msg = "write_parquet with `use_pyarrow=True` allows only boolean values for `statistics`"
                 raise ValueError(msg)
+            if bloom_filter_columns is not None:
+                msg = "write_parquet with `use_pyarrow=True` does not support `bloom_filter_columns`"
+                raise ValueError(msg)
+            if column_options is not None:
+                msg = "write_parquet with `use_pyarrow=True` does not support `column_options`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -3626,6 +3658,8 @@ class DataFrame:
 
             if isinstance(partition_by, str):
                 partition_by = [partition_by]
+            if isinstance(bloom_filter_columns, str):
+                bloom_filter_columns = [bloom_filter_columns]
 
             self._df.write_parquet(
                 file,
@@ -3636,6 +3670,10 @@ class DataFrame:
                 data_page_size,
                 partition_by=partition_by,
                 partition_chunk_size_bytes=partition_chunk_size_bytes,
+                bloom_filter_columns=bloom_filter_columns,
+                column_options=(
+                    list(column_options.items()) if column_options else None
+                ),
             )
 
     def write_database(
@@ -6895,6 +6933,71 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: DataFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Join on the rows for which all predicates hold.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Comparisons between a column of this DataFrame and a column of `other`
+        are executed as an equi join or an inequality join instead of a cross
+        join followed by a filter. The row order of the result is not guaranteed.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        *predicates
+            Predicates that rows of both tables must satisfy to be joined, combined
+            with `&`. Columns of `other` that also exist in this DataFrame are referred
+            to with the `suffix` appended.
+        suffix
+            Suffix to append to columns of `other` with a duplicate name.
+
+        See Also
+        --------
+        join
+
+        Examples
+        --------
+        >>> sessions = pl.DataFrame(
+        ...     {"id": [1, 2], "start": [0, 10], "end": [10, 20]}
+        ... )
+        >>> events = pl.DataFrame({"ts": [1, 5, 12, 25]})
+        >>> sessions.join_where(
+        ...     events,
+        ...     pl.col("start") <= pl.col("ts"),
+        ...     pl.col("ts") < pl.col("end"),
+        ... )
+        shape: (3, 4)
+        ┌─────┬───────┬─────┬─────┐
+        │ id  ┆ start ┆ end ┆ ts  │
+        │ --- ┆ ---   ┆ --- ┆ --- │
+        │ i64 ┆ i64   ┆ i64 ┆ i64 │
+        ╞═════╪═══════╪═════╪═════╡
+        │ 1   ┆ 0     ┆ 10  ┆ 1   │
+        │ 1   ┆ 0     ┆ 10  ┆ 5   │
+        │ 2   ┆ 10    ┆ 20  ┆ 12  │
+        └─────┴───────┴─────┴─────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return (
+            self.lazy()
+            .join_where(other.lazy(), *predicates, suffix=suffix)
+            .collect(_eager=True)
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -2240,6 +2240,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter_columns: str | Sequence[str] | None = None,
+        column_options: dict[str, dict[str, Any]] | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
@@ -2289,6 +2291,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               - "max": column maximum value (default: `True`)
               - "distinct_count": number of unique column values (default: `False`)
               - "null_count": number of null values in column (default: `True`)
+
+            If both the minimum and maximum are written, they are also written per
+            data page as a page index, which allows readers to skip pages.
         row_group_size
             Size of the row groups in number of rows.
             If None (default), the chunks of the `DataFrame` are
@@ -2297,6 +2302,26 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        bloom_filter_columns
+            Column(s) for which a bloom filter is written in every row group. Readers
+            use bloom filters to skip row groups when filtering a column for equality
+            or membership, which is most effective for columns with many distinct
+            values.
+        column_options
+            Write options of individual columns, as a mapping from column name to a
+            dictionary with the following (optional) keys.
+
+            - "encoding": the encoding of the values that are not dictionary
+              encoded, one of {'plain', 'delta_binary_packed',
+              'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split'}.
+              "byte_stream_split" makes floats compress better and
+              "delta_binary_packed" suits sorted integers and timestamps.
+            - "dictionary": whether to dictionary encode the column (default: `True`
+              for integer, temporal, string and binary columns).
+            - "dictionary_max_cardinality": the maximum number of distinct values in a
+              row group for which dictionary encoding is used. By default, dictionary
+              encoding is given up when more than 75% of the values are distinct.
+            - "compression" and "compression_level": the compression of the column.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
@@ -2348,6 +2373,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 "null_count": True,
             }
 
+        if isinstance(bloom_filter_columns, str):
+            bloom_filter_columns = [bloom_filter_columns]
+
         return lf.sink_parquet(
             path=normalize_filepath(path),
             compression=compression,
@@ -2356,6 +2384,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_page_size=data_page_size,
             maintain_order=maintain_order,
+            bloom_filter_columns=bloom_filter_columns,
+            column_options=(
+                list(column_options.items()) if column_options else None
+            ),
         )
 
     @unstable()
@@ -4393,6 +4425,68 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: LazyFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """
+        Join on the rows for which all predicates hold.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Comparisons between a column of this LazyFrame and a column of `other`
+        are executed as an equi join or an inequality join instead of a cross
+        join followed by a filter. The row order of the result is not guaranteed.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        *predicates
+            Predicates that rows of both tables must satisfy to be joined, combined
+            with `&`. Columns of `other` that also exist in this LazyFrame are referred
+            to with the `suffix` appended.
+        suffix
+            Suffix to append to columns of `other` with a duplicate name.
+
+        See Also
+        --------
+        join
+
+        Examples
+        --------
+        >>> sessions = pl.LazyFrame(
+        ...     {"id": [1, 2], "start": [0, 10], "end": [10, 20]}
+        ... )
+        >>> events = pl.LazyFrame({"ts": [1, 5, 12, 25]})
+        >>> sessions.join_where(
+        ...     events,
+        ...     pl.col("start") <= pl.col("ts"),
+        ...     pl.col("ts") < pl.col("end"),
+        ... ).collect()
+        shape: (3, 4)
+        ┌─────┬───────┬─────┬─────┐
+        │ id  ┆ start ┆ end ┆ ts  │
+        │ --- ┆ ---   ┆ --- ┆ --- │
+        │ i64 ┆ i64   ┆ i64 ┆ i64 │
+        ╞═════╪═══════╪═════╪═════╡
+        │ 1   ┆ 0     ┆ 10  ┆ 1   │
+        │ 1   ┆ 0     ┆ 10  ┆ 5   │
+        │ 2   ┆ 10    ┆ 20  ┆ 12  │
+        └─────┴───────┴─────┴─────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        pyexprs = parse_into_list_of_expressions(*predicates)
+        return self._from_pyldf(self._ldf.join_where(other._ldf, pyexprs, suffix))
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -489,6 +489,56 @@ impl<'s> FromPyObject<'s> for Wrap<StatisticsOptions> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl<'s> FromPyObject<'s> for Wrap<ParquetColumnWriteOptions> {
+    fn extract_bound(ob: &Bound<'s, PyAny>) -> PyResult<Self> {
+        let mut options = ParquetColumnWriteOptions::default();
+        let mut compression = None;
+        let mut compression_level = None;
+
+        let dict = ob.downcast::<PyDict>()?;
+        for (key, val) in dict {
+            let key = key.extract::<PyBackedStr>()?;
+            match key.as_ref() {
+                "encoding" => {
+                    let encoding = val.extract::<PyBackedStr>()?;
+                    options.encoding = Some(match encoding.as_ref() {
+                        "plain" => ParquetEncoding::Plain,
+                        "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+                        "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+                        "delta_byte_array" => ParquetEncoding::DeltaByteArray,
+                        "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+                        v => {
+                            return Err(PyValueError::new_err(format!(
+                                "parquet `encoding` must be one of {{'plain', 'delta_binary_packed', 'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split'}}, got {v}",
+                            )))
+                        },
+                    })
+                },
+                "dictionary" => options.dictionary = Some(val.extract::<bool>()?),
+                "dictionary_max_cardinality" => {
+                    options.dictionary_max_cardinality = Some(val.extract::<usize>()?)
+                },
+                "compression" => compression = Some(val.extract::<PyBackedStr>()?),
+                "compression_level" => compression_level = Some(val.extract::<i32>()?),
+                _ => {
+                    return Err(PyTypeError::new_err(format!(
+                        "'{key}' is not a valid column write option",
+                    )))
+                },
+            }
+        }
+        if let Some(compression) = compression {
+            options.compression = Some(parse_parquet_compression(
+                compression.as_ref(),
+                compression_level,
+            )?);
+        }
+
+        Ok(Wrap(options))
+    }
+}
+
 impl<'s> FromPyObject<'s> for Wrap<Row<'s>> {
     fn extract_bound(ob: &Bound<'s, PyAny>) -> PyResult<Self> {
         let vals = ob.extract::<Vec<Wrap<AnyValue<'s>>>>()?;
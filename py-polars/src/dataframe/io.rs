@@ -378,7 +378,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, partition_by, partition_chunk_size_bytes))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, partition_by, partition_chunk_size_bytes, bloom_filter_columns, column_options))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -390,9 +390,18 @@ impl PyDataFrame {
         data_page_size: Option<usize>,
         partition_by: Option<Vec<String>>,
         partition_chunk_size_bytes: usize,
+        bloom_filter_columns: Option<Vec<String>>,
+        column_options: Option<Vec<(String, Wrap<ParquetColumnWriteOptions>)>>,
     ) -> PyResult<()> {
         use polars_io::partition::write_partitioned_dataset;
 
+        let bloom_filter_columns = bloom_filter_columns.unwrap_or_default();
+        let column_options = column_options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, options)| (name, options.0))
+            .collect::<Vec<_>>();
+
         let compression = parse_parquet_compression(compression, compression_level)?;
 
         if let Some(partition_by) = partition_by {
@@ -405,6 +414,9 @@ impl PyDataFrame {
                     row_group_size,
                     data_page_size,
                     maintain_order: true,
+                    bloom_filter_columns,
+                    column_options,
+                    encryption: None,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
@@ -426,6 +438,8 @@ impl PyDataFrame {
                 .with_statistics(statistics.0)
                 .with_row_group_size(row_group_size)
                 .with_data_page_size(data_page_size)
+                .with_bloom_filter_columns(bloom_filter_columns)
+                .with_column_options(column_options)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)
         })?;
@@ -338,6 +338,7 @@ impl PyLazyFrame {
             hive_options,
             glob,
             include_file_paths: include_file_paths.map(Arc::from),
+            decryption: None,
         };
 
         let lf = if path.is_some() {
@@ -666,7 +667,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, maintain_order, bloom_filter_columns, column_options))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -677,6 +678,8 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
         maintain_order: bool,
+        bloom_filter_columns: Option<Vec<String>>,
+        column_options: Option<Vec<(String, Wrap<ParquetColumnWriteOptions>)>>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -686,6 +689,13 @@ impl PyLazyFrame {
             row_group_size,
             data_page_size,
             maintain_order,
+            bloom_filter_columns: bloom_filter_columns.unwrap_or_default(),
+            column_options: column_options
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, options)| (name, options.0))
+                .collect(),
+            encryption: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -984,6 +994,19 @@ impl PyLazyFrame {
             .into())
     }
 
+    #[cfg(feature = "iejoin")]
+    fn join_where(&self, other: Self, predicates: Vec<PyExpr>, suffix: String) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let other = other.ldf;
+
+        Ok(ldf
+            .join_builder()
+            .with(other)
+            .suffix(suffix)
+            .join_where(predicates.to_exprs())
+            .into())
+    }
+
     fn with_columns(&mut self, exprs: Vec<PyExpr>) -> Self {
         let ldf = self.ldf.clone();
         ldf.with_columns(exprs.to_exprs()).into()
@@ -358,6 +358,8 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                         .map_err(|err| PyValueError::new_err(format!("{err:?}")))?;
                     ("ndjson", options).into_py(py)
                 },
+                #[cfg(feature = "avro")]
+                FileScan::Avro { .. } => return Err(PyNotImplementedError::new_err("avro scan")),
                 FileScan::Anonymous { .. } => {
                     return Err(PyNotImplementedError::new_err("anonymous scan"))
                 },
@@ -467,6 +469,12 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     #[cfg(feature = "asof_join")]
                     JoinType::AsOf(_) => return Err(PyNotImplementedError::new_err("asof join")),
                     JoinType::Cross => "cross",
+                    #[cfg(feature = "iejoin")]
+                    JoinType::IEJoin(_) => return Err(PyNotImplementedError::new_err("IEJoin")),
+                    #[cfg(feature = "interval_join")]
+                    JoinType::Interval(_) => {
+                        return Err(PyNotImplementedError::new_err("interval join"))
+                    },
                     JoinType::Semi => "leftsemi",
                     JoinType::Anti => "leftanti",
                 },
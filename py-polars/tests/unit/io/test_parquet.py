@@ -1387,3 +1387,93 @@ def test_struct_plain_encoded_statistics(tmp_path: Path) -> None:
 )
 def test_scan_round_trip_parametric(tmp_path: Path, df: pl.DataFrame) -> None:
     test_scan_round_trip(tmp_path, df)
+
+
+@pytest.mark.write_disk()
+def test_parquet_bloom_filters(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "bloom.parquet"
+    df = pl.DataFrame(
+        {
+            "id": range(0, 2000, 2),
+            "name": [f"name{i}" for i in range(0, 2000, 2)],
+            "x": [0.5] * 1000,
+        }
+    )
+    df.write_parquet(path, row_group_size=500, bloom_filter_columns=["id", "name"])
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    lf = pl.scan_parquet(path)
+    assert lf.filter(pl.col("id") == 501).collect().height == 0
+    assert lf.filter(pl.col("id") == 500).collect().height == 1
+    assert lf.filter(pl.col("name").is_in(["name1", "name2", "name1002"])).select(
+        "id"
+    ).collect().to_series().to_list() == [2, 1002]
+
+    sink_path = tmp_path / "bloom_sink.parquet"
+    lf.sink_parquet(sink_path, bloom_filter_columns="id")
+    assert_frame_equal(pl.read_parquet(sink_path), df)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_parquet(path, bloom_filter_columns="x")
+    with pytest.raises(ValueError):
+        df.write_parquet(path, bloom_filter_columns="id", use_pyarrow=True)
+
+
+def test_parquet_page_index(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "page_index.parquet"
+    ids = [*range(5000), *range(10000, 15000)]
+    df = pl.DataFrame({"id": ids, "name": [f"name{i}" for i in ids]})
+    df.write_parquet(path, data_page_size=1024)
+
+    column = pq.ParquetFile(path).metadata.row_group(0).column(0)
+    assert column.has_column_index
+    assert column.has_offset_index
+
+    for predicate in [
+        pl.col("id") == 7000,
+        pl.col("id").is_between(4990, 10005),
+        pl.col("name") == "name12345",
+    ]:
+        out = pl.scan_parquet(path).filter(predicate).collect()
+        assert_frame_equal(out, df.filter(predicate))
+
+
+def test_parquet_column_options(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "column_options.parquet"
+    n = 1000
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2024, 1, 1, 0, i // 60, i % 60) for i in range(n)],
+            "value": [float(i) / 7 for i in range(n)],
+            "name": [f"sensor-{i:04}" for i in range(n)],
+        }
+    )
+    df.write_parquet(
+        path,
+        column_options={
+            "ts": {"encoding": "delta_binary_packed", "dictionary": False},
+            "value": {"encoding": "byte_stream_split", "compression": "uncompressed"},
+            "name": {
+                "encoding": "delta_byte_array",
+                "dictionary_max_cardinality": 10,
+            },
+        },
+    )
+
+    row_group = pq.ParquetFile(path).metadata.row_group(0)
+    assert "DELTA_BINARY_PACKED" in row_group.column(0).encodings
+    assert "BYTE_STREAM_SPLIT" in row_group.column(1).encodings
+    assert row_group.column(1).compression == "UNCOMPRESSED"
+    assert "DELTA_BYTE_ARRAY" in row_group.column(2).encodings
+    assert_frame_equal(pl.read_parquet(path), df)
+    assert_frame_equal(pl.read_parquet(path, use_pyarrow=True), df)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_parquet(
+            path, column_options={"name": {"encoding": "byte_stream_split"}}
+        )
+    with pytest.raises(ValueError):
+        df.write_parquet(path, column_options={"ts": {"encoding": "rle"}})
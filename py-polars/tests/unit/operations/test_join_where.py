@@ -0,0 +1,119 @@
+from __future__ import annotations
+
+import operator
+from typing import Any, Callable
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+OPERATORS = [operator.lt, operator.le, operator.gt, operator.ge]
+
+
+@pytest.fixture()
+def sessions() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {
+            "id": [0, 1, 2, 3, 4, 5],
+            "start": [0, 10, 10, None, 25, 3],
+            "end": [10, 20, 15, 30, 40, 3],
+            "v": [1, 2, 1, 2, 1, 2],
+        }
+    )
+
+
+@pytest.fixture()
+def events() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {
+            "ts": [0, 3, 10, 12, None, 15, 25, 39, 40, 41],
+            "v": [1, 1, 2, 2, 1, 2, 1, 1, 2, 2],
+        }
+    )
+
+
+def cross_filter(
+    left: pl.LazyFrame, right: pl.LazyFrame, *predicates: pl.Expr
+) -> pl.DataFrame:
+    return (
+        left.join(right, how="cross")
+        .filter(*predicates)
+        .collect(predicate_pushdown=False)
+    )
+
+
+@pytest.mark.parametrize("op1", OPERATORS)
+@pytest.mark.parametrize("op2", [None, *OPERATORS])
+def test_join_where_inequalities(
+    sessions: pl.LazyFrame,
+    events: pl.LazyFrame,
+    op1: Callable[[Any, Any], pl.Expr],
+    op2: Callable[[Any, Any], pl.Expr] | None,
+) -> None:
+    predicates = [op1(pl.col("start"), pl.col("ts"))]
+    if op2 is not None:
+        predicates.append(op2(pl.col("ts"), pl.col("end")))
+
+    q = sessions.join_where(events, *predicates)
+    assert "IEJOIN" in q.explain()
+    assert_frame_equal(
+        q.collect(),
+        cross_filter(sessions, events, *predicates),
+        check_row_order=False,
+    )
+
+
+def test_join_where_suffix(sessions: pl.LazyFrame, events: pl.LazyFrame) -> None:
+    predicates = [pl.col("v_other") < pl.col("v"), pl.col("ts") >= pl.col("start")]
+    q = sessions.join_where(events, *predicates, suffix="_other")
+    assert "IEJOIN" in q.explain()
+    out = q.collect()
+    assert out.columns == ["id", "start", "end", "v", "ts", "v_other"]
+    expected = (
+        sessions.join(events, how="cross", suffix="_other")
+        .filter(*predicates)
+        .collect(predicate_pushdown=False)
+    )
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_join_where_equality_and_filters(
+    sessions: pl.LazyFrame, events: pl.LazyFrame
+) -> None:
+    predicates = [
+        pl.col("v") == pl.col("v_right"),
+        pl.col("start") <= pl.col("ts"),
+        pl.col("ts") < pl.col("end"),
+    ]
+    q = sessions.join_where(events, *predicates)
+    plan = q.explain()
+    assert "INNER JOIN" in plan
+    assert "CROSS JOIN" not in plan
+    assert_frame_equal(
+        q.collect(),
+        cross_filter(sessions, events, *predicates),
+        check_row_order=False,
+    )
+
+    # Predicates on a single table are pushed down.
+    predicates = [pl.col("start") <= pl.col("ts"), pl.col("id") > 1]
+    q = sessions.join_where(events, *predicates)
+    assert 'SELECTION: [(col("id")) > (1)]' in q.explain()
+    assert_frame_equal(
+        q.collect(),
+        cross_filter(sessions, events, *predicates),
+        check_row_order=False,
+    )
+
+
+def test_join_where_eager() -> None:
+    sessions = pl.DataFrame({"id": [1, 2], "start": [0, 10], "end": [10, 20]})
+    events = pl.DataFrame({"ts": [1, 5, 12, 25]})
+    out = sessions.join_where(
+        events, pl.col("start") <= pl.col("ts"), pl.col("ts") < pl.col("end")
+    )
+    expected = pl.DataFrame(
+        {"id": [1, 1, 2], "start": [0, 0, 10], "end": [10, 10, 20], "ts": [1, 5, 12]}
+    )
+    assert_frame_equal(out, expected)
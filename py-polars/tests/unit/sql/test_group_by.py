@@ -6,7 +6,7 @@ from pathlib import Path
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLSyntaxError
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -238,3 +238,115 @@ def test_group_by_errors() -> None:
         match=r"'a' should participate in the GROUP BY clause or an aggregate function",
     ):
         df.sql("SELECT a, SUM(b) FROM self GROUP BY b")
+
+
+@pytest.fixture()
+def sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "region": ["eu", "eu", "us", "us", "us"],
+            "product": ["a", "b", "a", "a", "b"],
+            "sales": [1, 2, 3, 4, 5],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "group_by",
+    [
+        "ROLLUP (region, product)",
+        "region, product WITH ROLLUP",
+        "GROUPING SETS ((region, product), (region), ())",
+    ],
+)
+def test_group_by_rollup(sales: pl.DataFrame, group_by: str) -> None:
+    res = sales.sql(
+        f"""
+        SELECT region, product, SUM(sales) AS total
+        FROM self
+        GROUP BY {group_by}
+        ORDER BY region NULLS LAST, product NULLS LAST
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["eu", "eu", "eu", "us", "us", "us", None],
+        "product": ["a", "b", None, "a", "b", None, None],
+        "total": [1, 2, 3, 7, 5, 12, 15],
+    }
+
+
+def test_group_by_cube_grouping(sales: pl.DataFrame) -> None:
+    res = sales.sql(
+        """
+        SELECT
+          region,
+          product,
+          GROUPING(region, product) AS lvl,
+          CASE WHEN GROUPING(product) = 1 THEN 'all' ELSE product END AS label,
+          COUNT(*) AS n
+        FROM self
+        GROUP BY CUBE (region, product)
+        HAVING n > 1
+        ORDER BY lvl, region, product
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["us", "eu", "us", None, None, None],
+        "product": ["a", None, None, "a", "b", None],
+        "lvl": [0, 1, 1, 2, 2, 3],
+        "label": ["a", "all", "all", "a", "b", "all"],
+        "n": [2, 2, 3, 3, 2, 5],
+    }
+
+    res = sales.sql(
+        """
+        SELECT region, product, MAX(sales) AS top, GROUPING(product) AS g
+        FROM self
+        GROUP BY region, ROLLUP (product)
+        ORDER BY region, product NULLS LAST
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["eu", "eu", "eu", "us", "us", "us"],
+        "product": ["a", "b", None, "a", "b", None],
+        "top": [1, 2, 2, 4, 5, 5],
+        "g": [0, 0, 1, 0, 0, 1],
+    }
+
+
+def test_grouping_errors(sales: pl.DataFrame) -> None:
+    with pytest.raises(
+        SQLSyntaxError,
+        match="GROUPING can only be used in a query with a GROUP BY clause",
+    ):
+        sales.sql("SELECT GROUPING(region) FROM self")
+
+    with pytest.raises(SQLSyntaxError, match="GROUPING argument 'product'"):
+        sales.sql(
+            "SELECT region, GROUPING(product) FROM self GROUP BY ROLLUP (region)"
+        )
+
+
+def test_aggregate_filter(sales: pl.DataFrame) -> None:
+    res = sales.sql(
+        """
+        SELECT
+          region,
+          SUM(sales) FILTER (WHERE product = 'a') AS a_sales,
+          COUNT(*) FILTER (WHERE sales > 3) AS n,
+          MAX(sales) FILTER (WHERE product = 'c') AS c_max
+        FROM self
+        GROUP BY region
+        ORDER BY region
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["eu", "us"],
+        "a_sales": [1, 7],
+        "n": [0, 2],
+        "c_max": [None, None],
+    }
+    with pytest.raises(
+        SQLInterfaceError, match="only supported for aggregate functions"
+    ):
+        sales.sql("SELECT UPPER(region) FILTER (WHERE sales > 1) FROM self")
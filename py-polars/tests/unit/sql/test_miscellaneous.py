@@ -271,6 +271,41 @@ def test_nested_cte_column_aliasing() -> None:
     }
 
 
+def test_recursive_cte() -> None:
+    parts = pl.DataFrame(  # noqa: F841
+        {
+            "part": ["bike", "bike", "wheel", "wheel", "frame"],
+            "component": ["wheel", "frame", "spoke", "tyre", "tube"],
+            "qty": [2, 1, 32, 1, 3],
+        }
+    )
+    res = pl.sql(
+        """
+        WITH RECURSIVE bom(component, qty) AS (
+          SELECT component, qty FROM parts WHERE part = 'bike'
+          UNION ALL
+          SELECT p.component, p.qty * b.qty
+          FROM parts p JOIN bom b ON p.part = b.component
+        )
+        SELECT component, SUM(qty) AS total FROM bom
+        GROUP BY component ORDER BY component
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "component": ["frame", "spoke", "tube", "tyre", "wheel"],
+        "total": [1, 64, 3, 2, 2],
+    }
+
+    with pytest.raises(SQLInterfaceError, match="did not terminate"):
+        pl.sql(
+            """
+            WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t)
+            SELECT * FROM t
+            """
+        )
+
+
 def test_invalid_derived_table_column_aliases() -> None:
     values_query = "SELECT * FROM (VALUES (1,2), (3,4))"
 
@@ -325,6 +360,47 @@ def test_read_csv(tmp_path: Path) -> None:
         pl.sql("SELECT * FROM read_csv('a','b','c')")
 
 
+def test_read_csv_named_args(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    for n in range(2):
+        df.write_csv(tmp_path / f"test_sql_read_{n}.csv", separator=";")
+
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_csv(
+          '{tmp_path}/test_sql_read_*.csv',
+          separator => ';',
+          columns => ['b'],
+          n_rows => 2
+        )
+        """
+    ).collect()
+    assert res.to_dict(as_series=False) == {"b": ["x", "y"]}
+
+    with pytest.raises(SQLSyntaxError, match="unknown argument 'sep' for `read_csv`"):
+        pl.sql(f"SELECT * FROM read_csv('{tmp_path}/test_sql_read_0.csv', sep => ';')")
+
+
+def test_generate_series() -> None:
+    res = pl.sql(
+        """
+        SELECT n, d
+        FROM generate_series(1, 3) AS s(n)
+        CROSS JOIN generate_series(DATE '2024-01-01', DATE '2024-01-02') AS t(d)
+        ORDER BY n, d
+        """,
+        eager=True,
+    )
+    assert res.rows() == [
+        (1, date(2024, 1, 1)),
+        (1, date(2024, 1, 2)),
+        (2, date(2024, 1, 1)),
+        (2, date(2024, 1, 2)),
+        (3, date(2024, 1, 1)),
+        (3, date(2024, 1, 2)),
+    ]
+
+
 def test_global_variable_inference_17398() -> None:
     users = pl.DataFrame({"id": "1"})
 
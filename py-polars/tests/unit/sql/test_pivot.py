@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture()
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "region": ["eu", "eu", "us", "us", "us"],
+            "quarter": ["q1", "q2", "q1", "q1", "q3"],
+            "amount": [1, 2, 3, 4, 5],
+        }
+    )
+
+
+def test_pivot(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2' AS second))
+        ORDER BY region
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "region": ["eu", "us"],
+            "q1": [1, 7],
+            "second": [2, None],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (
+          MIN(amount) AS lo, MAX(amount) AS hi
+          FOR quarter IN ('q1', 'q3') DEFAULT ON NULL (-1)
+        )
+        ORDER BY region
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "region": ["eu", "us"],
+            "q1_lo": [1, 3],
+            "q1_hi": [1, 4],
+            "q3_lo": [-1, 5],
+            "q3_hi": [-1, 5],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_unpivot() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["eu", "us"],
+            "q1": [1, 7],
+            "q2": [2, None],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT (amount FOR quarter IN (q1, q2)) AS u (r, q, v)
+        ORDER BY r, q
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "r": ["eu", "eu", "us"],
+            "q": ["q1", "q2", "q1"],
+            "v": [1, 2, 7],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_pivot_errors(df_sales: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+        df_sales.sql(
+            "SELECT * FROM self PIVOT (SUM(amount) FOR quarter IN (ANY ORDER BY quarter))"
+        )
+
+    with pytest.raises(SQLSyntaxError, match="requires an alias"):
+        df_sales.sql(
+            "SELECT * FROM self PIVOT (SUM(amount), MAX(amount) FOR quarter IN ('q1'))"
+        )
+
+    with pytest.raises(SQLInterfaceError, match="'missing' not found"):
+        df_sales.sql("SELECT * FROM self UNPIVOT (v FOR k IN (amount, missing))")
@@ -1,7 +1,11 @@
+from __future__ import annotations
+
+from typing import Any
+
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLSyntaxError
+from polars.exceptions import ComputeError, SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -146,3 +150,95 @@ def test_in_subquery() -> None:
             """,
             eager=True,
         )
+
+
+@pytest.fixture
+def customers_orders() -> pl.SQLContext[Any]:
+    customers = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4],
+            "name": ["a", "b", "c", "d"],
+            "region": ["eu", "us", "eu", None],
+        }
+    )
+    orders = pl.DataFrame(
+        {
+            "cust_id": [1, 1, 2, 5],
+            "amount": [10, 20, 5, 7],
+        }
+    )
+    return pl.SQLContext(customers=customers, orders=orders)
+
+
+@pytest.mark.parametrize(
+    ("condition", "expected"),
+    [
+        ("EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id)", ["a", "b"]),
+        ("NOT EXISTS (SELECT * FROM orders WHERE cust_id = id)", ["c", "d"]),
+        (
+            "EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id AND amount > 10)",
+            ["a"],
+        ),
+        (
+            "region = 'us' OR NOT EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id)",
+            ["b", "c", "d"],
+        ),
+        ("c.id * 10 IN (SELECT amount FROM orders o WHERE o.cust_id = c.id)", ["a"]),
+        (
+            "c.id * 10 NOT IN (SELECT amount FROM orders o WHERE o.cust_id = c.id)",
+            ["b", "c", "d"],
+        ),
+        ("id = (SELECT MAX(cust_id) FROM orders WHERE cust_id < 5)", ["b"]),
+        ("EXISTS (SELECT 1 FROM orders WHERE amount > 100)", []),
+    ],
+)
+def test_correlated_subquery_where(
+    customers_orders: pl.SQLContext[Any], condition: str, expected: list[str]
+) -> None:
+    res = customers_orders.execute(
+        f"SELECT name FROM customers c WHERE {condition} ORDER BY name",
+        eager=True,
+    )
+    assert res["name"].to_list() == expected
+
+
+def test_correlated_subquery_select(customers_orders: pl.SQLContext[Any]) -> None:
+    res = customers_orders.execute(
+        """
+        SELECT
+          name,
+          (SELECT SUM(amount) FROM orders o WHERE o.cust_id = c.id) AS total,
+          (SELECT COUNT(*) FROM orders o WHERE o.cust_id = c.id) AS n,
+          EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id) AS has_orders
+        FROM customers c
+        ORDER BY name
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "name": ["a", "b", "c", "d"],
+        "total": [30, 5, None, None],
+        "n": [2, 1, 0, 0],
+        "has_orders": [True, True, False, False],
+    }
+
+
+def test_correlated_subquery_errors(customers_orders: pl.SQLContext[Any]) -> None:
+    with pytest.raises(
+        SQLInterfaceError,
+        match="correlated subquery predicates must be equalities",
+    ):
+        customers_orders.execute(
+            """
+            SELECT name FROM customers c
+            WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id > c.id)
+            """
+        )
+    with pytest.raises(ComputeError):
+        customers_orders.execute(
+            """
+            SELECT name, (SELECT amount FROM orders o WHERE o.cust_id = c.id)
+            FROM customers c
+            """,
+            eager=True,
+        )
@@ -64,6 +64,43 @@ def test_show_tables(test_frame: pl.LazyFrame) -> None:
         assert_frame_equal(res, pl.DataFrame({"name": ["tbl1", "tbl2", "tbl3"]}))
 
 
+def test_describe_table(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        expected = pl.DataFrame(
+            {
+                "column_name": ["x", "y", "z"],
+                "column_type": ["UTINYINT", "VARCHAR", "DATE"],
+            }
+        )
+        assert_frame_equal(ctx.execute("DESCRIBE frame"), expected)
+        assert_frame_equal(ctx.execute("DESCRIBE public.frame"), expected)
+
+        with pytest.raises(SQLInterfaceError, match="'missing' was not found"):
+            ctx.execute("DESCRIBE missing")
+
+
+def test_information_schema(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, other=test_frame, eager=True) as ctx:
+        res = ctx.execute("SELECT * FROM information_schema.tables")
+        assert res.to_dict(as_series=False) == {
+            "table_schema": ["public", "public"],
+            "table_name": ["frame", "other"],
+            "table_type": ["BASE TABLE", "BASE TABLE"],
+        }
+        res = ctx.execute(
+            """
+            SELECT column_name, ordinal_position, data_type
+            FROM information_schema.columns
+            WHERE table_name = 'other'
+            """
+        )
+        assert res.to_dict(as_series=False) == {
+            "column_name": ["x", "y", "z"],
+            "ordinal_position": [1, 2, 3],
+            "data_type": ["UTINYINT", "VARCHAR", "DATE"],
+        }
+
+
 @pytest.mark.parametrize(
     "truncate_sql",
     [
@@ -81,3 +118,50 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_update_delete(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("INSERT INTO frame (x, y) VALUES (4, 'ddd'), (5, 'eee')")
+        assert_frame_equal(res, pl.DataFrame({"Response": ["INSERT"]}))
+
+        ctx.execute("UPDATE frame SET y = upper(y), z = DATE '2020-01-01' WHERE x > 3")
+        ctx.execute("DELETE FROM frame WHERE x < 3")
+
+        res = ctx.execute("SELECT * FROM frame ORDER BY x")
+        expected = pl.DataFrame(
+            {
+                "x": [3, 4, 5],
+                "y": ["ccc", "DDD", "EEE"],
+                "z": [date(2077, 10, 20), date(2020, 1, 1), date(2020, 1, 1)],
+            },
+            schema_overrides={"x": pl.UInt8},
+        )
+        assert_frame_equal(res, expected)
+
+
+def test_merge(test_frame: pl.LazyFrame) -> None:
+    updates = pl.DataFrame({"x": [2, 3, 4], "y": ["xxx", None, "zzz"]})
+    with pl.SQLContext(frame=test_frame, updates=updates, eager=True) as ctx:
+        ctx.execute(
+            """
+            MERGE INTO frame f
+            USING updates u
+            ON f.x = u.x
+            WHEN MATCHED AND u.y IS NULL THEN DELETE
+            WHEN MATCHED THEN UPDATE SET y = u.y
+            WHEN NOT MATCHED THEN INSERT (x, y) VALUES (u.x, u.y)
+            """
+        )
+        res = ctx.execute("SELECT x, y FROM frame ORDER BY x")
+        expected = pl.DataFrame(
+            {"x": [1, 2, 4], "y": ["aaa", "xxx", "zzz"]},
+            schema_overrides={"x": pl.UInt8},
+        )
+        assert_frame_equal(res, expected)
+
+        with pytest.raises(SQLInterfaceError, match="must compare target and source"):
+            ctx.execute(
+                "MERGE INTO frame f USING updates u ON f.x > u.x "
+                "WHEN MATCHED THEN DELETE"
+            )
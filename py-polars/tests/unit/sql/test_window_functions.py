@@ -0,0 +1,192 @@
+from __future__ import annotations
+
+from datetime import date
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+
+
+@pytest.fixture()
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5, 6, 7],
+            "grp": ["a", "a", "a", "a", "b", "b", "b"],
+            "val": [3, 1, 3, 2, 7, None, 7],
+        }
+    )
+
+
+def test_ranking_window_functions(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          id,
+          ROW_NUMBER() OVER (PARTITION BY grp ORDER BY val, id) AS rn,
+          RANK() OVER (PARTITION BY grp ORDER BY val) AS rk,
+          DENSE_RANK() OVER (PARTITION BY grp ORDER BY val) AS dr,
+          PERCENT_RANK() OVER (PARTITION BY grp ORDER BY val) AS pr,
+          NTILE(2) OVER (PARTITION BY grp ORDER BY id) AS nt,
+          ROW_NUMBER() OVER (ORDER BY val DESC NULLS LAST, id) AS rn_all
+        FROM self
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6, 7],
+        "rn": [3, 1, 4, 2, 1, 3, 2],
+        "rk": [3, 1, 3, 2, 1, 3, 1],
+        "dr": [3, 1, 3, 2, 1, 2, 1],
+        "pr": [2 / 3, 0.0, 2 / 3, 1 / 3, 0.0, 1.0, 0.0],
+        "nt": [1, 1, 2, 2, 1, 1, 2],
+        "rn_all": [3, 6, 4, 5, 1, 7, 2],
+    }
+
+
+def test_offset_window_functions(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          id,
+          LAG(val) OVER (PARTITION BY grp ORDER BY id) AS lg,
+          LEAD(val, 1, 0) OVER (PARTITION BY grp ORDER BY id) AS ld,
+          FIRST_VALUE(id) OVER (PARTITION BY grp ORDER BY val DESC, id) AS fv,
+          LAST_VALUE(val) OVER (PARTITION BY grp ORDER BY val) AS lv,
+          NTH_VALUE(id, 2) OVER (PARTITION BY grp ORDER BY id) AS nv
+        FROM self
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6, 7],
+        "lg": [None, 3, 1, 3, None, 7, None],
+        "ld": [1, 3, 2, 0, None, 7, 0],
+        "fv": [1, 1, 1, 1, 6, 6, 6],
+        "lv": [3, 1, 3, 2, 7, None, 7],
+        "nv": [None, 2, 2, 2, None, 6, 6],
+    }
+
+
+def test_window_function_errors(df: pl.DataFrame) -> None:
+    with pytest.raises(SQLSyntaxError, match="ROW_NUMBER requires an OVER clause"):
+        df.sql("SELECT ROW_NUMBER() AS rn FROM self")
+    with pytest.raises(SQLSyntaxError, match="invalid argument for NTILE"):
+        df.sql("SELECT NTILE(0) OVER (ORDER BY id) AS nt FROM self")
+
+
+def test_window_frames(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          id,
+          AVG(val) OVER (
+            PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+          ) AS avg3,
+          SUM(val) OVER (PARTITION BY grp ORDER BY id ROWS UNBOUNDED PRECEDING) AS cum_sum,
+          MAX(val) OVER (
+            PARTITION BY grp ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING
+          ) AS prev_max,
+          SUM(val) OVER (
+            PARTITION BY grp ORDER BY val RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+          ) AS range_sum
+        FROM self
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6, 7],
+        "avg3": [2.0, 7 / 3, 2.0, 2.5, 7.0, 7.0, 7.0],
+        "cum_sum": [3, 4, 7, 9, 7, 7, 14],
+        "prev_max": [None, 3, 3, 3, None, 7, 7],
+        "range_sum": [9, 1, 9, 3, 14, 14, 14],
+    }
+
+
+def test_range_frame_interval() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 4),
+                date(2024, 1, 5),
+                date(2024, 1, 5),
+            ],
+            "v": [1, 2, 3, 4, 5],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT
+          v,
+          SUM(v) OVER (
+            ORDER BY dt RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW
+          ) AS sum_3d
+        FROM self
+        ORDER BY v
+        """
+    )
+    assert res["sum_3d"].to_list() == [1, 3, 5, 12, 12]
+
+
+def test_named_windows(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          id,
+          ROW_NUMBER() OVER w AS rn,
+          SUM(val) OVER (w ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS pair_sum,
+          MAX(val) OVER w_all AS max_val
+        FROM self
+        WINDOW w_all AS (PARTITION BY grp), w AS (w_all ORDER BY id)
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6, 7],
+        "rn": [1, 2, 3, 4, 1, 2, 3],
+        "pair_sum": [3, 4, 4, 5, 7, 7, 7],
+        "max_val": [3, 3, 3, 3, 7, 7, 7],
+    }
+
+
+def test_window_frame_errors(df: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="no window named 'w' found"):
+        df.sql("SELECT SUM(val) OVER w AS s FROM self")
+    with pytest.raises(SQLSyntaxError, match="cannot override the ORDER BY clause"):
+        df.sql(
+            """
+            SELECT SUM(val) OVER (w ORDER BY id) AS s FROM self
+            WINDOW w AS (PARTITION BY grp ORDER BY val)
+            """
+        )
+    with pytest.raises(SQLInterfaceError, match="RANGE frame offsets"):
+        df.sql(
+            """
+            SELECT SUM(val) OVER (
+              ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW
+            ) AS s FROM self
+            """
+        )
+
+
+def test_qualify(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT grp, id FROM self
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY grp ORDER BY id DESC) = 1
+        ORDER BY grp
+        """
+    )
+    assert res.to_dict(as_series=False) == {"grp": ["a", "b"], "id": [4, 7]}
+
+    res = df.sql(
+        """
+        SELECT id, SUM(val) OVER (PARTITION BY grp) AS total FROM self
+        QUALIFY total > 10
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {"id": [5, 6, 7], "total": [14, 14, 14]}
@@ -299,3 +299,26 @@ def test_streaming_outer_join_partial_flush(tmp_path: Path) -> None:
         ],
         "value": [0, 1, 2, 3, 4, 5],
     }
+
+
+@pytest.mark.parametrize("how", ["semi", "anti"])
+@pytest.mark.parametrize("join_nulls", [False, True])
+def test_streaming_semi_anti_join(how: JoinStrategy, join_nulls: bool) -> None:
+    df1 = pl.LazyFrame(
+        {"a": [1, 2, None, 3, 2, 4], "b": ["a", "b", "c", "d", "e", "f"]}
+    )
+    df2 = pl.LazyFrame({"a": [2, 2, None, 4, 5], "c": [1, 2, 3, 4, 5]})
+
+    q = df1.join(df2, on="a", how=how, join_nulls=join_nulls)
+    assert q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))
+
+
+@pytest.mark.parametrize("coalesce", [None, False])
+def test_streaming_right_join(coalesce: bool | None) -> None:
+    df1 = pl.LazyFrame({"a": [1, 2, 2, 3], "b": ["a", "b", "c", "d"]})
+    df2 = pl.LazyFrame({"a": [2, 4, None, 1], "b": [1, 2, 3, 4]})
+
+    q = df1.join(df2, on="a", how="right", coalesce=coalesce)
+    assert q.explain(streaming=True).startswith("STREAMING")
+    assert_frame_equal(q.collect(streaming=True), q.collect(streaming=False))